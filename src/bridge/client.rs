@@ -1,11 +1,16 @@
+use std::sync::Arc;
+
 use reqwest::{
-    Client as ReqwestClient, Method,
+    Client as ReqwestClient, Method, Request,
     header::{HeaderMap, HeaderValue},
 };
+use serde::de::DeserializeOwned;
 use url::Url;
 
 use super::types::{DepositRequest, DepositResponse, SupportedAssetsResponse};
-use crate::Result;
+use crate::metrics::MetricsObserver;
+use crate::middleware::Middleware;
+use crate::{ConnectionConfig, RequestContext, Result};
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -35,7 +40,12 @@ use crate::Result;
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    http: ReqwestClient,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    #[cfg(feature = "lenient")]
+    lenient: bool,
+    max_response_bytes: Option<usize>,
+    middleware: Middleware,
 }
 
 impl Default for Client {
@@ -52,20 +62,75 @@ impl Client {
     ///
     /// Returns an error if the host URL is invalid or the HTTP client fails to build.
     pub fn new(host: &str) -> Result<Client> {
+        Self::new_with_connection(host, ConnectionConfig::default())
+    }
+
+    /// Creates a new Bridge API client with a custom host URL whose HTTP connections use the
+    /// `connection` pool and keep-alive settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid or the HTTP client fails to build.
+    pub fn new_with_connection(host: &str, connection: ConnectionConfig) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let http = connection
+            .apply(ReqwestClient::builder().default_headers(headers))
+            .build()?;
 
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            http,
+            metrics: None,
+            #[cfg(feature = "lenient")]
+            lenient: false,
+            max_response_bytes: None,
+            middleware: Middleware::default(),
         })
     }
 
+    /// Registers a [`MetricsObserver`] that is notified of every request made by this client.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enables lenient deserialization of responses: optional fields that fail to deserialize
+    /// degrade to `None` or their default and are logged instead of failing the request. Required
+    /// fields and list items still fail it.
+    ///
+    /// Strict deserialization is the default.
+    #[cfg(feature = "lenient")]
+    #[must_use]
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Caps the size of a response body this client will buffer at `max_response_bytes`. Larger
+    /// responses fail with [`ResponseTooLarge`](crate::error::ResponseTooLarge) as soon as they
+    /// are known to exceed it, rather than being read in full.
+    ///
+    /// Responses are unlimited by default.
+    #[must_use]
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Registers [`Middleware`] whose interceptors and observers are applied to every request made
+    /// by this client.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Middleware) -> Self {
+        self.middleware = middleware;
+        self
+    }
+
     /// Returns the host URL for the client.
     #[must_use]
     pub fn host(&self) -> &Url {
@@ -74,7 +139,7 @@ impl Client {
 
     #[must_use]
     fn client(&self) -> &ReqwestClient {
-        &self.client
+        &self.http
     }
 
     /// Create deposit addresses for a Polymarket wallet.
@@ -108,7 +173,7 @@ impl Client {
             .json(request)
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        self.send(request).await
     }
 
     /// Get all supported chains and tokens for deposits.
@@ -143,6 +208,20 @@ impl Client {
             .request(Method::GET, format!("{}supported-assets", self.host()))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        self.send(request).await
+    }
+
+    /// Sends `request` with the HTTP client and settings of this client.
+    async fn send<Res: DeserializeOwned>(&self, request: Request) -> Result<Res> {
+        let context = RequestContext {
+            client: &self.http,
+            metrics: self.metrics.as_deref(),
+            #[cfg(feature = "lenient")]
+            lenient: self.lenient,
+            max_response_bytes: self.max_response_bytes,
+            middleware: &self.middleware,
+        };
+
+        crate::request(context, request, None).await
     }
 }
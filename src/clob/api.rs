@@ -0,0 +1,76 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`ClobApi` names the API the trait abstracts over"
+)]
+
+//! Trait-based interface to the CLOB's public market data.
+
+use async_trait::async_trait;
+
+use super::Client;
+use super::types::request::{MidpointRequest, OrderBookSummaryRequest, PriceRequest};
+use super::types::response::{
+    MarketResponse, MidpointResponse, NegRiskResponse, OrderBookSummaryResponse, PriceResponse,
+    TickSizeResponse,
+};
+use crate::Result;
+use crate::auth::state::State;
+
+/// The public CLOB endpoints most commonly used by trading code.
+///
+/// Depend on this trait rather than on [`Client`] to be able to substitute a fake in tests, such
+/// as `FakeClobApi` from the `test-util` feature. Each method behaves like the [`Client`] method
+/// of the same name, and the trait is implemented for clients in any authentication state.
+#[async_trait]
+pub trait ClobApi: Send + Sync {
+    /// See [`Client::midpoint`].
+    async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse>;
+
+    /// See [`Client::price`].
+    async fn price(&self, request: &PriceRequest) -> Result<PriceResponse>;
+
+    /// See [`Client::order_book`].
+    async fn order_book(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> Result<OrderBookSummaryResponse>;
+
+    /// See [`Client::tick_size`].
+    async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse>;
+
+    /// See [`Client::neg_risk`].
+    async fn neg_risk(&self, token_id: &str) -> Result<NegRiskResponse>;
+
+    /// See [`Client::market`].
+    async fn market(&self, condition_id: &str) -> Result<MarketResponse>;
+}
+
+#[async_trait]
+impl<S: State + Send + Sync> ClobApi for Client<S> {
+    async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
+        Client::midpoint(self, request).await
+    }
+
+    async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
+        Client::price(self, request).await
+    }
+
+    async fn order_book(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> Result<OrderBookSummaryResponse> {
+        Client::order_book(self, request).await
+    }
+
+    async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse> {
+        Client::tick_size(self, token_id).await
+    }
+
+    async fn neg_risk(&self, token_id: &str) -> Result<NegRiskResponse> {
+        Client::neg_risk(self, token_id).await
+    }
+
+    async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
+        Client::market(self, condition_id).await
+    }
+}
@@ -0,0 +1,170 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`BookDiff` reads better at use sites than `book::Diff`"
+)]
+
+//! Comparisons between [`OrderBook`] snapshots.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::clob::types::response::OrderBook;
+use crate::types::Decimal;
+
+/// The resting size at one price level in two snapshots of a book.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LevelChange {
+    pub price: Decimal,
+    /// Size resting at `price` in the earlier snapshot, zero if the level was empty.
+    pub before: Decimal,
+    /// Size resting at `price` in the later snapshot, zero if the level is empty.
+    pub after: Decimal,
+}
+
+impl LevelChange {
+    /// Returns `after - before`, negative when liquidity left the level.
+    #[must_use]
+    pub fn delta(&self) -> Decimal {
+        self.after - self.before
+    }
+}
+
+/// The difference between two snapshots of a book, as returned by [`diff_books`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookDiff {
+    /// Bid levels whose size changed, by ascending price.
+    pub bids: Vec<LevelChange>,
+    /// Ask levels whose size changed, by ascending price.
+    pub asks: Vec<LevelChange>,
+    /// Total size that left the bid levels, counting only levels that shrank.
+    pub bids_removed: Decimal,
+    /// Total size that left the ask levels, counting only levels that shrank.
+    pub asks_removed: Decimal,
+}
+
+/// Compares two snapshots of the same token's book, level by level.
+///
+/// Removed liquidity was either traded against or cancelled; snapshots alone cannot tell the two
+/// apart, so treat it as an upper bound on what traded in between.
+#[must_use]
+pub fn diff_books(before: &OrderBook, after: &OrderBook) -> BookDiff {
+    let (bids, bids_removed) = diff_levels(&before.bids, &after.bids);
+    let (asks, asks_removed) = diff_levels(&before.asks, &after.asks);
+
+    BookDiff {
+        bids,
+        asks,
+        bids_removed,
+        asks_removed,
+    }
+}
+
+fn diff_levels(
+    before: &BTreeMap<Decimal, Decimal>,
+    after: &BTreeMap<Decimal, Decimal>,
+) -> (Vec<LevelChange>, Decimal) {
+    let size = |levels: &BTreeMap<Decimal, Decimal>, price: &Decimal| {
+        levels.get(price).copied().unwrap_or_default()
+    };
+
+    let prices: BTreeSet<&Decimal> = before.keys().chain(after.keys()).collect();
+
+    let changes: Vec<LevelChange> = prices
+        .into_iter()
+        .map(|price| LevelChange {
+            price: *price,
+            before: size(before, price),
+            after: size(after, price),
+        })
+        .filter(|change| change.before != change.after)
+        .collect();
+
+    let removed = changes
+        .iter()
+        .map(LevelChange::delta)
+        .filter(Decimal::is_sign_negative)
+        .map(|delta| -delta)
+        .sum();
+
+    (changes, removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::types::TickSize;
+
+    fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBook {
+        OrderBook {
+            market: "0xabc".to_owned(),
+            token_id: U256::from(1),
+            timestamp: Utc::now(),
+            hash: None,
+            bids: bids.iter().copied().collect(),
+            asks: asks.iter().copied().collect(),
+            min_order_size: dec!(5),
+            neg_risk: false,
+            tick_size: TickSize::Hundredth,
+        }
+    }
+
+    #[test]
+    fn diff_books_should_report_changed_levels() {
+        let before = book(
+            &[(dec!(0.48), dec!(100)), (dec!(0.49), dec!(50))],
+            &[(dec!(0.51), dec!(40)), (dec!(0.52), dec!(60))],
+        );
+        let after = book(
+            &[(dec!(0.48), dec!(120)), (dec!(0.49), dec!(20))],
+            &[(dec!(0.52), dec!(60)), (dec!(0.53), dec!(10))],
+        );
+
+        let diff = diff_books(&before, &after);
+
+        assert_eq!(
+            diff.bids,
+            vec![
+                LevelChange {
+                    price: dec!(0.48),
+                    before: dec!(100),
+                    after: dec!(120),
+                },
+                LevelChange {
+                    price: dec!(0.49),
+                    before: dec!(50),
+                    after: dec!(20),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.asks,
+            vec![
+                LevelChange {
+                    price: dec!(0.51),
+                    before: dec!(40),
+                    after: Decimal::ZERO,
+                },
+                LevelChange {
+                    price: dec!(0.53),
+                    before: Decimal::ZERO,
+                    after: dec!(10),
+                },
+            ]
+        );
+        assert_eq!(diff.bids_removed, dec!(30));
+        assert_eq!(diff.asks_removed, dec!(40));
+        assert_eq!(diff.asks[1].delta(), dec!(10));
+    }
+
+    #[test]
+    fn diff_books_should_be_empty_for_identical_books() {
+        let book = book(&[(dec!(0.4), dec!(10))], &[(dec!(0.6), dec!(10))]);
+
+        assert_eq!(diff_books(&book, &book), BookDiff::default());
+    }
+}
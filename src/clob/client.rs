@@ -1,47 +1,63 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::mem;
+use std::str::FromStr as _;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
+use alloy::primitives::{B256, ChainId, Signature, U256};
 use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
 use bon::Builder;
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use dashmap::DashMap;
-use futures::Stream;
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::{Client as ReqwestClient, Method, Request};
+use rust_decimal::prelude::ToPrimitive as _;
+use serde::de::DeserializeOwned;
 use serde_json::json;
 use url::Url;
 
 use crate::auth::builder::{Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind, Normal};
-use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::order_builder::{
+    Limit, Market, OrderBuilder, USDC_DECIMALS, generate_seed, to_base_units,
+};
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
-    PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UpdateBalanceAllowanceRequest,
-    UserRewardsEarningRequest,
+    PriceHistoryRequest, PriceRequest, SplitAndSellRequest, SplitPositionRequest, SpreadRequest,
+    TradesRequest, UpdateBalanceAllowanceRequest, UserRewardsEarningRequest,
 };
 use crate::clob::types::response::{
     ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
     BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, FeeRateResponse,
-    GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse, MarketResponse,
-    MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-    NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
+    FeeSchedule, GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse, MarketParams,
+    MarketResponse, MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
+    NotificationResponse, OpenOrderResponse, OrderBook, OrderBookSummaryResponse,
+    OrderScoringResponse, OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse,
+    PriceResponse, PricesResponse, Quote, ReplaceOrderResponse, RewardEarningPoint,
+    RewardsPercentagesResponse, SimplifiedMarketResponse, SplitAndSellResponse, SpreadResponse,
     SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
     UserEarningResponse, UserRewardsEarningResponse,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Synchronization};
-use crate::types::Address;
-use crate::{AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config};
+use crate::clob::types::{
+    Amount, OrderType, Side, SignableOrder, SignatureType, SignedOrder, TickSize,
+};
+use crate::error::{Error, NoOpenOrders, RotationIncomplete, SplitUnsold, Synchronization};
+use crate::metrics::MetricsObserver;
+use crate::middleware::Middleware;
+use crate::types::{Address, Decimal, RoundingStrategy};
+use crate::{
+    AMOY, CLOB_HOST_VAR, ConnectionConfig, MAX_CONCURRENT_REQUESTS, POLYGON, PROXY_ADDRESS_VAR,
+    RequestContext, Result, Timestamp, ToQueryParams as _, auth, contract_config, env_var,
+    host_from_env,
+};
 
 const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
 const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
@@ -104,6 +120,28 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         self
     }
 
+    /// Sets the funder to the proxy wallet in [`PROXY_ADDRESS_VAR`] (`POLYMARKET_PROXY_ADDRESS`),
+    /// if set, switching an [`SignatureType::Eoa`] signature type to [`SignatureType::Proxy`].
+    /// Leaves the builder unchanged when the variable is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is set but is not a valid address.
+    pub fn funder_from_env(mut self) -> Result<Self> {
+        let Some(value) = env_var(PROXY_ADDRESS_VAR)? else {
+            return Ok(self);
+        };
+        let funder = value.trim().parse::<Address>().map_err(|e| {
+            Error::validation(format!("Invalid {PROXY_ADDRESS_VAR} `{value}`: {e}"))
+        })?;
+
+        self.funder = Some(funder);
+        if matches!(self.signature_type, None | Some(SignatureType::Eoa)) {
+            self.signature_type = Some(SignatureType::Proxy);
+        }
+        Ok(self)
+    }
+
     /// Attempt to elevate the inner `client` to [`Client<Authenticated<K>>`] using the optional
     /// fields supplied in the builder.
     pub async fn authenticate(self) -> Result<Client<Authenticated<K>>> {
@@ -175,6 +213,9 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                condition_tokens: inner.condition_tokens,
+                fee_schedules: inner.fee_schedules,
+                market_params: inner.market_params,
                 funder: self.funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
@@ -245,7 +286,7 @@ pub struct Client<S: State = Unauthenticated> {
 
 impl Default for Client<Unauthenticated> {
     fn default() -> Self {
-        Client::new("https://clob.polymarket.com", Config::default())
+        Client::new(DEFAULT_HOST, Config::default())
             .expect("Client with default endpoint should succeed")
     }
 }
@@ -261,8 +302,39 @@ pub struct Config {
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// Observer notified of every HTTP request made by the [`Client`].
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    /// How long [`Client::market_params`] results are cached. When unset, they are cached until
+    /// [`Client::invalidate_internal_caches`] is called, like the other per-token caches.
+    market_params_ttl: Option<Duration>,
+    /// Whether responses are deserialized leniently. When enabled, optional fields that fail to
+    /// deserialize degrade to `None` or their default and are logged instead of failing the
+    /// request. Required fields and list items still fail it.
+    #[cfg(feature = "lenient")]
+    #[builder(default)]
+    lenient: bool,
+    /// Maximum size of a response body the [`Client`] will buffer, in bytes. Larger responses
+    /// fail with [`ResponseTooLarge`](crate::error::ResponseTooLarge) as soon as they are known to
+    /// exceed it, rather than being read in full. Unlimited by default. Paginated endpoints are
+    /// best read a page at a time through [`Client::stream_data`] when setting a low limit.
+    max_response_bytes: Option<usize>,
+    /// Interceptors and observers applied to every HTTP request made by the [`Client`].
+    #[builder(default)]
+    middleware: Middleware,
+    /// Maximum number of idle connections kept open per host. See [`ConnectionConfig`] for the
+    /// defaults.
+    pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open before being closed, or `None` to keep it open
+    /// until the server closes it. See [`ConnectionConfig`] for the defaults.
+    pool_idle_timeout: Option<Option<Duration>>,
+    /// Interval of TCP keep-alive probes on open connections, or `None` to send none. See
+    /// [`ConnectionConfig`] for the defaults.
+    tcp_keepalive: Option<Option<Duration>>,
 }
 
+/// The default CLOB API host
+const DEFAULT_HOST: &str = "https://clob.polymarket.com";
+
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
@@ -283,6 +355,12 @@ struct ClientInner<S: State> {
     neg_risk: DashMap<String, bool>,
     /// Local cache representing the fee rate in basis points per token ID
     fee_rate_bps: DashMap<String, u32>,
+    /// Local cache of outcome token IDs per condition ID, in outcome order
+    condition_tokens: DashMap<String, Vec<U256>>,
+    /// Local cache of [`FeeSchedule`] per condition ID
+    fee_schedules: DashMap<String, FeeSchedule>,
+    /// Local cache of [`MarketParams`] per token ID, along with when they were fetched
+    market_params: DashMap<String, (MarketParams, Instant)>,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -294,20 +372,55 @@ struct ClientInner<S: State> {
 }
 
 impl<S: State> ClientInner<S> {
+    /// Sends `request` with the HTTP client and settings of this client, replacing its headers
+    /// with `headers` if given.
+    async fn send<Res: DeserializeOwned>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Res> {
+        let context = RequestContext {
+            client: &self.client,
+            metrics: self.config.metrics.as_deref(),
+            #[cfg(feature = "lenient")]
+            lenient: self.config.lenient,
+            max_response_bytes: self.config.max_response_bytes,
+            middleware: &self.config.middleware,
+        };
+
+        crate::request(context, request, headers).await
+    }
+
     pub async fn server_time(&self) -> Result<Timestamp> {
         let request = self
             .client
             .request(Method::GET, format!("{}time", self.host))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        self.send(request, None).await
     }
-}
 
-impl ClientInner<Unauthenticated> {
-    pub async fn create_api_key<S: Signer>(
+    async fn create_headers<Sig: Signer>(
         &self,
-        signer: &S,
+        signer: &Sig,
+        nonce: Option<u32>,
+    ) -> Result<HeaderMap> {
+        let chain_id = signer.chain_id().ok_or(Error::validation(
+            "Chain id not set, be sure to provide one on the signer",
+        ))?;
+
+        let timestamp = if self.config.use_server_time {
+            self.server_time().await?
+        } else {
+            Utc::now().timestamp()
+        };
+
+        auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
+    }
+
+    pub async fn create_api_key<Sig: Signer>(
+        &self,
+        signer: &Sig,
         nonce: Option<u32>,
     ) -> Result<Credentials> {
         let request = self
@@ -316,9 +429,11 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.send(request, Some(headers)).await
     }
+}
 
+impl ClientInner<Unauthenticated> {
     pub async fn derive_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -330,7 +445,7 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.send(request, Some(headers)).await
     }
 
     async fn create_or_derive_api_key<S: Signer>(
@@ -343,20 +458,6 @@ impl ClientInner<Unauthenticated> {
             Err(_) => self.derive_api_key(signer, nonce).await,
         }
     }
-
-    async fn create_headers<S: Signer>(&self, signer: &S, nonce: Option<u32>) -> Result<HeaderMap> {
-        let chain_id = signer.chain_id().ok_or(Error::validation(
-            "Chain id not set, be sure to provide one on the signer",
-        ))?;
-
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
-
-        auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
-    }
 }
 
 impl<S: State> Client<S> {
@@ -369,6 +470,9 @@ impl<S: State> Client<S> {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.condition_tokens.clear();
+        self.inner.fee_schedules.clear();
+        self.inner.market_params.clear();
     }
 
     pub async fn ok(&self) -> Result<String> {
@@ -377,13 +481,34 @@ impl<S: State> Client<S> {
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn server_time(&self) -> Result<Timestamp> {
         self.inner.server_time().await
     }
 
+    /// Returns the L1 headers proving control of `signer`'s wallet, for the given API key
+    /// `nonce` (zero when [`None`]).
+    ///
+    /// L1 authentication signs an EIP-712 message with the wallet's private key. It does not
+    /// depend on the request, and is only required by the endpoints that obtain L2
+    /// [`Credentials`]: `POST /auth/api-key` ([`Client::create_api_key`]) and
+    /// `GET /auth/derive-api-key` ([`Client::derive_api_key`]). The timestamp is the server's
+    /// when the client's [`Config`] enables `use_server_time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signer` has no chain ID, signing fails, or the server time cannot
+    /// be fetched.
+    pub async fn l1_headers<Sig: Signer>(
+        &self,
+        signer: &Sig,
+        nonce: Option<u32>,
+    ) -> Result<HeaderMap> {
+        self.inner.create_headers(signer, nonce).await
+    }
+
     pub async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
         let request = self
             .client()
@@ -391,7 +516,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn midpoints(&self, requests: &[MidpointRequest]) -> Result<MidpointsResponse> {
@@ -401,7 +526,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
@@ -414,7 +539,7 @@ impl<S: State> Client<S> {
             ])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn prices(&self, requests: &[PriceRequest]) -> Result<PricesResponse> {
@@ -424,7 +549,50 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
+    }
+
+    /// Fetches the best bid and ask for `token_id`, along with the midpoint and spread derived
+    /// from them, in a single `/prices` request.
+    ///
+    /// The bid is the price for [`Side::Buy`] and the ask the price for [`Side::Sell`].
+    pub async fn quote(&self, token_id: &str) -> Result<Quote> {
+        let mut quotes = self.quotes(&[token_id.to_owned()]).await?;
+
+        Ok(quotes.remove(token_id).unwrap_or_default())
+    }
+
+    /// Fetches a [`Quote`] for each of `token_ids` in a single `/prices` request, keyed by token
+    /// ID. Tokens the API has no prices for map to an empty quote.
+    pub async fn quotes(&self, token_ids: &[String]) -> Result<HashMap<String, Quote>> {
+        if token_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let requests: Vec<_> = token_ids
+            .iter()
+            .flat_map(|token_id| {
+                [Side::Buy, Side::Sell].map(|side| {
+                    PriceRequest::builder()
+                        .token_id(token_id)
+                        .side(side)
+                        .build()
+                })
+            })
+            .collect();
+        let prices = self.prices(&requests).await?.prices.unwrap_or_default();
+
+        Ok(token_ids
+            .iter()
+            .map(|token_id| {
+                let sides = prices.get(token_id);
+                let price = |side| sides.and_then(|sides| sides.get(&side)).copied();
+                (
+                    token_id.clone(),
+                    Quote::new(price(Side::Buy), price(Side::Sell)),
+                )
+            })
+            .collect())
     }
 
     pub async fn all_prices(&self) -> Result<PricesResponse> {
@@ -433,7 +601,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn price_history(
@@ -460,7 +628,7 @@ impl<S: State> Client<S> {
             req = req.query(&[("fidelity", fidelity)]);
         }
 
-        crate::request(&self.inner.client, req.build()?, None).await
+        self.inner.send(req.build()?, None).await
     }
 
     pub async fn spread(&self, request: &SpreadRequest) -> Result<SpreadResponse> {
@@ -470,7 +638,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn spreads(&self, requests: &[SpreadRequest]) -> Result<SpreadsResponse> {
@@ -480,7 +648,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse> {
@@ -501,8 +669,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response =
-            crate::request::<TickSizeResponse>(&self.inner.client, request, None).await?;
+        let response = self.inner.send::<TickSizeResponse>(request, None).await?;
 
         self.inner
             .tick_sizes
@@ -532,7 +699,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<NegRiskResponse>(&self.inner.client, request, None).await?;
+        let response = self.inner.send::<NegRiskResponse>(request, None).await?;
 
         self.inner
             .neg_risk
@@ -562,7 +729,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<FeeRateResponse>(&self.inner.client, request, None).await?;
+        let response = self.inner.send::<FeeRateResponse>(request, None).await?;
 
         self.inner
             .fee_rate_bps
@@ -627,7 +794,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn order_book(
@@ -640,7 +807,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn order_books(
@@ -653,7 +820,279 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
+    }
+
+    /// Fetches the current [`OrderBook`] for `token_id`.
+    ///
+    /// The snapshot contains every resting price level, not just the top of the book.
+    pub async fn book(&self, token_id: U256) -> Result<OrderBook> {
+        let request = OrderBookSummaryRequest {
+            token_id: token_id.to_string(),
+            side: None,
+        };
+
+        self.order_book(&request).await?.try_into()
+    }
+
+    /// Fetches the current [`OrderBook`] for each of `token_ids` in a single request.
+    ///
+    /// Like [`Client::book`], each snapshot contains every resting price level.
+    pub async fn books(&self, token_ids: &[U256]) -> Result<Vec<OrderBook>> {
+        let requests: Vec<_> = token_ids
+            .iter()
+            .map(|token_id| OrderBookSummaryRequest {
+                token_id: token_id.to_string(),
+                side: None,
+            })
+            .collect();
+
+        self.order_books(&requests)
+            .await?
+            .into_iter()
+            .map(OrderBook::try_from)
+            .collect()
+    }
+
+    /// Fetches the [`MarketParams`] needed to build an order for `token_id` in one go.
+    ///
+    /// Results are cached per token for the configured `market_params_ttl`. A fresh fetch also
+    /// refreshes the tick size, neg risk and fee rate caches used by [`Client::tick_size`],
+    /// [`Client::neg_risk`] and [`Client::fee_rate_bps`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order book or fee rate cannot be fetched.
+    pub async fn market_params(&self, token_id: &str) -> Result<MarketParams> {
+        if let Some(entry) = self.inner.market_params.get(token_id) {
+            let (params, fetched_at) = *entry;
+            if self
+                .inner
+                .config
+                .market_params_ttl
+                .is_none_or(|ttl| fetched_at.elapsed() < ttl)
+            {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(token_id = %token_id, "cache hit: market_params");
+                return Ok(params);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(token_id = %token_id, "cache miss: market_params");
+
+        // Force a fresh fee rate rather than reusing one cached indefinitely
+        self.inner.fee_rate_bps.remove(token_id);
+
+        let request = OrderBookSummaryRequest {
+            token_id: token_id.to_owned(),
+            side: None,
+        };
+        let (book, fee_rate) =
+            futures::try_join!(self.order_book(&request), self.fee_rate_bps(token_id))?;
+
+        let params = MarketParams {
+            tick_size: book.tick_size,
+            min_order_size: book.min_order_size,
+            neg_risk: book.neg_risk,
+            fee_bps: fee_rate.base_fee,
+        };
+
+        self.cache_market_params(token_id, &params);
+        self.inner
+            .market_params
+            .insert(token_id.to_owned(), (params, Instant::now()));
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(token_id = %token_id, "cached market_params");
+
+        Ok(params)
+    }
+
+    /// Seeds the per-token caches with `params` so that building and signing an order for
+    /// `token_id` needs no further lookups.
+    fn cache_market_params(&self, token_id: &str, params: &MarketParams) {
+        self.inner
+            .tick_sizes
+            .insert(token_id.to_owned(), params.tick_size);
+        self.inner
+            .neg_risk
+            .insert(token_id.to_owned(), params.neg_risk);
+        self.inner
+            .fee_rate_bps
+            .insert(token_id.to_owned(), params.fee_bps);
+    }
+
+    /// Resolves the token ID of the outcome at `outcome_index` in the market identified by
+    /// `condition_id`.
+    ///
+    /// Outcomes are indexed in the order returned by [`Client::market`] (for binary markets,
+    /// `0` is typically "Yes" and `1` is "No"). The token IDs for a condition are cached after
+    /// the first lookup; see [`Client::invalidate_internal_caches`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market cannot be fetched, one of its token IDs is malformed, or
+    /// `outcome_index` is out of range.
+    pub async fn token_id_by_condition(
+        &self,
+        condition_id: &str,
+        outcome_index: usize,
+    ) -> Result<U256> {
+        let token_ids = self.condition_token_ids(condition_id).await?;
+
+        token_ids.get(outcome_index).copied().ok_or_else(|| {
+            Error::validation(format!(
+                "Outcome index {outcome_index} is out of range for condition {condition_id} with {} outcomes",
+                token_ids.len()
+            ))
+        })
+    }
+
+    /// Fetches the current [`OrderBook`] for the outcome at `outcome_index` in the market
+    /// identified by `condition_id`.
+    ///
+    /// See [`Client::token_id_by_condition`] for how the outcome is resolved.
+    pub async fn book_by_condition(
+        &self,
+        condition_id: &str,
+        outcome_index: usize,
+    ) -> Result<OrderBook> {
+        let token_id = self
+            .token_id_by_condition(condition_id, outcome_index)
+            .await?;
+
+        self.book(token_id).await
+    }
+
+    /// Returns the price of a complete set of outcomes for the market identified by
+    /// `condition_id`, i.e. the sum of every outcome's midpoint.
+    ///
+    /// For a binary market this is `Yes + No`, which should be close to `1`. Outcomes are
+    /// resolved the same way as in [`Client::token_id_by_condition`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market or the midpoints cannot be fetched, if the market has no
+    /// outcome tokens, or if the CLOB does not report a midpoint for one of the outcomes.
+    pub async fn complete_set_price(&self, condition_id: &str) -> Result<Decimal> {
+        let token_ids: Vec<_> = self
+            .condition_token_ids(condition_id)
+            .await?
+            .iter()
+            .map(U256::to_string)
+            .collect();
+        if token_ids.is_empty() {
+            return Err(Error::validation(format!(
+                "Condition {condition_id} has no outcome tokens to price"
+            )));
+        }
+
+        let requests: Vec<_> = token_ids
+            .iter()
+            .map(|token_id| MidpointRequest {
+                token_id: token_id.clone(),
+            })
+            .collect();
+        let midpoints = self.midpoints(&requests).await?.midpoints;
+
+        token_ids.iter().try_fold(Decimal::ZERO, |sum, token_id| {
+            let midpoint = midpoints.get(token_id).ok_or_else(|| {
+                Error::validation(format!(
+                    "Missing midpoint for token {token_id} of condition {condition_id}"
+                ))
+            })?;
+
+            Ok(sum + midpoint)
+        })
+    }
+
+    /// Returns `1 - `[`Client::complete_set_price`] for the market identified by `condition_id`.
+    ///
+    /// A positive gap means the complete set trades below `1`, so buying every outcome locks in
+    /// a profit at resolution. A negative gap means it trades above `1`, so splitting collateral
+    /// into a complete set and selling each outcome is profitable. Midpoints ignore spreads and
+    /// fees, so treat the gap as an upper bound on the achievable edge.
+    ///
+    /// # Errors
+    ///
+    /// See [`Client::complete_set_price`].
+    pub async fn arbitrage_gap(&self, condition_id: &str) -> Result<Decimal> {
+        Ok(Decimal::ONE - self.complete_set_price(condition_id).await?)
+    }
+
+    /// Returns the maker and taker fees of the market identified by `condition_id`.
+    ///
+    /// Fees rarely change, so the schedule is cached per condition after the first lookup; see
+    /// [`Client::invalidate_internal_caches`]. Pass it to
+    /// [`OrderBuilder::fee_schedule`](crate::clob::order_builder::OrderBuilder::fee_schedule) to
+    /// set an order's fee rate without looking it up per token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market cannot be fetched or its fees are not a whole, non-negative
+    /// number of basis points.
+    pub async fn fee_schedule(&self, condition_id: &str) -> Result<FeeSchedule> {
+        if let Some(schedule) = self.inner.fee_schedules.get(condition_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(condition_id = %condition_id, "cache hit: fee_schedule");
+            return Ok(*schedule);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cache miss: fee_schedule");
+
+        let market = self.market(condition_id).await?;
+        let to_bps = |fee: Decimal, kind: &str| {
+            fee.to_u32()
+                .filter(|_| fee.fract().is_zero())
+                .ok_or_else(|| {
+                    Error::validation(format!(
+                        "Invalid {kind} fee {fee} for condition {condition_id}"
+                    ))
+                })
+        };
+        let schedule = FeeSchedule {
+            maker_bps: to_bps(market.maker_base_fee, "maker")?,
+            taker_bps: to_bps(market.taker_base_fee, "taker")?,
+        };
+
+        self.inner
+            .fee_schedules
+            .insert(condition_id.to_owned(), schedule);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cached fee_schedule");
+
+        Ok(schedule)
+    }
+
+    async fn condition_token_ids(&self, condition_id: &str) -> Result<Vec<U256>> {
+        if let Some(token_ids) = self.inner.condition_tokens.get(condition_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(condition_id = %condition_id, "cache hit: condition_tokens");
+            return Ok(token_ids.clone());
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cache miss: condition_tokens");
+
+        let token_ids = self
+            .market(condition_id)
+            .await?
+            .tokens
+            .iter()
+            .map(|token| U256::from_str(&token.token_id).map_err(Error::from))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner
+            .condition_tokens
+            .insert(condition_id.to_owned(), token_ids.clone());
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cached condition_tokens");
+
+        Ok(token_ids)
     }
 
     pub async fn last_trade_price(
@@ -666,7 +1105,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn last_trades_prices(
@@ -679,7 +1118,7 @@ impl<S: State> Client<S> {
             .json(token_ids)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
@@ -691,7 +1130,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn markets(&self, next_cursor: Option<String>) -> Result<Page<MarketResponse>> {
@@ -701,7 +1140,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}markets{cursor}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn sampling_markets(
@@ -717,7 +1156,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn simplified_markets(
@@ -733,7 +1172,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     pub async fn sampling_simplified_markets(
@@ -749,7 +1188,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.send(request, None).await
     }
 
     /// Returns a stream of results, using `self` to repeatedly invoke the provided closure,
@@ -797,7 +1236,14 @@ impl Client<Unauthenticated> {
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
 
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let connection = ConnectionConfig::builder()
+            .maybe_pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .maybe_pool_idle_timeout(config.pool_idle_timeout)
+            .maybe_tcp_keepalive(config.tcp_keepalive)
+            .build();
+        let client = connection
+            .apply(ReqwestClient::builder().default_headers(headers))
+            .build()?;
 
         let geoblock_host = Url::parse(
             config
@@ -815,6 +1261,9 @@ impl Client<Unauthenticated> {
                 tick_sizes: DashMap::new(),
                 neg_risk: DashMap::new(),
                 fee_rate_bps: DashMap::new(),
+                condition_tokens: DashMap::new(),
+                fee_schedules: DashMap::new(),
+                market_params: DashMap::new(),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
@@ -823,6 +1272,25 @@ impl Client<Unauthenticated> {
         })
     }
 
+    /// Creates a new unauthenticated client with the default [`Config`], configured from the
+    /// environment.
+    ///
+    /// Reads [`CLOB_HOST_VAR`] (`POLYMARKET_CLOB_HOST`) for the host, defaulting to
+    /// `https://clob.polymarket.com` when unset. To trade, build a signer from the key in
+    /// [`PRIVATE_KEY_VAR`](crate::PRIVATE_KEY_VAR) and pass it to
+    /// [`Self::authentication_builder`], calling
+    /// [`AuthenticationBuilder::funder_from_env`] to pick up a proxy wallet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is set but is not a valid URL.
+    pub fn from_env() -> Result<Client<Unauthenticated>> {
+        Self::new(
+            &host_from_env(CLOB_HOST_VAR, DEFAULT_HOST)?,
+            Config::default(),
+        )
+    }
+
     pub fn authentication_builder<S: Signer>(
         self,
         signer: &S,
@@ -841,6 +1309,12 @@ impl Client<Unauthenticated> {
 
     /// Attempts to create a new set of [`Credentials`] and returns an error if there already is one
     /// for the particular L2 header's (signer) `address` and `nonce`.
+    ///
+    /// The request is authenticated with the L1 headers of [`Client::l1_headers`]: `signer`
+    /// signs the EIP-712 `ClobAuth { address, timestamp, nonce, message }` struct in the
+    /// `ClobAuthDomain` domain, version `1`, on `signer`'s chain, with the message
+    /// `"This message attests that I control the given wallet"`. The same `nonce` later derives
+    /// the same credentials with [`Client::derive_api_key`].
     pub async fn create_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -851,6 +1325,9 @@ impl Client<Unauthenticated> {
 
     /// Attempts to derive an existing set of [`Credentials`] and returns an error if there
     /// are none for the particular L2 header's (signer) `address` and `nonce`.
+    ///
+    /// Derivation is deterministic, so the credentials returned are the ones
+    /// [`Client::create_api_key`] created for that `nonce`.
     pub async fn derive_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -885,6 +1362,9 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                condition_tokens: inner.condition_tokens,
+                fee_schedules: inner.fee_schedules,
+                market_params: inner.market_params,
                 // Reset the order parameters that were previously stored on the client
                 funder: None,
                 signature_type: SignatureType::Eoa,
@@ -898,6 +1378,34 @@ impl<K: Kind> Client<Authenticated<K>> {
         &self.inner.state
     }
 
+    /// Returns the L2 headers authenticating a `method` request to `path` (e.g. `/data/orders`)
+    /// with `body`, so it can be sent without going through this client.
+    ///
+    /// L2 authentication signs the timestamp, method, path and body with the HMAC of the API
+    /// secret. It is required by every endpoint of an authenticated client, i.e. orders,
+    /// cancellations, trades, API key management, balances and allowances, notifications and
+    /// user rewards. Builder clients also get their builder headers. A query string in `path`
+    /// is accepted but, as for the client's own requests, not signed. The timestamp is the
+    /// server's when the client's [`Config`] enables `use_server_time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` cannot be joined to the host, the credentials cannot sign, or
+    /// the server time cannot be fetched.
+    pub async fn l2_headers(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<HeaderMap> {
+        let mut request = self.client().request(method, self.host().join(path)?);
+        if let Some(body) = body {
+            request = request.body(body.to_owned());
+        }
+
+        self.create_headers(&request.build()?).await
+    }
+
     #[must_use]
     pub fn address(&self) -> Address {
         self.state().address
@@ -912,17 +1420,80 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
+    /// Deletes the API key this client is authenticated with, i.e. [`Credentials::key`] of the
+    /// credentials it was given or derived. The client can no longer authenticate afterwards, but
+    /// new credentials can be created with [`Client::create_api_key`] once deauthenticated.
     pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
+        self.delete_api_key_of(self.state()).await
+    }
+
+    /// Rotates the API key this client is authenticated with: creates new [`Credentials`] for
+    /// `signer` with `nonce`, then deletes the current key and returns the new credentials.
+    ///
+    /// Creation is authenticated with the L1 headers of [`Client::l1_headers`], so `signer` must
+    /// be the signer this client was authenticated with, and `nonce` one it has not created
+    /// credentials with yet. Deletion is authenticated with this client's L2 headers. Should it
+    /// fail, the new key is deleted again so that only the current key remains, unless that
+    /// deletion fails too. This client keeps the old credentials either way, so authenticate a
+    /// new one with those returned. Key management is rate limited like the rest of the CLOB API,
+    /// so failed rotations should be retried with a backoff rather than in a tight loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signer` is not the one this client was authenticated with, or if the
+    /// new key cannot be created or the current one deleted. If the new key cannot be deleted
+    /// again either, the error is a [`RotationIncomplete`] carrying the new credentials, both keys
+    /// being valid.
+    pub async fn rotate_api_key<Sig: Signer>(
+        &self,
+        signer: &Sig,
+        nonce: Option<u32>,
+    ) -> Result<Credentials> {
+        if signer.address() != self.address() {
+            return Err(Error::validation(format!(
+                "Signer {} is not the address {} the client is authenticated with",
+                signer.address(),
+                self.address()
+            )));
+        }
+
+        let credentials = self.inner.create_api_key(signer, nonce).await?;
+
+        if let Err(e) = self.delete_api_key().await {
+            let created = Authenticated {
+                address: self.address(),
+                credentials,
+                kind: Normal,
+            };
+            if let Err(rollback_error) = self.delete_api_key_of(&created).await {
+                return Err(RotationIncomplete {
+                    credentials: created.credentials,
+                    delete_error: e,
+                    rollback_error,
+                }
+                .into());
+            }
+            return Err(e);
+        }
+
+        Ok(credentials)
+    }
+
+    /// Deletes the API key of `state`'s credentials, which need not be this client's.
+    async fn delete_api_key_of<Kd: Kind>(
+        &self,
+        state: &Authenticated<Kd>,
+    ) -> Result<serde_json::Value> {
         let request = self
             .client()
             .request(Method::DELETE, format!("{}auth/api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let headers = self.create_headers_as(state, &request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn closed_only_mode(&self) -> Result<BanStatusResponse> {
@@ -935,7 +1506,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
@@ -951,6 +1522,9 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 
     /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
+    ///
+    /// Any alloy [`Signer`] can be used here, including remote signers such as AWS KMS or Ledger.
+    /// For signers that do not implement [`Signer`], see [`Client::sign_with`].
     #[expect(
         clippy::missing_panics_doc,
         reason = "No need to publicly document as we are guarded by the typestate pattern. \
@@ -959,14 +1533,36 @@ impl<K: Kind> Client<Authenticated<K>> {
     pub async fn sign<S: Signer>(
         &self,
         signer: &S,
-        SignableOrder { order, order_type }: SignableOrder,
+        signable_order: SignableOrder,
     ) -> Result<SignedOrder> {
-        let token_id = order.tokenId.to_string();
-        let neg_risk = self.neg_risk(&token_id).await?.neg_risk;
         let chain_id = signer
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
+        self.sign_with(chain_id, signable_order, |hash| async move {
+            Ok(signer.sign_hash(&hash).await?)
+        })
+        .await
+    }
+
+    /// Signs the provided [`SignableOrder`] by passing its EIP-712 signing hash to `sign_hash`.
+    ///
+    /// This supports key custody that is not exposed as an alloy [`Signer`] (e.g. a
+    /// `WalletConnect` session or an in-house signing service): `sign_hash` receives the 32-byte
+    /// digest of the order for `chain_id` and returns the resulting [`Signature`].
+    pub async fn sign_with<F, Fut>(
+        &self,
+        chain_id: ChainId,
+        SignableOrder { order, order_type }: SignableOrder,
+        sign_hash: F,
+    ) -> Result<SignedOrder>
+    where
+        F: FnOnce(B256) -> Fut,
+        Fut: Future<Output = Result<Signature>>,
+    {
+        let token_id = order.tokenId.to_string();
+        let neg_risk = self.neg_risk(&token_id).await?.neg_risk;
+
         let exchange_contract = contract_config(chain_id, neg_risk)
             .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
             .exchange;
@@ -979,9 +1575,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             ..Eip712Domain::default()
         };
 
-        let signature = signer
-            .sign_hash(&order.eip712_signing_hash(&domain))
-            .await?;
+        let signature = sign_hash(order.eip712_signing_hash(&domain)).await?;
 
         Ok(SignedOrder {
             order,
@@ -992,6 +1586,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 
     pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        let side = Side::try_from(order.order.side)?;
         let request = self
             .client()
             .request(Method::POST, format!("{}order", self.host()))
@@ -999,10 +1594,16 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: PostOrderResponse = self.inner.send(request, Some(headers)).await?;
+
+        Ok(response.with_fills(side))
     }
 
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        let sides = orders
+            .iter()
+            .map(|order| Side::try_from(order.order.side))
+            .collect::<Result<Vec<_>>>()?;
         let request = self
             .client()
             .request(Method::POST, format!("{}orders", self.host()))
@@ -1010,7 +1611,13 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let responses: Vec<PostOrderResponse> = self.inner.send(request, Some(headers)).await?;
+
+        Ok(responses
+            .into_iter()
+            .zip(sides)
+            .map(|(response, side)| response.with_fills(side))
+            .collect())
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
@@ -1021,7 +1628,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn orders(
@@ -1036,7 +1643,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
@@ -1047,7 +1654,224 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
+    }
+
+    /// Cancels the order `old_order_id`, then posts `new_order` in its place.
+    ///
+    /// The CLOB has no endpoint replacing an order in one step, so this sequences the two calls.
+    /// `new_order` is only posted once the cancellation is confirmed, so a quote that was filled
+    /// or cancelled in the meantime is never doubled; check
+    /// [`canceled`](ReplaceOrderResponse::canceled) to tell. A cancelled order cannot be
+    /// restored, so if posting then fails the old order stays cancelled and the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cancelling fails, in which case nothing was posted, or if posting
+    /// fails after the old order was cancelled.
+    pub async fn replace_order(
+        &self,
+        old_order_id: &str,
+        new_order: SignedOrder,
+    ) -> Result<ReplaceOrderResponse> {
+        let cancel = self.cancel_order(old_order_id).await?;
+        let canceled = cancel.canceled.iter().any(|id| id == old_order_id);
+
+        let post = if canceled {
+            Some(self.post_order(new_order).await?)
+        } else {
+            None
+        };
+
+        Ok(ReplaceOrderResponse {
+            canceled,
+            cancel,
+            post,
+        })
+    }
+
+    /// Reduces the resting order `order_id` so that `new_size` shares remain to be filled,
+    /// keeping its token, side, price, type and expiration.
+    ///
+    /// The CLOB cannot amend orders, so this cancels and replaces the order through
+    /// [`Client::replace_order`], which is not atomic: the new order loses the queue priority of
+    /// the old one, and a fill landing between the two calls is not accounted for. The
+    /// replacement is built and signed before cancelling, so an order that cannot be amended is
+    /// left untouched. The size resting afterwards is `new_size` less the response's
+    /// [`filled_size`](PostOrderResponse::filled_size).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be fetched, if `new_size` is not positive and below
+    /// the order's remaining size, if the replacement cannot be built or signed, or if the order
+    /// was not cancelled (e.g. because it filled meanwhile), in which case nothing was posted.
+    pub async fn amend_order_size<Sig: Signer>(
+        &self,
+        signer: &Sig,
+        order_id: &str,
+        new_size: Decimal,
+    ) -> Result<PostOrderResponse> {
+        let order = self.order(order_id).await?;
+        let remaining = order.original_size - order.size_matched;
+        if new_size <= Decimal::ZERO || new_size >= remaining {
+            return Err(Error::validation(format!(
+                "Unable to amend order {order_id}: New size {new_size} must be positive and below \
+                its remaining size {remaining}"
+            )));
+        }
+
+        let mut builder = self
+            .limit_order()
+            .token_id(order.asset_id)
+            .side(order.side)
+            .price(order.price)
+            .size(new_size)
+            .order_type(order.order_type);
+        if order.expiration > DateTime::<Utc>::UNIX_EPOCH {
+            builder = builder.expiration(order.expiration);
+        }
+        let new_order = self.sign(signer, builder.build().await?).await?;
+
+        let ReplaceOrderResponse { cancel, post, .. } =
+            self.replace_order(order_id, new_order).await?;
+
+        post.ok_or_else(|| {
+            let reason = cancel
+                .not_canceled
+                .get(order_id)
+                .map_or("not canceled", String::as_str);
+            Error::validation(format!("Unable to amend order {order_id}: {reason}"))
+        })
+    }
+
+    /// Splits collateral into a complete set of a market's outcomes and sells one of them, the
+    /// usual way of acquiring the other outcomes without buying them.
+    ///
+    /// The SDK does not talk to the chain itself, so `split` performs the `splitPosition` call
+    /// described by its [`SplitPositionRequest`] with the caller's own provider, and resolves to
+    /// the transaction hash once the transaction is confirmed. The sale is then posted as a FOK
+    /// market order for the `amount` shares of `token_id` received, at no less than the token's
+    /// midpoint before the split less `max_slippage`, rounded up to the tick size. If the
+    /// midpoint has fallen below that price by the time the split is confirmed, nothing is sold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request is invalid or the market cannot be looked up, in which case
+    /// `split` is not called, or the error of `split` itself. Once the split is confirmed, a sale
+    /// that is aborted, rejected or not filled returns a [`SplitUnsold`] holding the split's
+    /// transaction hash.
+    pub async fn split_and_sell<Sig, F, Fut>(
+        &self,
+        signer: &Sig,
+        request: &SplitAndSellRequest,
+        split: F,
+    ) -> Result<SplitAndSellResponse>
+    where
+        Sig: Signer,
+        F: FnOnce(SplitPositionRequest) -> Fut,
+        Fut: Future<Output = Result<B256>>,
+    {
+        let SplitAndSellRequest {
+            condition_id,
+            token_id,
+            amount,
+            max_slippage,
+        } = request;
+        if *amount <= Decimal::ZERO || max_slippage.is_sign_negative() {
+            return Err(Error::validation(format!(
+                "Unable to split and sell: Amount {amount} must be positive and max slippage \
+                {max_slippage} must not be negative"
+            )));
+        }
+        let condition = B256::from_str(condition_id)
+            .map_err(|e| Error::validation(format!("Invalid condition ID {condition_id}: {e}")))?;
+        let chain_id = signer.chain_id().ok_or(Error::validation(
+            "Chain id not set, be sure to provide one on the signer",
+        ))?;
+
+        let market = self.market(condition_id).await?;
+        if !market
+            .tokens
+            .iter()
+            .any(|token| token.token_id == *token_id)
+        {
+            return Err(Error::validation(format!(
+                "Unable to split and sell: Token {token_id} is not an outcome of market \
+                {condition_id}"
+            )));
+        }
+        let collateral_token = contract_config(chain_id, market.neg_risk)
+            .ok_or(Error::missing_contract_config(chain_id, market.neg_risk))?
+            .collateral;
+
+        let midpoint_request = MidpointRequest::builder()
+            .token_id(token_id.as_str())
+            .build();
+        let min_price = (self.midpoint(&midpoint_request).await?.mid - max_slippage)
+            .round_dp_with_strategy(
+                market.minimum_tick_size.normalize().scale(),
+                RoundingStrategy::ToPositiveInfinity,
+            );
+        if min_price <= Decimal::ZERO {
+            return Err(Error::validation(format!(
+                "Unable to split and sell: Max slippage {max_slippage} leaves no positive price \
+                to sell {token_id} at"
+            )));
+        }
+
+        let transaction_hash = split(SplitPositionRequest {
+            collateral_token,
+            parent_collection_id: B256::ZERO,
+            condition_id: condition,
+            partition: (0..market.tokens.len())
+                .map(|index| U256::ONE << index)
+                .collect(),
+            amount: U256::from(to_base_units(*amount, USDC_DECIMALS)?),
+            neg_risk: market.neg_risk,
+        })
+        .await?;
+
+        let sale = async {
+            let midpoint = self.midpoint(&midpoint_request).await?.mid;
+            if midpoint < min_price {
+                return Err(Error::validation(format!(
+                    "Unable to sell {token_id}: Midpoint moved to {midpoint}, below the minimum \
+                    price {min_price}"
+                )));
+            }
+
+            let order = self
+                .market_order()
+                .token_id(token_id.as_str())
+                .side(Side::Sell)
+                .amount(Amount::shares(*amount)?)
+                .price(min_price)
+                .order_type(OrderType::FOK)
+                .build()
+                .await?;
+            let sale = self.post_order(self.sign(signer, order).await?).await?;
+            if !sale.success {
+                return Err(Error::validation(format!(
+                    "Unable to sell {token_id}: {}",
+                    sale.error_msg.as_deref().unwrap_or("order not filled")
+                )));
+            }
+
+            Ok(sale)
+        }
+        .await;
+
+        match sale {
+            Ok(sale) => Ok(SplitAndSellResponse {
+                transaction_hash,
+                sale,
+            }),
+            Err(error) => Err(SplitUnsold {
+                transaction_hash,
+                error,
+            }
+            .into()),
+        }
     }
 
     pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
@@ -1058,7 +1882,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
@@ -1068,26 +1892,51 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
-    /// and/or [`CancelMarketOrderRequest::asset_id`]
+    /// and/or [`CancelMarketOrderRequest::asset_id`], leaving orders in other markets untouched.
+    ///
+    /// The IDs of the cancelled orders are reported in [`CancelOrdersResponse::canceled`], and
+    /// those that could not be cancelled in [`CancelOrdersResponse::not_canceled`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if neither `market` nor `asset_id` is set; use
+    /// [`Client::cancel_all_orders`] to cancel every order. Returns a [`NoOpenOrders`] error if
+    /// the market has no open orders, so callers repeating the call can tell it had nothing left
+    /// to cancel.
     pub async fn cancel_market_orders(
         &self,
-        request: &CancelMarketOrderRequest,
+        cancel: &CancelMarketOrderRequest,
     ) -> Result<CancelOrdersResponse> {
+        if cancel.market.is_none() && cancel.asset_id.is_none() {
+            return Err(Error::validation(
+                "Unable to cancel market orders without a market or asset ID",
+            ));
+        }
+
         let request = self
             .client()
             .request(
                 Method::DELETE,
                 format!("{}cancel-market-orders", self.host()),
             )
-            .json(&request)
+            .json(&cancel)
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: CancelOrdersResponse = self.inner.send(request, Some(headers)).await?;
+        if response.canceled.is_empty() && response.not_canceled.is_empty() {
+            return Err(NoOpenOrders {
+                market: cancel.market.clone(),
+                asset_id: cancel.asset_id.clone(),
+            }
+            .into());
+        }
+
+        Ok(response)
     }
 
     pub async fn trades(
@@ -1102,7 +1951,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn notifications(&self) -> Result<Vec<NotificationResponse>> {
@@ -1113,7 +1962,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn delete_notifications(&self, request: &DeleteNotificationsRequest) -> Result<()> {
@@ -1154,7 +2003,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn update_balance_allowance(
@@ -1192,7 +2041,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
@@ -1203,7 +2052,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn earnings_for_user_for_day(
@@ -1225,7 +2074,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn total_earnings_for_user_for_day(
@@ -1245,7 +2094,44 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
+    }
+
+    /// Returns the value of the user's total reward earnings for each day from `start` to `end`,
+    /// both inclusive, in date order, e.g. to chart reward accrual over time.
+    ///
+    /// The CLOB only reports earnings one day at a time, so this fetches each day with
+    /// [`Client::total_earnings_for_user_for_day`], a few at a time. Rewards may be paid in
+    /// several assets, so each earning is converted at its `asset_rate` before being summed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `end` is before `start`, or if any request fails.
+    pub async fn reward_earnings_series(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<RewardEarningPoint>> {
+        if end < start {
+            return Err(Error::validation(format!(
+                "Unable to fetch reward earnings from {start} to {end}: end is before start"
+            )));
+        }
+
+        stream::iter(start.iter_days().take_while(|date| *date <= end))
+            .map(|date| async move {
+                let earnings = self.total_earnings_for_user_for_day(date).await?;
+                Ok::<_, Error>(RewardEarningPoint {
+                    date,
+                    value: earnings
+                        .iter()
+                        .map(|earning| earning.earnings * earning.asset_rate)
+                        .sum(),
+                })
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await
     }
 
     pub async fn user_earnings_and_markets_config(
@@ -1267,7 +2153,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn reward_percentages(&self) -> Result<RewardsPercentagesResponse> {
@@ -1284,7 +2170,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn current_rewards(
@@ -1301,7 +2187,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn raw_rewards_for_market(
@@ -1319,7 +2205,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn create_builder_api_key(&self) -> Result<Credentials> {
@@ -1329,17 +2215,26 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
+        self.create_headers_as(self.state(), request).await
+    }
+
+    /// Returns the L2 headers authenticating `request` with `state`'s credentials.
+    async fn create_headers_as<Kd: Kind>(
+        &self,
+        state: &Authenticated<Kd>,
+        request: &Request,
+    ) -> Result<HeaderMap> {
         let timestamp = if self.inner.config.use_server_time {
             self.server_time().await?
         } else {
             Utc::now().timestamp()
         };
 
-        auth::l2::create_headers(self.state(), request, timestamp).await
+        auth::l2::create_headers(state, request, timestamp).await
     }
 
     fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
@@ -1351,12 +2246,18 @@ impl<K: Kind> Client<Authenticated<K>> {
             token_id: None,
             price: None,
             size: None,
+            size_rounding: None,
             amount: None,
             side: None,
             nonce: None,
             expiration: None,
             taker: None,
             order_type: None,
+            min_fill_size: None,
+            market_params: None,
+            fee_schedule: None,
+            market_status: None,
+            max_slippage: None,
             client: Client {
                 inner: Arc::clone(&self.inner),
             },
@@ -1390,6 +2291,9 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            condition_tokens: inner.condition_tokens,
+            fee_schedules: inner.fee_schedules,
+            market_params: inner.market_params,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
@@ -1409,7 +2313,7 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
@@ -1447,7 +2351,7 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.send(request, Some(headers)).await
     }
 }
 
@@ -0,0 +1,157 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`FakeClobApi` reads better at use sites than `fake::ClobApi`"
+)]
+
+//! In-memory fake of the CLOB's public market data for tests.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+
+use super::api::ClobApi;
+use super::types::Side;
+use super::types::request::{MidpointRequest, OrderBookSummaryRequest, PriceRequest};
+use super::types::response::{
+    MarketResponse, MidpointResponse, NegRiskResponse, OrderBookSummaryResponse, PriceResponse,
+    TickSizeResponse,
+};
+use crate::{Result, canned};
+
+/// A [`ClobApi`] that answers every call with a canned response instead of making HTTP requests.
+///
+/// Responses are programmed per token ID (or per token ID and side for prices, and per
+/// condition ID for markets). Each method returns a clone of the matching response and fails if
+/// none was programmed, or with an HTTP status error if it was made to fail with
+/// [`with_failure`](Self::with_failure).
+#[derive(Debug, Clone, Default)]
+pub struct FakeClobApi {
+    midpoints: HashMap<String, MidpointResponse>,
+    prices: HashMap<(String, Side), PriceResponse>,
+    order_books: HashMap<String, OrderBookSummaryResponse>,
+    tick_sizes: HashMap<String, TickSizeResponse>,
+    neg_risk: HashMap<String, NegRiskResponse>,
+    markets: HashMap<String, MarketResponse>,
+    failures: HashMap<&'static str, StatusCode>,
+}
+
+impl FakeClobApi {
+    /// Creates a fake with no canned responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the response to [`ClobApi::midpoint`] for `token_id`.
+    #[must_use]
+    pub fn with_midpoint<T: Into<String>>(
+        mut self,
+        token_id: T,
+        midpoint: MidpointResponse,
+    ) -> Self {
+        self.midpoints.insert(token_id.into(), midpoint);
+        self
+    }
+
+    /// Sets the response to [`ClobApi::price`] for `token_id` and `side`.
+    #[must_use]
+    pub fn with_price<T: Into<String>>(
+        mut self,
+        token_id: T,
+        side: Side,
+        price: PriceResponse,
+    ) -> Self {
+        self.prices.insert((token_id.into(), side), price);
+        self
+    }
+
+    /// Sets the response to [`ClobApi::order_book`] for `token_id`.
+    #[must_use]
+    pub fn with_order_book<T: Into<String>>(
+        mut self,
+        token_id: T,
+        order_book: OrderBookSummaryResponse,
+    ) -> Self {
+        self.order_books.insert(token_id.into(), order_book);
+        self
+    }
+
+    /// Sets the response to [`ClobApi::tick_size`] for `token_id`.
+    #[must_use]
+    pub fn with_tick_size<T: Into<String>>(
+        mut self,
+        token_id: T,
+        tick_size: TickSizeResponse,
+    ) -> Self {
+        self.tick_sizes.insert(token_id.into(), tick_size);
+        self
+    }
+
+    /// Sets the response to [`ClobApi::neg_risk`] for `token_id`.
+    #[must_use]
+    pub fn with_neg_risk<T: Into<String>>(
+        mut self,
+        token_id: T,
+        neg_risk: NegRiskResponse,
+    ) -> Self {
+        self.neg_risk.insert(token_id.into(), neg_risk);
+        self
+    }
+
+    /// Sets the response to [`ClobApi::market`] for `condition_id`.
+    #[must_use]
+    pub fn with_market<T: Into<String>>(mut self, condition_id: T, market: MarketResponse) -> Self {
+        self.markets.insert(condition_id.into(), market);
+        self
+    }
+
+    /// Makes every call to the [`ClobApi`] method named `method`, e.g. `"midpoint"`, fail with the
+    /// error the real client returns when the API answers with `status_code`.
+    #[must_use]
+    pub fn with_failure(mut self, method: &'static str, status_code: StatusCode) -> Self {
+        self.failures.insert(method, status_code);
+        self
+    }
+}
+
+#[async_trait]
+impl ClobApi for FakeClobApi {
+    async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
+        canned(
+            &self.failures,
+            self.midpoints.get(&request.token_id),
+            "midpoint",
+        )
+    }
+
+    async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
+        canned(
+            self.prices.get(&(request.token_id.clone(), request.side)),
+            "price",
+        )
+    }
+
+    async fn order_book(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> Result<OrderBookSummaryResponse> {
+        canned(
+            &self.failures,
+            self.order_books.get(&request.token_id),
+            "order_book",
+        )
+    }
+
+    async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse> {
+        canned(&self.failures, self.tick_sizes.get(token_id), "tick_size")
+    }
+
+    async fn neg_risk(&self, token_id: &str) -> Result<NegRiskResponse> {
+        canned(&self.failures, self.neg_risk.get(token_id), "neg_risk")
+    }
+
+    async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
+        canned(&self.failures, self.markets.get(condition_id), "market")
+    }
+}
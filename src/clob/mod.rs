@@ -1,7 +1,24 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`ClobApi` is re-exported next to the `Client` it abstracts over"
+)]
+#![expect(
+    clippy::same_name_method,
+    reason = "`ClobApi` mirrors `Client` methods so either can be used interchangeably"
+)]
+
+pub mod api;
+pub mod book;
 pub mod client;
+#[cfg(feature = "test-util")]
+pub mod fake;
 pub mod order_builder;
+pub mod pricing;
+#[cfg(feature = "quotes")]
+pub mod quotes;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
 
+pub use api::ClobApi;
 pub use client::{Client, Config};
@@ -5,6 +5,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use rand::Rng as _;
+use rust_decimal::RoundingStrategy;
 use rust_decimal::prelude::ToPrimitive as _;
 
 use crate::Result;
@@ -12,10 +13,13 @@ use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
 use crate::clob::types::request::OrderBookSummaryRequest;
+use crate::clob::types::response::{
+    FeeSchedule, MarketParams, MarketStatus, OrderBookSummaryResponse, OrderSummary,
+};
 use crate::clob::types::{
-    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
+    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType, TickSize,
 };
-use crate::error::Error;
+use crate::error::{Error, Validation};
 use crate::types::{Address, Decimal};
 
 pub(crate) const USDC_DECIMALS: u32 = 6;
@@ -43,12 +47,18 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) token_id: Option<String>,
     pub(crate) price: Option<Decimal>,
     pub(crate) size: Option<Decimal>,
+    pub(crate) size_rounding: Option<RoundingStrategy>,
     pub(crate) amount: Option<Amount>,
     pub(crate) side: Option<Side>,
     pub(crate) nonce: Option<u64>,
     pub(crate) expiration: Option<DateTime<Utc>>,
     pub(crate) taker: Option<Address>,
     pub(crate) order_type: Option<OrderType>,
+    pub(crate) min_fill_size: Option<Decimal>,
+    pub(crate) market_params: Option<MarketParams>,
+    pub(crate) fee_schedule: Option<FeeSchedule>,
+    pub(crate) market_status: Option<MarketStatus>,
+    pub(crate) max_slippage: Option<Decimal>,
     pub(crate) funder: Option<Address>,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
@@ -81,6 +91,11 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
+    /// Sets the `taker` address, the only counterparty allowed to fill this order, e.g. for a
+    /// negotiated RFQ fill. This is an optional field.
+    ///
+    /// The taker is part of the signed order, so the exchange enforces it. It defaults to the
+    /// zero address, which leaves the order public. It cannot be the order's maker.
     #[must_use]
     pub fn taker(mut self, taker: Address) -> Self {
         self.taker = Some(taker);
@@ -92,6 +107,144 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self.order_type = Some(order_type);
         self
     }
+
+    /// Sets the [`SignatureType`] this order is signed with, overriding the one the client was
+    /// authenticated with. The order's `maker` follows it: the signer itself for
+    /// [`SignatureType::Eoa`], and the funder for the proxy types, which then requires one.
+    #[must_use]
+    pub fn signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = signature_type;
+        self
+    }
+
+    /// Sets the funder, the proxy wallet holding the funds for this order, overriding the one the
+    /// client was authenticated with. Only used with the [`SignatureType::Proxy`] and
+    /// [`SignatureType::GnosisSafe`] signature types.
+    #[must_use]
+    pub fn funder(mut self, funder: Address) -> Self {
+        self.funder = Some(funder);
+        self
+    }
+
+    /// Sets the [`MarketParams`] for this builder's token, typically obtained from
+    /// [`Client::market_params`]. This is an optional field.
+    ///
+    /// When set, the tick size and fee rate are taken from `market_params` instead of being
+    /// looked up, and the order size is checked against its minimum order size. The params only
+    /// apply to this build: they are not written to the client's caches, so signing still looks
+    /// up the token's neg risk flag.
+    #[must_use]
+    pub fn market_params(mut self, market_params: MarketParams) -> Self {
+        self.market_params = Some(market_params);
+        self
+    }
+
+    /// Sets the [`FeeSchedule`] of this builder's market, typically obtained from
+    /// [`Client::fee_schedule`]. This is an optional field.
+    ///
+    /// When set, the order is signed with its taker fee rather than the fee rate looked up for
+    /// the token (or provided by [`MarketParams`]).
+    #[must_use]
+    pub fn fee_schedule(mut self, fee_schedule: FeeSchedule) -> Self {
+        self.fee_schedule = Some(fee_schedule);
+        self
+    }
+
+    /// Sets the [`MarketStatus`] of this builder's market. This is an optional field.
+    ///
+    /// When set, building (and validating) fails if the market is archived, closed or not
+    /// accepting orders, or the caller is geoblocked, instead of the CLOB rejecting the order once
+    /// submitted.
+    #[must_use]
+    pub fn market_status(mut self, market_status: MarketStatus) -> Self {
+        self.market_status = Some(market_status);
+        self
+    }
+
+    /// Resolves the minimum tick size and fee rate in basis points for `token_id`, preferring
+    /// [`FeeSchedule`] and [`MarketParams`] when they were provided.
+    async fn tick_size_and_fee_rate(&self, token_id: &str) -> Result<(Decimal, u32)> {
+        let fee_override = self.fee_schedule.map(|schedule| schedule.taker_bps);
+
+        if let Some(params) = &self.market_params {
+            return Ok((
+                params.tick_size.as_decimal(),
+                fee_override.unwrap_or(params.fee_bps),
+            ));
+        }
+
+        let minimum_tick_size = self
+            .client
+            .tick_size(token_id)
+            .await?
+            .minimum_tick_size
+            .as_decimal();
+        let fee_rate = match fee_override {
+            Some(fee_rate) => fee_rate,
+            None => self.client.fee_rate_bps(token_id).await?.base_fee,
+        };
+
+        Ok((minimum_tick_size, fee_rate))
+    }
+
+    /// Ensures `shares` meets the minimum order size from [`MarketParams`], if provided.
+    fn ensure_min_order_size(&self, shares: Decimal) -> Result<()> {
+        match self.min_order_size_problem(shares) {
+            Some(problem) => Err(problem.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn min_order_size_problem(&self, shares: Decimal) -> Option<Validation> {
+        let params = self.market_params.as_ref()?;
+        (shares < params.min_order_size).then(|| {
+            invalid(format!(
+                "Unable to build Order: Size {shares} is below the minimum order size {}",
+                params.min_order_size
+            ))
+        })
+    }
+
+    /// Collects the problems with the fields shared by every order kind.
+    fn common_problems(&self) -> Vec<Validation> {
+        let mut problems = Vec::new();
+
+        if self.token_id.is_none() {
+            problems.push(missing("token ID"));
+        }
+
+        if self.side.is_none() {
+            problems.push(missing("token side"));
+        }
+
+        match (self.maker(), self.taker) {
+            (Ok(maker), Some(taker)) if maker == taker => problems.push(invalid(format!(
+                "Cannot have the maker {maker} as the taker of its own order"
+            ))),
+            (maker, _) => problems.extend(maker.err()),
+        }
+
+        if let Some(reason) = self.market_status.and_then(MarketStatus::problem) {
+            problems.push(invalid(reason));
+        }
+
+        problems
+    }
+
+    /// Returns the `maker` of the order, which holds the funds: the signer for
+    /// [`SignatureType::Eoa`] orders, and the funder otherwise.
+    fn maker(&self) -> std::result::Result<Address, Validation> {
+        match (self.signature_type, self.funder) {
+            (SignatureType::Eoa, _) => Ok(self.signer),
+            (sig, None) => Err(invalid(format!(
+                "Must have a funder address with a {sig} signature type"
+            ))),
+            (sig, Some(Address::ZERO)) => Err(invalid(format!(
+                "Cannot have a zero funder address with a {sig} signature type"
+            ))),
+            (_, Some(funder)) => Ok(funder),
+        }
+    }
 }
 
 impl<K: AuthKind> OrderBuilder<Limit, K> {
@@ -109,91 +262,127 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         self
     }
 
-    /// Validates and transforms this limit builder into a [`SignableOrder`]
-    #[cfg_attr(
-        feature = "tracing",
-        tracing::instrument(skip(self), err(level = "warn"))
-    )]
-    pub async fn build(self) -> Result<SignableOrder> {
-        let Some(token_id) = self.token_id.clone() else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing token ID",
-            ));
-        };
+    /// Rounds the size to the maximum lot size precision (2 decimal places) with `strategy`,
+    /// instead of rejecting sizes with more decimal places.
+    ///
+    /// The rounded size is the one checked against the minimum order size and signed, so use
+    /// [`RoundingStrategy::ToZero`] to never exceed the intended size, or
+    /// [`RoundingStrategy::AwayFromZero`] to stay above a minimum order size.
+    #[must_use]
+    pub fn round_size(mut self, strategy: RoundingStrategy) -> Self {
+        self.size_rounding = Some(strategy);
+        self
+    }
 
-        let Some(side) = self.side else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing token side",
-            ));
-        };
+    /// Returns the size of the order, rounded as set by [`round_size`](Self::round_size).
+    fn rounded_size(&self) -> Option<Decimal> {
+        let size = self.size?;
 
-        let Some(price) = self.price else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing price",
-            ));
-        };
+        Some(match self.size_rounding {
+            Some(strategy) => size.round_dp_with_strategy(LOT_SIZE_SCALE, strategy),
+            None => size,
+        })
+    }
 
-        if price.is_sign_negative() {
-            return Err(Error::validation(format!(
+    /// Collects the problems [`build`](Self::build) rejects this limit builder for before
+    /// quantizing it, checking the price against `minimum_tick_size` when it is known.
+    fn problems(&self, minimum_tick_size: Option<Decimal>) -> Vec<Validation> {
+        let mut problems = self.common_problems();
+
+        match self.price {
+            None => problems.push(missing("price")),
+            Some(price) if price.is_sign_negative() => problems.push(invalid(format!(
                 "Unable to build Order due to negative price {price}"
-            )));
+            ))),
+            Some(price) => {
+                if let Some(minimum_tick_size) = minimum_tick_size {
+                    problems.extend(limit_price_problems(price, minimum_tick_size));
+                }
+            }
         }
 
-        let fee_rate = self.client.fee_rate_bps(&token_id).await?;
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-
-        let decimals = minimum_tick_size.scale();
+        match self.rounded_size() {
+            None => problems.push(missing("size")),
+            Some(size) => {
+                if size.scale() > LOT_SIZE_SCALE {
+                    problems.push(invalid(format!(
+                        "Unable to build Order: Size {size} has {} decimal places. Maximum lot size is {LOT_SIZE_SCALE}",
+                        size.scale()
+                    )));
+                }
 
-        if price.scale() > minimum_tick_size.scale() {
-            return Err(Error::validation(format!(
-                "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
-                {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
-                price.scale(),
-                minimum_tick_size.scale()
-            )));
+                if size.is_zero() || size.is_sign_negative() {
+                    problems.push(invalid(format!(
+                        "Unable to build Order due to negative size {size}"
+                    )));
+                } else {
+                    problems.extend(self.min_order_size_problem(size));
+                }
+            }
         }
 
-        if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
-            return Err(Error::validation(format!(
-                "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
-            )));
+        let order_type = self.order_type.unwrap_or(OrderType::GTC);
+        if let Some(expiration) = self.expiration
+            && !matches!(order_type, OrderType::GTD)
+            && expiration > DateTime::<Utc>::UNIX_EPOCH
+        {
+            problems.push(invalid("Only GTD orders may have a non-zero expiration"));
         }
 
-        let Some(size) = self.size else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing size",
-            ));
-        };
+        problems
+    }
 
-        if size.scale() > LOT_SIZE_SCALE {
-            return Err(Error::validation(format!(
-                "Unable to build Order: Size {size} has {} decimal places. Maximum lot size is {LOT_SIZE_SCALE}",
-                size.scale()
+    /// Checks this limit builder for every problem that can be detected without querying the
+    /// CLOB, rather than stopping at the first one like [`build`](Self::build) does.
+    ///
+    /// The price is checked against the tick size, and the size against the minimum order size,
+    /// only when [`MarketParams`] were provided. Unlike `build`, this also reports GTD orders
+    /// whose expiration has already passed, which the CLOB would reject.
+    pub fn validate(&self) -> std::result::Result<(), Vec<Validation>> {
+        let minimum_tick_size = self
+            .market_params
+            .as_ref()
+            .map(|params| params.tick_size.as_decimal());
+        let mut problems = self.problems(minimum_tick_size);
+
+        if let Some(expiration) = self.expiration
+            && matches!(self.order_type, Some(OrderType::GTD))
+            && expiration <= Utc::now()
+        {
+            problems.push(invalid(format!(
+                "Unable to build Order: Expiration {expiration} has already passed"
             )));
         }
 
-        if size.is_zero() || size.is_sign_negative() {
-            return Err(Error::validation(format!(
-                "Unable to build Order due to negative size {size}"
-            )));
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
         }
+    }
+
+    /// Validates and transforms this limit builder into a [`SignableOrder`]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), err(level = "warn"))
+    )]
+    pub async fn build(self) -> Result<SignableOrder> {
+        ensure_no_problems(self.problems(None))?;
+
+        let token_id = self.token_id.clone().ok_or_else(|| missing("token ID"))?;
+        let side = self.side.ok_or_else(|| missing("token side"))?;
+        let price = self.price.ok_or_else(|| missing("price"))?;
+        let size = self.rounded_size().ok_or_else(|| missing("size"))?;
 
+        let (minimum_tick_size, fee_rate_bps) = self.tick_size_and_fee_rate(&token_id).await?;
+        ensure_no_problems(limit_price_problems(price, minimum_tick_size))?;
+
+        let decimals = minimum_tick_size.scale();
         let nonce = self.nonce.unwrap_or(0);
         let expiration = self.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
         let taker = self.taker.unwrap_or(Address::ZERO);
         let order_type = self.order_type.unwrap_or(OrderType::GTC);
 
-        if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
-            return Err(Error::validation(
-                "Only GTD orders may have a non-zero expiration",
-            ));
-        }
-
         // When buying `YES` tokens, the user will "make" `size` * `price` USDC and "take"
         // `size` `YES` tokens, and vice versa for sells. We have to truncate the notional values
         // to the combined precision of the tick size _and_ the lot size. This is to ensure that
@@ -203,29 +392,21 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         // e.g. User submits a limit order to buy 100 `YES` tokens at $0.34.
         // This means they will take/receive 100 `YES` tokens, make/give up 34 USDC. This means that
         // the `taker_amount` is `100000000` and the `maker_amount` of `34000000`.
-        let (taker_amount, maker_amount) = match side {
-            Side::Buy => (
-                size,
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-            ),
-            Side::Sell => (
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-                size,
-            ),
-            side => return Err(Error::validation(format!("Invalid side: {side}"))),
-        };
+        let (maker_amount, taker_amount) =
+            quantize(price, Amount(AmountInner::Shares(size)), side, decimals)?;
 
+        let maker = self.maker()?;
         let salt = to_ieee_754_int((self.salt_generator)());
 
         let order = Order {
             salt: U256::from(salt),
-            maker: self.funder.unwrap_or(self.signer),
+            maker,
             taker,
             tokenId: U256::from_str(&token_id)?,
-            makerAmount: U256::from(to_fixed_u128(maker_amount)),
-            takerAmount: U256::from(to_fixed_u128(taker_amount)),
+            makerAmount: U256::from(to_base_units(maker_amount, USDC_DECIMALS)?),
+            takerAmount: U256::from(to_base_units(taker_amount, USDC_DECIMALS)?),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::from(expiration.timestamp().to_u64().ok_or(Error::validation(
@@ -256,6 +437,44 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         self
     }
 
+    /// Sets the minimum number of shares a [`OrderType::FAK`] order must fill. This is an
+    /// optional field.
+    ///
+    /// When set, [`build`](Self::build) checks the current book and rejects the order if fewer
+    /// than `min_fill_size` shares could be filled immediately at the order's price.
+    #[must_use]
+    pub fn min_fill_size(mut self, min_fill_size: Decimal) -> Self {
+        self.min_fill_size = Some(min_fill_size);
+        self
+    }
+
+    /// Sets how far from the book's midpoint this market order may fill, e.g. `0.03` to never
+    /// buy more than 3¢ above (or sell more than 3¢ below) the midpoint. This is an optional
+    /// field, which cannot be combined with [`price`](Self::price).
+    ///
+    /// When set, [`build`](Self::build) signs the order at the worst price allowed by the cap,
+    /// aligned to the tick size, rather than at the price needed to fill the whole amount, and
+    /// rejects the order if the current book cannot fill it within the cap.
+    #[must_use]
+    pub fn max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    fn max_slippage_problem(&self) -> Option<Validation> {
+        let max_slippage = self.max_slippage?;
+
+        if self.price.is_some() {
+            Some(invalid("Cannot set both `price` and `max_slippage`"))
+        } else if max_slippage.is_sign_negative() {
+            Some(invalid(format!(
+                "`max_slippage` {max_slippage} must not be negative"
+            )))
+        } else {
+            None
+        }
+    }
+
     // Attempts to calculate the market price from the top of the book for the particular token.
     // - Uses an orderbook depth search to find the cutoff price:
     //   - BUY + USDC: walk asks until notional >= USDC
@@ -280,11 +499,10 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             })
             .await?;
 
-        if !matches!(order_type, OrderType::FAK | OrderType::FOK) {
-            return Err(Error::validation(
-                "Cannot set an order type other than FAK/FOK for a market order",
-            ));
-        }
+        let bound = self
+            .max_slippage
+            .map(|max_slippage| slippage_bound(&book, side, max_slippage))
+            .transpose()?;
 
         let (levels, amount) = match side {
             Side::Buy => (book.asks, amount.0),
@@ -313,6 +531,21 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             (sum >= amount.as_inner()).then_some(level.price)
         });
 
+        if let Some(bound) = bound {
+            let within = |price: Decimal| match side {
+                Side::Buy => price <= bound,
+                _ => price >= bound,
+            };
+
+            return match cutoff_price {
+                Some(price) if within(price) => Ok(bound),
+                _ => Err(Error::validation(format!(
+                    "Unable to fill order for {token_id} at {} within the max slippage price {bound}",
+                    amount.as_inner()
+                ))),
+            };
+        }
+
         match cutoff_price {
             Some(price) => Ok(price),
             None if matches!(order_type, OrderType::FOK) => Err(Error::validation(format!(
@@ -323,53 +556,137 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         }
     }
 
+    // Rejects the order if the book cannot immediately fill at least `min_fill_size` of the
+    // order's `shares` at `price`.
+    async fn ensure_min_fill(
+        &self,
+        side: Side,
+        price: Decimal,
+        shares: Decimal,
+        min_fill_size: Decimal,
+    ) -> Result<()> {
+        if min_fill_size > shares {
+            return Err(Error::validation(format!(
+                "`min_fill_size` {min_fill_size} must be at most the order size {shares}"
+            )));
+        }
+
+        let token_id = self
+            .token_id
+            .as_ref()
+            .expect("Token ID was already validated in `build`");
+
+        let book = self
+            .client
+            .order_book(&OrderBookSummaryRequest {
+                token_id: token_id.to_owned(),
+                side: None,
+            })
+            .await?;
+
+        let levels = match side {
+            Side::Buy => book.asks,
+            _ => book.bids,
+        };
+        let fillable = fillable_shares(&levels, side, price, shares);
+
+        if fillable < min_fill_size {
+            return Err(Error::validation(format!(
+                "Only {fillable} of the minimum {min_fill_size} shares for {token_id} can fill at {price}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks this market builder for every problem that can be detected without querying the
+    /// CLOB, rather than stopping at the first one like [`build`](Self::build) does.
+    ///
+    /// The price, if set, is checked against the tick size, and an amount in shares against the
+    /// minimum order size, only when [`MarketParams`] were provided.
+    pub fn validate(&self) -> std::result::Result<(), Vec<Validation>> {
+        let mut problems = self.common_problems();
+        let order_type = self.order_type.unwrap_or(OrderType::FAK);
+
+        match self.amount {
+            None => problems.push(missing("amount")),
+            Some(Amount(AmountInner::Usdc(_))) if self.side == Some(Side::Sell) => {
+                problems.push(invalid(
+                    "Sell Orders must specify their `amount`s in shares",
+                ));
+            }
+            Some(Amount(AmountInner::Shares(shares))) => {
+                problems.extend(self.min_order_size_problem(shares));
+            }
+            Some(_) => {}
+        }
+
+        if !matches!(order_type, OrderType::FAK | OrderType::FOK) {
+            problems.push(invalid(
+                "Cannot set an order type other than FAK/FOK for a market order",
+            ));
+        }
+
+        if let (Some(price), Some(params)) = (self.price, &self.market_params) {
+            let minimum_tick_size = params.tick_size.as_decimal();
+            problems.extend(price_range_problem(
+                price.trunc_with_scale(minimum_tick_size.scale()),
+                minimum_tick_size,
+            ));
+        }
+
+        problems.extend(self.max_slippage_problem());
+
+        if let Some(min_fill_size) = self.min_fill_size {
+            if !matches!(order_type, OrderType::FAK) {
+                problems.push(invalid("`min_fill_size` can only be set for FAK orders"));
+            }
+
+            if min_fill_size <= Decimal::ZERO {
+                problems.push(invalid(format!(
+                    "`min_fill_size` {min_fill_size} must be positive"
+                )));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
     /// Validates and transforms this market builder into a [`SignableOrder`]
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self), err(level = "warn"))
     )]
     pub async fn build(self) -> Result<SignableOrder> {
-        let Some(token_id) = self.token_id.clone() else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing token ID",
-            ));
-        };
-
-        let Some(side) = self.side else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing token side",
-            ));
-        };
+        if let Err(problems) = self.validate() {
+            ensure_no_problems(problems)?;
+        }
 
-        let amount = self
-            .amount
-            .ok_or_else(|| Error::validation("Unable to build Order due to missing amount"))?;
+        let token_id = self.token_id.clone().ok_or_else(|| missing("token ID"))?;
+        let side = self.side.ok_or_else(|| missing("token side"))?;
+        let amount = self.amount.ok_or_else(|| missing("amount"))?;
 
         let nonce = self.nonce.unwrap_or(0);
         let taker = self.taker.unwrap_or(Address::ZERO);
-
         let order_type = self.order_type.unwrap_or(OrderType::FAK);
+
         let price = match self.price {
             Some(price) => price,
             None => self.calculate_price(order_type).await?,
         };
 
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-        let fee_rate = self.client.fee_rate_bps(&token_id).await?;
+        let (minimum_tick_size, fee_rate_bps) = self.tick_size_and_fee_rate(&token_id).await?;
 
         let decimals = minimum_tick_size.scale();
 
         // Ensure that the market price returned internally is truncated to our tick size
         let price = price.trunc_with_scale(decimals);
-        if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
-            return Err(Error::validation(format!(
-                "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
-            )));
+        if let Some(problem) = price_range_problem(price, minimum_tick_size) {
+            return Err(problem.into());
         }
 
         // When buying `YES` tokens, the user will "make" `USDC` dollars and "take"
@@ -388,47 +705,31 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         // `market_price` of $0.34. This means that they will take/receive $34, make/give up 100
         // `YES` tokens. This means that the `taker_amount` is `34000000` and the `maker_amount` is
         // `100000000`.
-        let raw_amount = amount.as_inner();
+        let (maker_amount, taker_amount) = quantize(price, amount, side, decimals)?;
 
-        let (taker_amount, maker_amount) = match (side, amount.0) {
-            // Spend USDC to buy shares
-            (Side::Buy, AmountInner::Usdc(_)) => {
-                let shares = (raw_amount / price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (shares, raw_amount)
-            }
-
-            // Buy N shares: use cutoff `price` derived from ask depth
-            (Side::Buy, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (raw_amount, usdc)
-            }
-
-            // Sell N shares for USDC
-            (Side::Sell, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (usdc, raw_amount)
-            }
-
-            (Side::Sell, AmountInner::Usdc(_)) => {
-                return Err(Error::validation(
-                    "Sell Orders must specify their `amount`s in shares",
-                ));
-            }
-
-            (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
+        let shares = match side {
+            Side::Buy => taker_amount,
+            _ => maker_amount,
         };
+        self.ensure_min_order_size(shares)?;
+
+        if let Some(min_fill_size) = self.min_fill_size {
+            self.ensure_min_fill(side, price, shares, min_fill_size)
+                .await?;
+        }
 
+        let maker = self.maker()?;
         let salt = to_ieee_754_int((self.salt_generator)());
 
         let order = Order {
             salt: U256::from(salt),
-            maker: self.funder.unwrap_or(self.signer),
+            maker,
             taker,
             tokenId: U256::from_str(&token_id)?,
-            makerAmount: U256::from(to_fixed_u128(maker_amount)),
-            takerAmount: U256::from(to_fixed_u128(taker_amount)),
+            makerAmount: U256::from(to_base_units(maker_amount, USDC_DECIMALS)?),
+            takerAmount: U256::from(to_base_units(taker_amount, USDC_DECIMALS)?),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::ZERO,
@@ -442,14 +743,241 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
     }
 }
 
-/// Removes trailing zeros, truncates to [`USDC_DECIMALS`] decimal places, and quanitizes as an
-/// integer.
-fn to_fixed_u128(d: Decimal) -> u128 {
-    d.normalize()
-        .trunc_with_scale(USDC_DECIMALS)
-        .mantissa()
-        .to_u128()
-        .expect("The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided")
+fn invalid<S: Into<String>>(reason: S) -> Validation {
+    Validation {
+        reason: reason.into(),
+    }
+}
+
+fn missing(field: &str) -> Validation {
+    invalid(format!("Unable to build Order due to missing {field}"))
+}
+
+/// Fails with the first of `problems`, if any.
+fn ensure_no_problems(problems: Vec<Validation>) -> Result<()> {
+    match problems.into_iter().next() {
+        Some(problem) => Err(problem.into()),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `price` is within the range a market with `minimum_tick_size` allows.
+fn price_range_problem(price: Decimal, minimum_tick_size: Decimal) -> Option<Validation> {
+    (price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size).then(|| {
+        invalid(format!(
+            "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
+        ))
+    })
+}
+
+/// Checks that the limit `price` is a multiple of `minimum_tick_size` within its allowed range.
+fn limit_price_problems(price: Decimal, minimum_tick_size: Decimal) -> Vec<Validation> {
+    let mut problems = Vec::new();
+
+    if price.scale() > minimum_tick_size.scale() {
+        problems.push(invalid(format!(
+            "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
+            {minimum_tick_size} has {} decimal places. Price decimal places <= minimum tick size decimal places",
+            price.scale(),
+            minimum_tick_size.scale()
+        )));
+    }
+
+    problems.extend(price_range_problem(price, minimum_tick_size));
+
+    problems
+}
+
+/// Returns the worst price `side` may trade at on `book` within `max_slippage` of its midpoint,
+/// aligned to the tick size towards the midpoint so that it never exceeds the cap.
+fn slippage_bound(
+    book: &OrderBookSummaryResponse,
+    side: Side,
+    max_slippage: Decimal,
+) -> Result<Decimal> {
+    // Levels are ordered from worst to best price
+    let (Some(best_bid), Some(best_ask)) = (book.bids.last(), book.asks.last()) else {
+        return Err(Error::validation(format!(
+            "One side of the book for {} is empty which means there is no midpoint",
+            book.asset_id
+        )));
+    };
+
+    let midpoint = (best_bid.price + best_ask.price) / Decimal::TWO;
+    let tick_size = book.tick_size.as_decimal();
+    let scale = tick_size.scale();
+
+    let bound = match side {
+        Side::Buy => {
+            (midpoint + max_slippage).round_dp_with_strategy(scale, RoundingStrategy::ToZero)
+        }
+        _ => {
+            (midpoint - max_slippage).round_dp_with_strategy(scale, RoundingStrategy::AwayFromZero)
+        }
+    };
+    Ok(bound.clamp(tick_size, Decimal::ONE - tick_size))
+}
+
+/// Sums the size of the opposing `levels` (ordered from worst to best price) that cross `price`,
+/// capped at the order's `shares`.
+fn fillable_shares(
+    levels: &[OrderSummary],
+    side: Side,
+    price: Decimal,
+    shares: Decimal,
+) -> Decimal {
+    let mut fillable = Decimal::ZERO;
+
+    for level in levels.iter().rev() {
+        let crosses = match side {
+            Side::Buy => level.price <= price,
+            _ => level.price >= price,
+        };
+        if !crosses {
+            break;
+        }
+
+        fillable += level.size;
+        if fillable >= shares {
+            return shares;
+        }
+    }
+
+    fillable
+}
+
+/// Scales a human-readable `amount` (e.g. `20.1` shares) into integer base units with `decimals`
+/// decimal places (e.g. `20100000` for 6 decimals). Any precision beyond `decimals` is truncated.
+///
+/// All arithmetic is performed on [`Decimal`] and `u128`, so no precision is lost through `f64`
+/// rounding, and `decimals` is not limited to the 28 decimal places a [`Decimal`] can hold.
+///
+/// # Errors
+///
+/// Returns an error if `amount` is negative, since base units are unsigned, or if the base units
+/// do not fit in a `u128`.
+pub fn to_base_units(amount: Decimal, decimals: u32) -> Result<u128> {
+    if amount < Decimal::ZERO {
+        return Err(Error::validation(format!(
+            "Amount {amount} is negative, but base units are unsigned"
+        )));
+    }
+
+    let truncated = amount.trunc_with_scale(decimals).normalize();
+    let overflow = || {
+        Error::validation(format!(
+            "Amount {amount} with {decimals} decimal places overflows the base units"
+        ))
+    };
+
+    let mantissa = truncated.mantissa().to_u128().ok_or_else(overflow)?;
+    let factor = 10_u128
+        .checked_pow(decimals - truncated.scale())
+        .ok_or_else(overflow)?;
+
+    mantissa.checked_mul(factor).ok_or_else(overflow)
+}
+
+/// Returns every price in `[min, max]` that is a multiple of `tick_size`, in ascending order.
+///
+/// Bounds that are not tick-aligned are rounded inwards (`min` up, `max` down), so e.g. a range
+/// of `0.455..=0.5` at a tick size of `0.01` starts at `0.46`. Use
+/// [`TickSize::as_decimal`](crate::clob::types::TickSize::as_decimal) to ladder orders at a
+/// market's tick size.
+///
+/// # Errors
+///
+/// Returns an error if `tick_size` is not positive or `min` is greater than `max`.
+pub fn price_levels(
+    min: Decimal,
+    max: Decimal,
+    tick_size: Decimal,
+) -> Result<impl Iterator<Item = Decimal>> {
+    if tick_size <= Decimal::ZERO {
+        return Err(Error::validation(format!(
+            "Tick size must be positive, found {tick_size}"
+        )));
+    }
+    if min > max {
+        return Err(Error::validation(format!(
+            "Minimum price {min} must not be greater than maximum price {max}"
+        )));
+    }
+
+    let first = (min / tick_size).ceil();
+    let last = (max / tick_size).floor();
+
+    Ok(
+        std::iter::successors(Some(first), |level| Some(level + Decimal::ONE))
+            .take_while(move |level| *level <= last)
+            .map(move |level| level * tick_size),
+    )
+}
+
+/// Returns the `(maker_amount, taker_amount)` of an order for `amount` at `price` on `side`,
+/// quantized exactly as the order builders do before scaling them to 6 decimal base units with
+/// [`to_base_units`].
+///
+/// Shares given by `amount` are used as is, [`Amount`] having already capped them at 2 decimal
+/// places, while USDC is truncated to the same 2 decimal places. The other leg is truncated to the
+/// decimal places of `tick_size` plus the 2 of the lot size, so the order snaps to the precision
+/// of resting orders:
+///
+/// | `side` | `amount` | maker amount                      | taker amount                      |
+/// |--------|----------|-----------------------------------|-----------------------------------|
+/// | Buy    | shares   | `shares * price`, truncated       | `shares`                          |
+/// | Buy    | USDC     | `usdc`, truncated                 | `usdc / price`, truncated         |
+/// | Sell   | shares   | `shares`                          | `shares * price`, truncated       |
+///
+/// e.g. buying 100 shares at `0.34` with a tick size of `0.01` makes 34 USDC and takes 100
+/// shares, while spending 100 USDC at the same price takes `294.1176` shares. The order type
+/// plays no part. `price` is expected to be a multiple of `tick_size`, which the builders
+/// enforce.
+///
+/// # Errors
+///
+/// Returns an error if `price` is not positive, `side` is [`Side::Unknown`] or a sell is given an
+/// amount in USDC.
+pub fn quantize_amounts(
+    price: Decimal,
+    amount: Amount,
+    side: Side,
+    tick_size: TickSize,
+) -> Result<(Decimal, Decimal)> {
+    quantize(price, amount, side, tick_size.as_decimal().scale())
+}
+
+/// [`quantize_amounts`] for a tick size with `decimals` decimal places.
+fn quantize(
+    price: Decimal,
+    amount: Amount,
+    side: Side,
+    decimals: u32,
+) -> Result<(Decimal, Decimal)> {
+    if price <= Decimal::ZERO {
+        return Err(Error::validation(format!(
+            "Unable to build Order: Price {price} must be positive"
+        )));
+    }
+
+    let raw_amount = amount.as_inner();
+    let notional = |value: Decimal| value.trunc_with_scale(decimals + LOT_SIZE_SCALE);
+
+    match (side, amount.0) {
+        // Spend USDC to buy shares, in whole cents like the lot size
+        (Side::Buy, AmountInner::Usdc(_)) => {
+            let usdc = raw_amount.trunc_with_scale(LOT_SIZE_SCALE);
+            Ok((usdc, notional(usdc / price)))
+        }
+        // Buy N shares, giving up their price in USDC
+        (Side::Buy, AmountInner::Shares(_)) => Ok((notional(raw_amount * price), raw_amount)),
+        // Sell N shares for USDC
+        (Side::Sell, AmountInner::Shares(_)) => Ok((raw_amount, notional(raw_amount * price))),
+        (Side::Sell, AmountInner::Usdc(_)) => Err(Error::validation(
+            "Sell Orders must specify their `amount`s in shares",
+        )),
+        (side, _) => Err(Error::validation(format!("Invalid side: {side}"))),
+    }
 }
 
 /// Mask the salt to be <= 2^53 - 1, as the backend parses as an IEEE 754.
@@ -485,20 +1013,174 @@ mod tests {
     use super::*;
 
     #[test]
-    fn to_fixed_u128_should_succeed() {
-        assert_eq!(to_fixed_u128(dec!(123.456)), 123_456_000);
-        assert_eq!(to_fixed_u128(dec!(123.456789)), 123_456_789);
-        assert_eq!(to_fixed_u128(dec!(123.456789111111111)), 123_456_789);
-        assert_eq!(to_fixed_u128(dec!(3.456789111111111)), 3_456_789);
-        assert_eq!(to_fixed_u128(Decimal::ZERO), 0);
+    fn to_base_units_should_succeed() -> Result<()> {
+        assert_eq!(to_base_units(dec!(123.456), USDC_DECIMALS)?, 123_456_000);
+        assert_eq!(to_base_units(dec!(123.456789), USDC_DECIMALS)?, 123_456_789);
+        assert_eq!(
+            to_base_units(dec!(123.456789111111111), USDC_DECIMALS)?,
+            123_456_789
+        );
+        assert_eq!(
+            to_base_units(dec!(3.456789111111111), USDC_DECIMALS)?,
+            3_456_789
+        );
+        assert_eq!(to_base_units(Decimal::ZERO, USDC_DECIMALS)?, 0);
+
+        Ok(())
     }
 
     #[test]
-    #[should_panic(
-        expected = "The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided"
-    )]
-    fn to_fixed_u128_panics() {
-        to_fixed_u128(dec!(-123.456));
+    fn to_base_units_should_scale_human_amounts() -> Result<()> {
+        assert_eq!(to_base_units(dec!(20.10), USDC_DECIMALS)?, 20_100_000);
+        assert_eq!(to_base_units(dec!(20.1), USDC_DECIMALS)?, 20_100_000);
+        assert_eq!(to_base_units(dec!(0.000001), USDC_DECIMALS)?, 1);
+        assert_eq!(to_base_units(dec!(0.0000009), USDC_DECIMALS)?, 0);
+        assert_eq!(to_base_units(dec!(100), USDC_DECIMALS)?, 100_000_000);
+        assert_eq!(to_base_units(dec!(1.5), 0)?, 1);
+        assert_eq!(to_base_units(dec!(1.5), 18)?, 1_500_000_000_000_000_000);
+        assert_eq!(to_base_units(dec!(1), 30)?, 10_u128.pow(30));
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_base_units_should_not_lose_precision_like_f64() -> Result<()> {
+        // `8.2 * 1e6` is `8199999.999999999` as an `f64`, which truncates to `8199999`
+        assert_eq!(to_base_units(dec!(8.2), USDC_DECIMALS)?, 8_200_000);
+        // `1.005 * 1e6` is `1004999.9999999999` as an `f64`, which truncates to `1004999`
+        assert_eq!(to_base_units(dec!(1.005), USDC_DECIMALS)?, 1_005_000);
+        // Values beyond the 53-bit mantissa of an `f64` are preserved exactly
+        assert_eq!(
+            to_base_units(dec!(9007199254.740993), USDC_DECIMALS)?,
+            9_007_199_254_740_993
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_base_units_should_reject_negative_amounts() {
+        let err = to_base_units(dec!(-123.456), USDC_DECIMALS).unwrap_err();
+        assert!(err.to_string().contains("is negative"), "{err}");
+    }
+
+    #[test]
+    fn to_base_units_should_reject_overflowing_amounts() {
+        let err = to_base_units(Decimal::MAX, 18).unwrap_err();
+        assert!(err.to_string().contains("overflows"), "{err}");
+        to_base_units(Decimal::ONE, 39).unwrap_err();
+    }
+
+    #[test]
+    fn price_levels_should_yield_tick_aligned_prices() {
+        let levels: Vec<_> = price_levels(dec!(0.455), dec!(0.5), dec!(0.01))
+            .unwrap()
+            .collect();
+        assert_eq!(
+            levels,
+            vec![dec!(0.46), dec!(0.47), dec!(0.48), dec!(0.49), dec!(0.5)]
+        );
+
+        let levels: Vec<_> = price_levels(dec!(0.1), dec!(0.3), dec!(0.1))
+            .unwrap()
+            .collect();
+        assert_eq!(levels, vec![dec!(0.1), dec!(0.2), dec!(0.3)]);
+
+        assert_eq!(
+            price_levels(dec!(0.451), dec!(0.459), dec!(0.01))
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn price_levels_should_reject_invalid_input() {
+        let err = price_levels(dec!(0.1), dec!(0.5), Decimal::ZERO).unwrap_err();
+        assert!(err.to_string().contains("Tick size must be positive"));
+
+        let err = price_levels(dec!(0.6), dec!(0.5), dec!(0.01)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("must not be greater than maximum price")
+        );
+    }
+
+    #[test]
+    fn quantize_amounts_should_truncate_the_notional_leg() -> Result<()> {
+        let shares = Amount::shares(dec!(100))?;
+        let usdc = Amount::usdc(dec!(100))?;
+
+        assert_eq!(
+            quantize_amounts(dec!(0.34), shares, Side::Buy, TickSize::Hundredth)?,
+            (dec!(34), dec!(100))
+        );
+        assert_eq!(
+            quantize_amounts(dec!(0.34), shares, Side::Sell, TickSize::Hundredth)?,
+            (dec!(100), dec!(34))
+        );
+        assert_eq!(
+            quantize_amounts(dec!(0.34), usdc, Side::Buy, TickSize::Hundredth)?,
+            (dec!(100), dec!(294.1176))
+        );
+        assert_eq!(
+            quantize_amounts(dec!(0.034), usdc, Side::Buy, TickSize::Thousandth)?,
+            (dec!(100), dec!(2941.17647))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn quantize_amounts_should_respect_the_tick_size_precision() -> Result<()> {
+        let shares = Amount::shares(dec!(12.34))?;
+        let (maker, taker) =
+            quantize_amounts(dec!(0.5678), shares, Side::Buy, TickSize::TenThousandth)?;
+        assert_eq!((maker, taker), (dec!(7.006652), dec!(12.34)));
+        assert_eq!(to_base_units(maker, USDC_DECIMALS)?, 7_006_652);
+
+        // 10 / 0.3 = 33.333..., which a 0.1 tick size truncates to 3 places
+        let usdc = Amount::usdc(dec!(10))?;
+        let (_, taker) = quantize_amounts(dec!(0.3), usdc, Side::Buy, TickSize::Tenth)?;
+        assert_eq!(taker, dec!(33.333));
+
+        // and a 0.0001 tick size to 6, the most base units can hold
+        let (_, taker) = quantize_amounts(dec!(0.0003), usdc, Side::Buy, TickSize::TenThousandth)?;
+        assert_eq!(taker, dec!(33333.333333));
+        assert_eq!(to_base_units(taker, USDC_DECIMALS)?, 33_333_333_333);
+
+        Ok(())
+    }
+
+    #[test]
+    fn quantize_amounts_should_reject_invalid_combinations() -> Result<()> {
+        let usdc = Amount::usdc(dec!(10))?;
+        let err = quantize_amounts(dec!(0.5), usdc, Side::Sell, TickSize::Hundredth).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("must specify their `amount`s in shares")
+        );
+
+        let shares = Amount::shares(dec!(10))?;
+        quantize_amounts(dec!(0.5), shares, Side::Unknown, TickSize::Hundredth).unwrap_err();
+
+        for price in [Decimal::ZERO, dec!(-0.5)] {
+            let err = quantize_amounts(price, shares, Side::Buy, TickSize::Hundredth).unwrap_err();
+            assert!(err.to_string().contains("must be positive"), "{err}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn quantize_amounts_should_truncate_usdc_to_the_lot_size() -> Result<()> {
+        let usdc = Amount::usdc(dec!(10.123456))?;
+        let (maker, taker) = quantize_amounts(dec!(0.5), usdc, Side::Buy, TickSize::Hundredth)?;
+
+        assert_eq!((maker, taker), (dec!(10.12), dec!(20.24)));
+        assert_eq!(to_base_units(maker, USDC_DECIMALS)?, 10_120_000);
+
+        Ok(())
     }
 
     #[test]
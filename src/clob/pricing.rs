@@ -0,0 +1,87 @@
+//! Exact [`Decimal`] conversions between CLOB prices and implied probabilities.
+
+use crate::Result;
+use crate::error::Error;
+use crate::types::Decimal;
+
+/// Returns the probability implied by an outcome token's `price`.
+///
+/// An outcome token pays out 1 USDC if the outcome occurs, so on Polymarket its price in `0..=1`
+/// already is the market's implied probability. This is the identity, named to make that
+/// conversion explicit at call sites.
+#[must_use]
+pub const fn price_to_probability(price: Decimal) -> Decimal {
+    price
+}
+
+/// Removes the vig from the prices of both sides of a binary market, scaling them so they sum to
+/// exactly 1 while keeping their ratio.
+///
+/// Prices taken from the asks of both outcomes usually sum to more than 1 (and those taken from
+/// the bids to less); the difference is the overround this removes.
+///
+/// # Errors
+///
+/// Returns an error if either price is negative, or if both are zero.
+pub fn devig_binary(yes: Decimal, no: Decimal) -> Result<(Decimal, Decimal)> {
+    if yes.is_sign_negative() || no.is_sign_negative() {
+        return Err(Error::validation(format!(
+            "Unable to devig negative prices {yes} and {no}"
+        )));
+    }
+
+    let total = yes + no;
+    if total.is_zero() {
+        return Err(Error::validation("Unable to devig two zero prices"));
+    }
+
+    let fair_yes = yes / total;
+    Ok((fair_yes, Decimal::ONE - fair_yes))
+}
+
+/// Returns the expected profit per share of buying at `market` when the outcome's true
+/// probability is `fair`, i.e. `fair - market`.
+///
+/// A positive edge means the token is underpriced; a negative edge means selling it at `market`
+/// is favorable instead.
+#[must_use]
+pub fn edge(fair: Decimal, market: Decimal) -> Decimal {
+    fair - market
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn devig_binary_should_normalize_overround() {
+        let (yes, no) = devig_binary(dec!(0.55), dec!(0.5)).unwrap();
+
+        assert_eq!(yes + no, Decimal::ONE);
+        assert_eq!(yes.round_dp(6), dec!(0.523810));
+        assert_eq!(no.round_dp(6), dec!(0.476190));
+    }
+
+    #[test]
+    fn devig_binary_should_keep_fair_prices() {
+        assert_eq!(
+            devig_binary(dec!(0.25), dec!(0.75)).unwrap(),
+            (dec!(0.25), dec!(0.75))
+        );
+    }
+
+    #[test]
+    fn devig_binary_should_reject_invalid_prices() {
+        devig_binary(Decimal::ZERO, Decimal::ZERO).unwrap_err();
+        devig_binary(dec!(-0.1), dec!(0.6)).unwrap_err();
+    }
+
+    #[test]
+    fn edge_should_be_fair_minus_market() {
+        assert_eq!(edge(dec!(0.6), dec!(0.55)), dec!(0.05));
+        assert_eq!(edge(dec!(0.4), dec!(0.45)), dec!(-0.05));
+        assert_eq!(price_to_probability(dec!(0.42)), dec!(0.42));
+    }
+}
@@ -0,0 +1,310 @@
+//! Keeping long-lived GTC quotes resting on the book.
+//!
+//! The CLOB may expire resting orders on its own, after which a passive quote silently stops
+//! quoting. A [`QuoteRefresher`] holds the quotes that should be resting, periodically compares
+//! them with the account's open orders, and re-places the ones that were canceled or expired,
+//! reporting what it did as a stream of [`RefreshAction`]s. A quote whose order was filled is
+//! not re-placed.
+//!
+//! ```no_run
+//! use std::str::FromStr as _;
+//! use std::time::Duration;
+//!
+//! use alloy::signers::Signer as _;
+//! use alloy::signers::local::LocalSigner;
+//! use futures::StreamExt as _;
+//! use polymarket_client_sdk::POLYGON;
+//! use polymarket_client_sdk::clob::quotes::{DesiredQuote, QuoteRefresher};
+//! use polymarket_client_sdk::clob::types::Side;
+//! use polymarket_client_sdk::clob::{Client, Config};
+//! use rust_decimal_macros::dec;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let signer = LocalSigner::from_str("0x...")?.with_chain_id(Some(POLYGON));
+//! let client = Client::new("https://clob.polymarket.com", Config::default())?
+//!     .authentication_builder(&signer)
+//!     .authenticate()
+//!     .await?;
+//!
+//! let quotes = vec![
+//!     DesiredQuote::builder()
+//!         .token_id("1234")
+//!         .side(Side::Buy)
+//!         .price(dec!(0.45))
+//!         .size(dec!(100))
+//!         .build(),
+//! ];
+//! let refresher =
+//!     QuoteRefresher::new(&client, &signer, quotes).with_poll_interval(Duration::from_secs(10));
+//!
+//! let mut actions = Box::pin(refresher.into_stream());
+//! while let Some(action) = actions.next().await {
+//!     println!("{action:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::mem;
+use std::time::{Duration, Instant};
+
+use alloy::signers::Signer;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use futures::{Stream, TryStreamExt as _};
+
+use crate::Result;
+use crate::auth::Kind;
+use crate::auth::state::Authenticated;
+use crate::clob::Client;
+use crate::clob::types::request::OrdersRequest;
+use crate::clob::types::response::{OpenOrderResponse, PostOrderResponse};
+use crate::clob::types::{OrderStatusType, Side};
+use crate::types::Decimal;
+
+/// How often a [`QuoteRefresher`] checks the open orders by default.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a [`QuoteRefresher`] waits by default before re-placing the same quote again.
+pub const DEFAULT_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A GTC limit order that should be kept resting on the book.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Builder)]
+#[builder(on(String, into))]
+pub struct DesiredQuote {
+    pub token_id: String,
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+impl DesiredQuote {
+    /// Returns `true` if `order` quotes exactly this.
+    fn matches(&self, order: &OpenOrderResponse) -> bool {
+        order.asset_id == self.token_id
+            && order.side == self.side
+            && order.price == self.price
+            && order.original_size == self.size
+    }
+}
+
+/// What a [`QuoteRefresher`] did about a quote without a live order.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RefreshAction {
+    /// A new order was posted for `quote`.
+    Placed {
+        quote: DesiredQuote,
+        /// ID of the order that expired or went missing, or [`None`] if the quote had none yet.
+        previous_order_id: Option<String>,
+        response: PostOrderResponse,
+    },
+    /// `quote` was placed less than the minimum refresh interval ago, so re-placing it is left
+    /// to a later check.
+    Throttled { quote: DesiredQuote },
+    /// The order for `quote` was filled. The quote is dropped rather than re-placed, so a filled
+    /// quote never trades its size again.
+    Filled {
+        quote: DesiredQuote,
+        order_id: String,
+    },
+}
+
+/// Keeps a set of [`DesiredQuote`]s live by re-placing their orders once they are canceled or
+/// expire.
+///
+/// On each check, a quote whose order still rests is left alone. A quote without an order yet
+/// adopts a resting one with the same token, side, price and size, so restarting the refresher
+/// does not double existing quotes. When a quote's order is missing from the open orders, its
+/// status is looked up: a filled order drops the quote with [`RefreshAction::Filled`], while a
+/// canceled or expired one is re-placed as a new GTC order. Placements happen at most once per
+/// minimum refresh interval, which bounds how fast a quote that keeps getting rejected is
+/// re-placed.
+pub struct QuoteRefresher<'client, K: Kind, S: Signer> {
+    client: &'client Client<Authenticated<K>>,
+    signer: &'client S,
+    quotes: Vec<DesiredQuote>,
+    poll_interval: Duration,
+    min_refresh_interval: Duration,
+}
+
+impl<'client, K: Kind, S: Signer> QuoteRefresher<'client, K, S> {
+    /// Creates a refresher keeping `quotes` live for `client`, signing new orders with `signer`.
+    #[must_use]
+    pub fn new(
+        client: &'client Client<Authenticated<K>>,
+        signer: &'client S,
+        quotes: Vec<DesiredQuote>,
+    ) -> Self {
+        Self {
+            client,
+            signer,
+            quotes,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            min_refresh_interval: DEFAULT_MIN_REFRESH_INTERVAL,
+        }
+    }
+
+    /// Sets how often the open orders are checked. Defaults to [`DEFAULT_POLL_INTERVAL`].
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the minimum time between two placements of the same quote. Defaults to
+    /// [`DEFAULT_MIN_REFRESH_INTERVAL`].
+    #[must_use]
+    pub fn with_min_refresh_interval(mut self, min_refresh_interval: Duration) -> Self {
+        self.min_refresh_interval = min_refresh_interval;
+        self
+    }
+
+    /// Returns a stream checking the quotes right away and then every poll interval, yielding
+    /// an action for each quote that had no live order.
+    ///
+    /// The stream only ends once every quote was filled: a failed check, or a quote whose order
+    /// fails to be looked up, built, signed or posted, yields its error and is retried on the
+    /// next check.
+    pub fn into_stream(self) -> impl Stream<Item = Result<RefreshAction>> + 'client {
+        async_stream::stream! {
+            let mut slots: Vec<Slot> = self.quotes.iter().cloned().map(Slot::new).collect();
+
+            while !slots.is_empty() {
+                match self.live_orders().await {
+                    Ok(mut orders) => {
+                        for slot in &mut slots {
+                            if let Some(action) = self.refresh(slot, &mut orders).await.transpose() {
+                                yield action;
+                            }
+                        }
+                        slots.retain(|slot| !slot.filled);
+                    }
+                    Err(e) => yield Err(e),
+                }
+
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+    }
+
+    /// Fetches the account's open orders that are live and not past their expiration.
+    async fn live_orders(&self) -> Result<Vec<OpenOrderResponse>> {
+        let now = Utc::now();
+        let orders: Vec<OpenOrderResponse> = self
+            .client
+            .stream_data(|client, cursor| async move {
+                client.orders(&OrdersRequest::default(), cursor).await
+            })
+            .try_collect()
+            .await?;
+
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.status == OrderStatusType::Live)
+            .filter(|order| !is_expired(order, now))
+            .collect())
+    }
+
+    /// Checks `slot` against the live `orders`, claiming its order from them if it still rests
+    /// and placing a new one otherwise.
+    async fn refresh(
+        &self,
+        slot: &mut Slot,
+        orders: &mut Vec<OpenOrderResponse>,
+    ) -> Result<Option<RefreshAction>> {
+        let resting = orders.iter().position(|order| match &slot.order_id {
+            Some(order_id) => order.id == *order_id,
+            None => slot.quote.matches(order),
+        });
+        if let Some(index) = resting {
+            slot.order_id = Some(orders.swap_remove(index).id);
+            return Ok(None);
+        }
+
+        if let Some(order_id) = &slot.order_id {
+            let order = self.client.order(order_id).await?;
+            match order.status {
+                OrderStatusType::Matched => {
+                    slot.filled = true;
+                    return Ok(Some(RefreshAction::Filled {
+                        quote: slot.quote.clone(),
+                        order_id: order.id,
+                    }));
+                }
+                OrderStatusType::Canceled | OrderStatusType::Unmatched => {}
+                OrderStatusType::Live if is_expired(&order, Utc::now()) => {}
+                // Still resting or being processed, so leave it to a later check
+                _ => return Ok(None),
+            }
+        }
+
+        if slot
+            .placed_at
+            .is_some_and(|placed_at| placed_at.elapsed() < self.min_refresh_interval)
+        {
+            return Ok(Some(RefreshAction::Throttled {
+                quote: slot.quote.clone(),
+            }));
+        }
+        slot.placed_at = Some(Instant::now());
+
+        let order = self
+            .client
+            .limit_order()
+            .token_id(slot.quote.token_id.clone())
+            .side(slot.quote.side)
+            .price(slot.quote.price)
+            .size(slot.quote.size)
+            .build()
+            .await?;
+        let order = self.client.sign(self.signer, order).await?;
+        let response = self.client.post_order(order).await?;
+
+        let order_id = response.success.then(|| response.order_id.clone());
+        let previous_order_id = mem::replace(&mut slot.order_id, order_id);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            token_id = %slot.quote.token_id,
+            previous_order_id = ?previous_order_id,
+            order_id = %response.order_id,
+            success = response.success,
+            "quote refreshed"
+        );
+
+        Ok(Some(RefreshAction::Placed {
+            quote: slot.quote.clone(),
+            previous_order_id,
+            response,
+        }))
+    }
+}
+
+/// Returns `true` if `order` has an expiration that has passed by `now`.
+fn is_expired(order: &OpenOrderResponse, now: DateTime<Utc>) -> bool {
+    order.expiration != DateTime::UNIX_EPOCH && order.expiration <= now
+}
+
+/// The refresh state of one [`DesiredQuote`].
+struct Slot {
+    quote: DesiredQuote,
+    /// ID of the order last placed or adopted for the quote.
+    order_id: Option<String>,
+    /// When the quote was last placed, to enforce the minimum refresh interval.
+    placed_at: Option<Instant>,
+    /// Whether the quote's order was filled, after which the quote is dropped.
+    filled: bool,
+}
+
+impl Slot {
+    fn new(quote: DesiredQuote) -> Self {
+        Self {
+            quote,
+            order_id: None,
+            placed_at: None,
+            filled: false,
+        }
+    }
+}
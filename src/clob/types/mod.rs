@@ -206,6 +206,9 @@ impl Amount {
     }
 }
 
+/// How an order's signature is verified, which depends on where the funds it trades are held.
+///
+/// Using the wrong type for an account gets its orders rejected.
 #[non_exhaustive]
 #[derive(
     Clone,
@@ -222,9 +225,15 @@ impl Amount {
 )]
 #[repr(u8)]
 pub enum SignatureType {
+    /// The signer's own address holds the funds. Used for wallets trading directly rather than
+    /// through a Polymarket account.
     #[default]
     Eoa = 0,
+    /// A Polymarket proxy wallet holds the funds. Used for accounts created with an email or
+    /// social login (Magic Link), signing with the exported private key.
     Proxy = 1,
+    /// A Gnosis Safe holds the funds. Used for accounts created by connecting a browser wallet
+    /// such as `MetaMask`.
     GnosisSafe = 2,
 }
 
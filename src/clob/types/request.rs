@@ -3,13 +3,14 @@
     reason = "Request suffix is intentional for clarity"
 )]
 
+use alloy::primitives::{B256, U256};
 use bon::Builder;
 use chrono::NaiveDate;
 use serde::Serialize;
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as};
 
 use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
-use crate::types::Address;
+use crate::types::{Address, Decimal};
 
 #[non_exhaustive]
 #[derive(Debug, Serialize, Builder)]
@@ -134,6 +135,46 @@ pub struct UserRewardsEarningRequest {
     pub no_competition: bool,
 }
 
+/// A split of collateral into a complete set of a market's outcomes followed by the sale of one of
+/// them, as performed by [`Client::split_and_sell`](crate::clob::Client::split_and_sell).
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+#[builder(on(String, into))]
+pub struct SplitAndSellRequest {
+    /// The market (condition ID) whose outcomes the collateral is split into.
+    pub condition_id: String,
+    /// The outcome token sold once the split is confirmed.
+    pub token_id: String,
+    /// The collateral to split, in USDC. This is also the number of shares of each outcome
+    /// received, and of `token_id` sold.
+    pub amount: Decimal,
+    /// How far below the token's midpoint before the split the sale may fill, e.g. `0.02` to never
+    /// sell more than 2¢ below it.
+    pub max_slippage: Decimal,
+}
+
+/// The arguments of a `splitPosition` call on the Conditional Tokens contract, as passed to the
+/// callback of [`Client::split_and_sell`](crate::clob::Client::split_and_sell).
+///
+/// Negative risk markets are split through the Neg Risk Adapter instead, whose `splitPosition`
+/// only takes `condition_id` and `amount`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitPositionRequest {
+    /// The ERC-20 collateral to split.
+    pub collateral_token: Address,
+    /// The parent collection, zero for markets that are not nested in another condition.
+    pub parent_collection_id: B256,
+    /// The condition whose outcomes the collateral is split into.
+    pub condition_id: B256,
+    /// One index set per outcome: outcome `i` is the set `1 << i`.
+    pub partition: Vec<U256>,
+    /// The collateral to split, in base units.
+    pub amount: U256,
+    /// Whether the market is a negative risk market, to be split through the Neg Risk Adapter.
+    pub neg_risk: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
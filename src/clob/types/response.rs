@@ -3,18 +3,25 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr as _;
 
+use alloy::primitives::{B256, U256};
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
-use serde_with::{DefaultOnNull, TimestampMilliSeconds, TimestampSeconds, TryFromInto, serde_as};
+use serde_with::{
+    DefaultOnNull, DeserializeAs as _, TimestampMilliSeconds, TimestampSeconds, TryFromInto,
+    serde_as,
+};
 use sha2::{Digest as _, Sha256};
 
 use crate::Result;
 use crate::auth::ApiKey;
+use crate::clob::order_builder::USDC_DECIMALS;
 use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TraderSide};
-use crate::serde_helpers::StringFromAny;
+use crate::error::Error;
+use crate::serde_helpers::{DecimalFromAny, StringFromAny};
 use crate::types::{Address, Decimal};
 
 #[non_exhaustive]
@@ -43,6 +50,35 @@ pub struct PricesResponse {
     pub prices: Option<HashMap<String, HashMap<Side, Decimal>>>,
 }
 
+/// The best bid and ask for a token, as returned by [`Client::quote`](crate::clob::Client::quote)
+/// and [`Client::quotes`](crate::clob::Client::quotes).
+///
+/// A side is [`None`] when its book is empty; `mid` and `spread` are only set when both are
+/// present.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Quote {
+    pub bid: Option<Decimal>,
+    pub ask: Option<Decimal>,
+    pub mid: Option<Decimal>,
+    pub spread: Option<Decimal>,
+}
+
+impl Quote {
+    /// Creates a quote from the best `bid` and `ask`, deriving `mid` and `spread`.
+    #[must_use]
+    pub fn new(bid: Option<Decimal>, ask: Option<Decimal>) -> Self {
+        let both = bid.zip(ask);
+
+        Self {
+            bid,
+            ask,
+            mid: both.map(|(bid, ask)| (bid + ask) / Decimal::TWO),
+            spread: both.map(|(bid, ask)| ask - bid),
+        }
+    }
+}
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct SpreadResponse {
@@ -141,6 +177,277 @@ impl OrderBookSummaryResponse {
     }
 }
 
+/// The per-token parameters needed to build and route an order, fetched together by
+/// [`Client::market_params`](crate::clob::Client::market_params).
+///
+/// Pass them to [`OrderBuilder::market_params`](crate::clob::order_builder::OrderBuilder::market_params)
+/// to validate an order without any further lookups.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder, PartialEq)]
+pub struct MarketParams {
+    /// Minimum price increment
+    pub tick_size: TickSize,
+    /// Minimum order size, in shares
+    pub min_order_size: Decimal,
+    /// Whether the token belongs to a `neg_risk` market, which routes orders to the neg risk
+    /// exchange
+    pub neg_risk: bool,
+    /// Fee rate in basis points
+    pub fee_bps: u32,
+}
+
+impl MarketParams {
+    /// Returns how many decimal places prices have in this market, that of its tick size (e.g. 2
+    /// for a tick size of `0.01`).
+    #[must_use]
+    pub fn price_decimals(&self) -> u32 {
+        self.tick_size.as_decimal().scale()
+    }
+
+    /// Returns how many decimal places share sizes have: 6, like the on-chain conditional tokens.
+    /// Orders only accept sizes with fewer, but positions and fills can use all of them.
+    #[must_use]
+    pub const fn size_decimals(&self) -> u32 {
+        USDC_DECIMALS
+    }
+
+    /// Formats `price` with exactly [`price_decimals`](Self::price_decimals) decimal places,
+    /// rounding it if it has more.
+    #[must_use]
+    pub fn format_price(&self, price: Decimal) -> String {
+        format_fixed(price, self.price_decimals())
+    }
+
+    /// Formats `size` with exactly [`size_decimals`](Self::size_decimals) decimal places,
+    /// rounding it if it has more.
+    #[must_use]
+    pub fn format_size(&self, size: Decimal) -> String {
+        format_fixed(size, self.size_decimals())
+    }
+}
+
+fn format_fixed(mut value: Decimal, decimals: u32) -> String {
+    value.rescale(decimals);
+    value.to_string()
+}
+
+/// Whether orders may be placed on a market, and if not, why.
+///
+/// Pass it to [`OrderBuilder::market_status`](crate::clob::order_builder::OrderBuilder::market_status)
+/// to refuse orders the CLOB would reject before they are signed. It is usually derived from a
+/// market's flags with [`From`]. A market's `restricted` flag only means that it is unavailable in
+/// some jurisdictions, so whether the caller is blocked is taken from
+/// [`Client::check_geoblock`](crate::clob::Client::check_geoblock) through
+/// [`MarketStatus::with_geoblock`] instead.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MarketStatus {
+    /// Orders may be placed on the market
+    #[default]
+    Open,
+    /// The market was archived
+    Archived,
+    /// The caller's location is blocked from placing orders
+    Geoblocked,
+    /// The market was closed
+    Closed,
+    /// The market's order book is not accepting orders
+    NotAcceptingOrders,
+}
+
+impl MarketStatus {
+    /// Returns the status of a market with the given flags, naming the first that forbids placing
+    /// orders.
+    const fn from_flags(archived: bool, closed: bool, accepting_orders: bool) -> Self {
+        if archived {
+            Self::Archived
+        } else if closed {
+            Self::Closed
+        } else if !accepting_orders {
+            Self::NotAcceptingOrders
+        } else {
+            Self::Open
+        }
+    }
+
+    /// Returns [`MarketStatus::Geoblocked`] if the response of
+    /// [`Client::check_geoblock`](crate::clob::Client::check_geoblock) blocks the caller, unless
+    /// the market was archived.
+    #[must_use]
+    pub const fn with_geoblock(self, geoblock: &GeoblockResponse) -> Self {
+        match self {
+            Self::Archived => self,
+            _ if geoblock.blocked => Self::Geoblocked,
+            _ => self,
+        }
+    }
+
+    /// Checks that orders may be placed on the market.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the reason orders may not be placed.
+    pub fn ensure_placeable(self) -> Result<()> {
+        match self.problem() {
+            Some(reason) => Err(Error::validation(reason)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) const fn problem(self) -> Option<&'static str> {
+        match self {
+            Self::Open => None,
+            Self::Archived => Some("Unable to build Order: market archived"),
+            Self::Geoblocked => Some("Unable to build Order: geoblocked"),
+            Self::Closed => Some("Unable to build Order: market closed"),
+            Self::NotAcceptingOrders => Some("Unable to build Order: market not accepting orders"),
+        }
+    }
+}
+
+impl From<&MarketResponse> for MarketStatus {
+    fn from(market: &MarketResponse) -> Self {
+        Self::from_flags(market.archived, market.closed, market.accepting_orders)
+    }
+}
+
+#[cfg(feature = "gamma")]
+impl From<&crate::gamma::types::response::Market> for MarketStatus {
+    /// Flags absent from the Gamma market are taken not to forbid placing orders. The market's
+    /// `restricted` flag is ignored, see [`MarketStatus::with_geoblock`].
+    fn from(market: &crate::gamma::types::response::Market) -> Self {
+        Self::from_flags(
+            market.archived.unwrap_or_default(),
+            market.closed.unwrap_or_default(),
+            market.accepting_orders.unwrap_or(true),
+        )
+    }
+}
+
+/// A market's trading fees, as returned by
+/// [`Client::fee_schedule`](crate::clob::Client::fee_schedule).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder, PartialEq, Eq)]
+pub struct FeeSchedule {
+    /// Fee charged to makers, in basis points
+    pub maker_bps: u32,
+    /// Fee charged to takers, in basis points. This is the rate orders must be signed with.
+    pub taker_bps: u32,
+}
+
+/// Point-in-time snapshot of a token's order book, keyed by price level.
+///
+/// Built from the `/book` endpoint, which returns the full book aggregated per price level
+/// rather than the top-N levels.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBook {
+    pub market: String,
+    pub token_id: U256,
+    pub timestamp: DateTime<Utc>,
+    pub hash: Option<String>,
+    /// Resting size by bid price. The best bid is the last entry.
+    pub bids: BTreeMap<Decimal, Decimal>,
+    /// Resting size by ask price. The best ask is the first entry.
+    pub asks: BTreeMap<Decimal, Decimal>,
+    pub min_order_size: Decimal,
+    pub neg_risk: bool,
+    pub tick_size: TickSize,
+}
+
+impl OrderBook {
+    /// Returns the highest bid as `(price, size)`, if any.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.bids
+            .last_key_value()
+            .map(|(price, size)| (*price, *size))
+    }
+
+    /// Returns the lowest ask as `(price, size)`, if any.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.asks
+            .first_key_value()
+            .map(|(price, size)| (*price, *size))
+    }
+
+    /// Returns the average price of filling an order of `size` shares on `side` against the
+    /// book, and the size that would fill, as `(avg_price, filled_size)`.
+    ///
+    /// Levels are walked from the best price, so `filled_size` is less than `size` when the book
+    /// is too thin. Returns `None` if nothing would fill, e.g. when the opposite side is empty or
+    /// `size` is not positive.
+    #[must_use]
+    pub fn vwap_for_size(&self, side: Side, size: Decimal) -> Option<(Decimal, Decimal)> {
+        let mut filled = Decimal::ZERO;
+        let mut notional = Decimal::ZERO;
+
+        for (price, level_size) in self.levels_against(side) {
+            if filled >= size {
+                break;
+            }
+
+            let take = level_size.min(size - filled);
+            filled += take;
+            notional += take * price;
+        }
+
+        (filled > Decimal::ZERO).then(|| (notional / filled, filled))
+    }
+
+    /// Returns the cumulative size available to an order on `side` at each price level, as
+    /// `(price, cumulative_size)` from the best price outwards, e.g. for a depth chart.
+    ///
+    /// A buy walks the asks from the lowest price up, a sell walks the bids from the highest
+    /// price down.
+    #[must_use]
+    pub fn cumulative_depth(&self, side: Side) -> Vec<(Decimal, Decimal)> {
+        self.levels_against(side)
+            .scan(Decimal::ZERO, |total, (price, size)| {
+                *total += size;
+                Some((price, *total))
+            })
+            .collect()
+    }
+
+    /// The levels an order on `side` fills against, best price first.
+    fn levels_against(&self, side: Side) -> Box<dyn Iterator<Item = (Decimal, Decimal)> + '_> {
+        let level = |(price, size): (&Decimal, &Decimal)| (*price, *size);
+
+        match side {
+            Side::Buy => Box::new(self.asks.iter().map(level)),
+            Side::Sell => Box::new(self.bids.iter().rev().map(level)),
+            Side::Unknown => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl TryFrom<OrderBookSummaryResponse> for OrderBook {
+    type Error = crate::error::Error;
+
+    fn try_from(summary: OrderBookSummaryResponse) -> std::result::Result<Self, Self::Error> {
+        let ladder = |levels: Vec<OrderSummary>| -> BTreeMap<Decimal, Decimal> {
+            levels
+                .into_iter()
+                .map(|level| (level.price, level.size))
+                .collect()
+        };
+
+        Ok(Self {
+            token_id: U256::from_str(&summary.asset_id)?,
+            market: summary.market,
+            timestamp: summary.timestamp,
+            hash: summary.hash,
+            bids: ladder(summary.bids),
+            asks: ladder(summary.asks),
+            min_order_size: summary.min_order_size,
+            neg_risk: summary.neg_risk,
+            tick_size: summary.tick_size,
+        })
+    }
+}
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Serialize, Deserialize, Hash, Builder, PartialEq)]
 pub struct OrderSummary {
@@ -246,6 +553,14 @@ pub struct ApiKeysResponse {
     keys: Option<Vec<ApiKey>>,
 }
 
+impl ApiKeysResponse {
+    /// The API keys of the address, empty if it has none.
+    #[must_use]
+    pub fn keys(&self) -> &[ApiKey] {
+        self.keys.as_deref().unwrap_or_default()
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct BanStatusResponse {
@@ -259,9 +574,9 @@ pub struct BanStatusResponse {
 #[builder(on(String, into))]
 pub struct PostOrderResponse {
     pub error_msg: Option<String>,
-    #[serde(deserialize_with = "empty_string_as_zero")]
+    #[serde_as(as = "DecimalFromAny")]
     pub making_amount: Decimal,
-    #[serde(deserialize_with = "empty_string_as_zero")]
+    #[serde_as(as = "DecimalFromAny")]
     pub taking_amount: Decimal,
     #[serde(rename = "orderID")]
     pub order_id: String,
@@ -276,19 +591,39 @@ pub struct PostOrderResponse {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub trade_ids: Vec<String>,
+    /// Number of shares that were filled, truncated to the token's 6-decimal on-chain precision.
+    /// Populated from `making_amount`/`taking_amount` by `post_order`/`post_orders`.
+    #[builder(default)]
+    #[serde(skip)]
+    pub filled_size: Decimal,
+    /// Average price paid or received per filled share, if anything filled.
+    #[serde(skip)]
+    pub avg_fill_price: Option<Decimal>,
 }
 
+impl PostOrderResponse {
+    /// Derives [`Self::filled_size`] and [`Self::avg_fill_price`] for an order on `side`. A `BUY`
+    /// makes USDC and takes shares, while a `SELL` makes shares and takes USDC.
+    #[must_use]
+    pub(crate) fn with_fills(mut self, side: Side) -> Self {
+        let (shares, usdc) = match side {
+            Side::Buy => (self.taking_amount, self.making_amount),
+            _ => (self.making_amount, self.taking_amount),
+        };
+
+        self.filled_size = shares.trunc_with_scale(USDC_DECIMALS).normalize();
+        self.avg_fill_price =
+            (!self.filled_size.is_zero()).then(|| (usdc / self.filled_size).normalize());
+        self
+    }
+}
+
+/// Deserializes a [`Decimal`] from a string or number, reading an empty string as zero.
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-
-    if s.trim().is_empty() {
-        Ok(Decimal::ZERO)
-    } else {
-        s.parse::<Decimal>().map_err(serde::de::Error::custom)
-    }
+    DecimalFromAny::deserialize_as(deserializer)
 }
 
 #[non_exhaustive]
@@ -333,6 +668,28 @@ pub struct CancelOrdersResponse {
     pub not_canceled: HashMap<String, String>,
 }
 
+/// Returned by [`Client::split_and_sell`](crate::clob::Client::split_and_sell).
+#[non_exhaustive]
+#[derive(Debug, Builder, PartialEq)]
+pub struct SplitAndSellResponse {
+    /// The hash of the confirmed split transaction.
+    pub transaction_hash: B256,
+    /// The response to posting the sell order.
+    pub sale: PostOrderResponse,
+}
+
+/// Returned by [`Client::replace_order`](crate::clob::Client::replace_order).
+#[non_exhaustive]
+#[derive(Debug, Builder, PartialEq)]
+pub struct ReplaceOrderResponse {
+    /// Whether the old order was cancelled. The new order is only posted if it was.
+    pub canceled: bool,
+    /// The response to cancelling the old order.
+    pub cancel: CancelOrdersResponse,
+    /// The response to posting the new order, `None` if the old order was not cancelled.
+    pub post: Option<PostOrderResponse>,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -513,6 +870,17 @@ pub struct TotalUserEarningResponse {
     pub asset_rate: Decimal,
 }
 
+/// A user's total reward earnings for one day, as returned by
+/// [`Client::reward_earnings_series`](crate::clob::Client::reward_earnings_series).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Builder, PartialEq, Eq)]
+pub struct RewardEarningPoint {
+    pub date: NaiveDate,
+    /// Earnings in every reward asset, each converted at its
+    /// [`asset_rate`](TotalUserEarningResponse::asset_rate) and summed, zero on days without any.
+    pub value: Decimal,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -667,3 +1035,81 @@ pub struct Page<T> {
     /// The length of `data`
     pub count: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBook {
+        OrderBook {
+            market: "0xabc".to_owned(),
+            token_id: U256::from(1),
+            timestamp: Utc::now(),
+            hash: None,
+            bids: bids.iter().copied().collect(),
+            asks: asks.iter().copied().collect(),
+            min_order_size: dec!(5),
+            neg_risk: false,
+            tick_size: TickSize::Hundredth,
+        }
+    }
+
+    #[test]
+    fn vwap_for_size_should_walk_levels_from_best_price() {
+        let book = book(
+            &[(dec!(0.40), dec!(100)), (dec!(0.45), dec!(50))],
+            &[(dec!(0.55), dec!(10)), (dec!(0.60), dec!(30))],
+        );
+
+        assert_eq!(
+            book.vwap_for_size(Side::Buy, dec!(20)),
+            Some((dec!(0.575), dec!(20)))
+        );
+        assert_eq!(
+            book.vwap_for_size(Side::Sell, dec!(50)),
+            Some((dec!(0.45), dec!(50)))
+        );
+        assert_eq!(
+            book.vwap_for_size(Side::Sell, dec!(100)),
+            Some((dec!(0.425), dec!(100)))
+        );
+    }
+
+    #[test]
+    fn vwap_for_size_should_report_partial_fills() {
+        let book = book(&[], &[(dec!(0.5), dec!(10)), (dec!(0.6), dec!(10))]);
+
+        assert_eq!(
+            book.vwap_for_size(Side::Buy, dec!(50)),
+            Some((dec!(0.55), dec!(20)))
+        );
+    }
+
+    #[test]
+    fn vwap_for_size_should_be_none_without_liquidity() {
+        let book = book(&[], &[(dec!(0.5), dec!(10))]);
+
+        assert_eq!(book.vwap_for_size(Side::Sell, dec!(10)), None);
+        assert_eq!(book.vwap_for_size(Side::Buy, Decimal::ZERO), None);
+        assert!(book.cumulative_depth(Side::Sell).is_empty());
+    }
+
+    #[test]
+    fn cumulative_depth_should_accumulate_from_best_price() {
+        let book = book(
+            &[(dec!(0.40), dec!(100)), (dec!(0.45), dec!(50))],
+            &[(dec!(0.55), dec!(10)), (dec!(0.60), dec!(30))],
+        );
+
+        assert_eq!(
+            book.cumulative_depth(Side::Buy),
+            vec![(dec!(0.55), dec!(10)), (dec!(0.60), dec!(40))]
+        );
+        assert_eq!(
+            book.cumulative_depth(Side::Sell),
+            vec![(dec!(0.45), dec!(50)), (dec!(0.40), dec!(150))]
+        );
+    }
+}
@@ -1,17 +1,21 @@
 use std::collections::{HashMap, hash_map::Entry};
 use std::sync::Arc;
+use std::time::Duration;
 
+use alloy::primitives::U256;
 use async_stream::try_stream;
-use futures::Stream;
 use futures::StreamExt as _;
+use futures::{Stream, stream};
+use tokio::sync::broadcast::error::RecvError;
 
 use super::config::Config;
 use super::connection::{ConnectionManager, ConnectionState};
-use super::interest::InterestTracker;
+use super::error::WsError;
+use super::interest::{InterestTracker, MessageInterest};
 use super::subscription::{ChannelType, SubscriptionManager};
 use super::types::response::{
-    BestBidAsk, BookUpdate, MarketResolved, MidpointUpdate, NewMarket, OrderMessage, PriceChange,
-    TradeMessage, WsMessage,
+    BestBidAsk, BookUpdate, LastTradePrice, MarketResolved, MidpointUpdate, NewMarket,
+    OrderMessage, PriceChange, TradeMessage, WsMessage,
 };
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
@@ -140,6 +144,11 @@ impl Client<Unauthenticated> {
 // Methods available in any state
 impl<S: State> Client<S> {
     /// Subscribe to orderbook updates for specific assets.
+    ///
+    /// Each ID is rejected locally unless it is a non-zero decimal token ID. The server does not
+    /// acknowledge subscriptions and silently ignores well-formed IDs of unknown tokens, which
+    /// then never yield a book; check unfamiliar IDs with the CLOB's
+    /// [`order_book`](crate::clob::Client::order_book) first.
     pub fn subscribe_orderbook(
         &self,
         asset_ids: Vec<String>,
@@ -158,15 +167,90 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribe to orderbook updates for specific assets, each paired with the verbatim text
+    /// frame it was received in.
+    ///
+    /// Useful for persisting the exact feed (e.g. for an audit trail) while still working with
+    /// typed updates. Frames are only retained while such a subscription is active, so
+    /// [`Client::subscribe_orderbook`] and other subscriptions do not pay for copying them. A frame
+    /// carrying several updates is paired with each of them.
+    pub fn subscribe_orderbook_raw(
+        &self,
+        asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<(BookUpdate, String)>>> {
+        let stream = self
+            .market_handles()?
+            .subscriptions
+            .subscribe_market_raw(asset_ids)?;
+
+        Ok(stream.filter_map(|msg_result| async move {
+            match msg_result {
+                Ok((WsMessage::Book(book), frame)) => Some(Ok((book, frame))),
+                Err(e) => Some(Err(e)),
+                _ => None,
+            }
+        }))
+    }
+
+    /// Subscribe to orderbook updates for specific token IDs.
+    ///
+    /// Typed counterpart of [`Client::subscribe_orderbook`]. Each ID is rejected locally if it is
+    /// zero, which is never a valid token ID.
+    pub fn subscribe_orderbook_tokens(
+        &self,
+        token_ids: &[U256],
+    ) -> Result<impl Stream<Item = Result<BookUpdate>>> {
+        if token_ids.contains(&U256::ZERO) {
+            return Err(WsError::InvalidArgument {
+                channel: ChannelType::Market,
+                reason: "token ID 0 is not a valid token ID".to_owned(),
+            }
+            .into());
+        }
+
+        self.subscribe_orderbook(token_ids.iter().map(U256::to_string).collect())
+    }
+
+    /// Subscribe to market data for specific assets, starting from the latest book snapshot of
+    /// each asset and the updates received since.
+    ///
+    /// Lets several consumers share one upstream subscription while each starts from a
+    /// consistent book, however late it subscribes. Messages are kept per asset from its first
+    /// replaying subscription until it is fully unsubscribed. At most
+    /// [`Config::replay_buffer_size`] updates are kept after each snapshot; past that, the asset
+    /// replays nothing until its next snapshot rather than an incomplete history. Trades are not
+    /// replayed, only yielded live, so they are never seen twice.
+    pub fn subscribe_with_replay(
+        &self,
+        asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.market_handles()?
+            .subscriptions
+            .subscribe_market_with_replay(asset_ids)
+    }
+
     /// Subscribe to price changes for specific assets.
+    ///
+    /// Equivalent to [`Client::subscribe_price_changes`].
     pub fn subscribe_prices(
         &self,
         asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<PriceChange>>> {
+        self.subscribe_price_changes(asset_ids)
+    }
+
+    /// Subscribe to price changes for specific assets, without book snapshots.
+    ///
+    /// Only interest in `price_change` messages is registered, so unless another subscription
+    /// needs them, other market messages such as full books are not decoded.
+    pub fn subscribe_price_changes(
+        &self,
+        asset_ids: Vec<String>,
     ) -> Result<impl Stream<Item = Result<PriceChange>>> {
         let stream = self
             .market_handles()?
             .subscriptions
-            .subscribe_market(asset_ids)?;
+            .subscribe_market_with_interest(asset_ids, false, MessageInterest::PRICE_CHANGE)?;
 
         Ok(stream.filter_map(|msg_result| async move {
             match msg_result {
@@ -177,6 +261,28 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribe to last trade price updates for specific assets, without book snapshots.
+    ///
+    /// Only interest in `last_trade_price` messages is registered, so unless another
+    /// subscription needs them, other market messages such as full books are not decoded.
+    pub fn subscribe_last_trade_price(
+        &self,
+        asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<LastTradePrice>>> {
+        let stream = self
+            .market_handles()?
+            .subscriptions
+            .subscribe_market_with_interest(asset_ids, false, MessageInterest::LAST_TRADE_PRICE)?;
+
+        Ok(stream.filter_map(|msg_result| async move {
+            match msg_result {
+                Ok(WsMessage::LastTradePrice(ltp)) => Some(Ok(ltp)),
+                Err(e) => Some(Err(e)),
+                _ => None,
+            }
+        }))
+    }
+
     /// Subscribe to midpoint updates (calculated from best bid/ask).
     pub fn subscribe_midpoints(
         &self,
@@ -265,6 +371,30 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribe to errors decoding messages received on any of the client's channels.
+    ///
+    /// Only messages of a type some subscription is interested in are decoded. One that fails
+    /// cannot be routed to the subscriptions it was meant for, so it is reported here instead,
+    /// as an error carrying a [`WsError::Decode`] with the raw text of the message. Yields a
+    /// [`WsError::Lagged`] error if errors were missed because the stream was not polled.
+    pub fn subscribe_decode_errors(&self) -> impl Stream<Item = Result<Arc<Error>>> {
+        let streams = self.inner.channels.values().map(|handles| {
+            let mut rx = handles.connection.subscribe_decode_errors();
+
+            Box::pin(try_stream! {
+                loop {
+                    match rx.recv().await {
+                        Ok(error) => yield error,
+                        Err(RecvError::Lagged(count)) => Err(WsError::Lagged { count })?,
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            })
+        });
+
+        stream::select_all(streams)
+    }
+
     /// Get the current connection state.
     #[must_use]
     pub fn connection_state(&self) -> ConnectionState {
@@ -275,6 +405,51 @@ impl<S: State> Client<S> {
         }
     }
 
+    /// Returns the round trip of the latest answered heartbeat on the market channel's current
+    /// connection, or [`None`] if there is no connection or no heartbeat has been answered on it
+    /// yet.
+    ///
+    /// Heartbeats are sent every [`Config::heartbeat_interval`], so this reflects the gateway's
+    /// latency as of at most one interval ago. To record every measurement, e.g. for alerting,
+    /// implement [`MetricsObserver::on_heartbeat`](crate::metrics::MetricsObserver::on_heartbeat)
+    /// and set it as [`Config::metrics`].
+    #[must_use]
+    pub fn latency(&self) -> Option<Duration> {
+        let handles = self.inner.channel(ChannelType::Market)?;
+        handles.connection.latency()
+    }
+
+    /// Returns the number of consecutive failed attempts to connect the market channel, or `0`
+    /// if it has not been opened.
+    ///
+    /// A connection that drops within
+    /// [`ReconnectConfig::stability_window`](super::ReconnectConfig::stability_window) counts as a
+    /// failed attempt; once one stays up for the window, the count and the reconnect backoff reset.
+    #[must_use]
+    pub fn reconnect_attempts(&self) -> u32 {
+        self.inner
+            .channel(ChannelType::Market)
+            .map_or(0, |handles| handles.connection.reconnect_attempts())
+    }
+
+    /// Opens a fresh connection to the market channel and closes it again once the handshake
+    /// completes, using the client's proxy, headers and connect timeout.
+    ///
+    /// The client's own connections are left untouched, so this checks that the gateway accepts
+    /// connections regardless of [`Self::connection_state`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be established within the connect timeout.
+    pub async fn probe(&self) -> Result<()> {
+        let endpoint = channel_endpoint(&self.inner.base_endpoint, ChannelType::Market);
+        let mut stream = ConnectionManager::connect(&endpoint, &self.inner.config).await?;
+        // The handshake has already succeeded, so a failed close does not make the probe fail
+        let _: std::result::Result<(), _> = stream.close(None).await;
+
+        Ok(())
+    }
+
     /// Get the number of active subscriptions.
     #[must_use]
     pub fn subscription_count(&self) -> usize {
@@ -305,6 +480,26 @@ impl<S: State> Client<S> {
             .unsubscribe_market(asset_ids)
     }
 
+    /// Unsubscribe from price changes for specific assets.
+    ///
+    /// This decrements the reference count for each asset. The server unsubscribe
+    /// is only sent when no other subscriptions are using those assets.
+    pub fn unsubscribe_price_changes(&self, asset_ids: &[String]) -> Result<()> {
+        self.market_handles()?
+            .subscriptions
+            .unsubscribe_market(asset_ids)
+    }
+
+    /// Unsubscribe from last trade price updates for specific assets.
+    ///
+    /// This decrements the reference count for each asset. The server unsubscribe
+    /// is only sent when no other subscriptions are using those assets.
+    pub fn unsubscribe_last_trade_price(&self, asset_ids: &[String]) -> Result<()> {
+        self.market_handles()?
+            .subscriptions
+            .unsubscribe_market(asset_ids)
+    }
+
     /// Unsubscribe from midpoint updates for specific assets.
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
@@ -3,21 +3,28 @@
     reason = "Connection types expose their domain in the name for clarity"
 )]
 
+use std::fmt::Write as _;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use backoff::backoff::Backoff as _;
 use futures::{SinkExt as _, StreamExt as _};
 use secrecy::ExposeSecret as _;
 use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
 use tokio::net::TcpStream;
 use tokio::sync::{broadcast, mpsc, watch};
 use tokio::time::{interval, sleep, timeout};
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest as _;
+use tokio_tungstenite::tungstenite::http::Uri;
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream, client_async_tls, connect_async, tungstenite::Message,
+};
 
-use super::config::Config;
+use super::config::{Config, ProxyConfig};
 use super::error::WsError;
 use super::interest::InterestTracker;
+use super::replay::ReplayCache;
 use super::types::request::SubscriptionRequest;
 use super::types::response::{WsMessage, parse_if_interested};
 use crate::{
@@ -30,6 +37,9 @@ type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 /// Broadcast channel capacity for incoming messages.
 const BROADCAST_CAPACITY: usize = 1024;
 
+/// Maximum size of the proxy's response to a `CONNECT` request.
+const MAX_PROXY_RESPONSE_SIZE: usize = 8192;
+
 /// Connection state tracking.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -58,6 +68,46 @@ impl ConnectionState {
     }
 }
 
+/// A decoded message together with the text frame it was received in.
+///
+/// A single frame may carry several messages (e.g. a batch of book snapshots), in which case each
+/// of them shares the same `frame`.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct RawMessage {
+    /// The decoded message.
+    pub message: WsMessage,
+    /// The verbatim text of the frame `message` was decoded from.
+    pub frame: Arc<str>,
+}
+
+/// Where the connection loop delivers incoming messages, messages that failed to decode,
+/// heartbeat round trips and its count of consecutive failed attempts.
+struct Outlets {
+    broadcast_tx: broadcast::Sender<WsMessage>,
+    raw_tx: broadcast::Sender<RawMessage>,
+    decode_errors_tx: broadcast::Sender<Arc<Error>>,
+    replay: Arc<ReplayCache>,
+    latency_tx: watch::Sender<Option<Duration>>,
+    attempts_tx: watch::Sender<u32>,
+}
+
+impl Outlets {
+    /// Records `message` for replay, then broadcasts it, paired with `frame` if someone asked for
+    /// frames. Recording first means a subscriber that subscribes before reading the replay never
+    /// misses a message, at worst seeing it twice.
+    fn publish(&self, message: WsMessage, frame: Option<&Arc<str>>) {
+        self.replay.record(&message);
+        if let Some(frame) = frame {
+            _ = self.raw_tx.send(RawMessage {
+                message: message.clone(),
+                frame: Arc::clone(frame),
+            });
+        }
+        _ = self.broadcast_tx.send(message);
+    }
+}
+
 /// Manages WebSocket connection lifecycle, reconnection, and heartbeat.
 #[derive(Clone)]
 pub struct ConnectionManager {
@@ -69,6 +119,18 @@ pub struct ConnectionManager {
     sender_tx: mpsc::UnboundedSender<String>,
     /// Broadcast sender for incoming messages
     broadcast_tx: broadcast::Sender<WsMessage>,
+    /// Broadcast sender for incoming messages paired with the frame they were decoded from.
+    /// Frames are only retained while this channel has receivers.
+    raw_tx: broadcast::Sender<RawMessage>,
+    /// Broadcast sender for errors decoding incoming messages, each carrying a
+    /// [`WsError::Decode`]
+    decode_errors_tx: broadcast::Sender<Arc<Error>>,
+    /// Recent market messages kept for late subscribers
+    replay: Arc<ReplayCache>,
+    /// Watch channel sender for the latest heartbeat round trip of the current connection
+    latency_tx: watch::Sender<Option<Duration>>,
+    /// Watch channel sender for the number of consecutive failed connection attempts
+    attempts_tx: watch::Sender<u32>,
 }
 
 impl ConnectionManager {
@@ -79,12 +141,24 @@ impl ConnectionManager {
     pub fn new(endpoint: String, config: Config, interest: &Arc<InterestTracker>) -> Result<Self> {
         let (sender_tx, sender_rx) = mpsc::unbounded_channel();
         let (broadcast_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
+        let (raw_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
+        let (decode_errors_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
         let (state_tx, state_rx) = watch::channel(ConnectionState::Disconnected);
+        let replay = Arc::new(ReplayCache::new(config.replay_buffer_size));
+        let (latency_tx, _) = watch::channel(None);
+        let (attempts_tx, _) = watch::channel(0);
 
         // Spawn connection task
         let connection_config = config;
         let connection_endpoint = endpoint;
-        let broadcast_tx_clone = broadcast_tx.clone();
+        let outlets = Outlets {
+            broadcast_tx: broadcast_tx.clone(),
+            raw_tx: raw_tx.clone(),
+            decode_errors_tx: decode_errors_tx.clone(),
+            replay: Arc::clone(&replay),
+            latency_tx: latency_tx.clone(),
+            attempts_tx: attempts_tx.clone(),
+        };
         let connection_interest = Arc::clone(interest);
         let state_tx_clone = state_tx.clone();
 
@@ -93,7 +167,7 @@ impl ConnectionManager {
                 connection_endpoint,
                 connection_config,
                 sender_rx,
-                broadcast_tx_clone,
+                outlets,
                 connection_interest,
                 state_tx_clone,
             )
@@ -105,15 +179,24 @@ impl ConnectionManager {
             state_rx,
             sender_tx,
             broadcast_tx,
+            raw_tx,
+            decode_errors_tx,
+            replay,
+            latency_tx,
+            attempts_tx,
         })
     }
 
     /// Main connection loop with automatic reconnection.
+    ///
+    /// The attempt counter and backoff only reset once a connection has stayed up for
+    /// [`ReconnectConfig::stability_window`](super::ReconnectConfig::stability_window);
+    /// a connection that drops sooner counts as another failed attempt.
     async fn connection_loop(
         endpoint: String,
         config: Config,
         mut sender_rx: mpsc::UnboundedReceiver<String>,
-        broadcast_tx: broadcast::Sender<WsMessage>,
+        outlets: Outlets,
         interest: Arc<InterestTracker>,
         state_tx: watch::Sender<ConnectionState>,
     ) {
@@ -126,25 +209,44 @@ impl ConnectionManager {
             _ = state_tx.send(ConnectionState::Connecting);
 
             // Attempt connection
-            match connect_async(&endpoint).await {
-                Ok((ws_stream, _)) => {
-                    attempt = 0;
-                    backoff.reset();
+            match Self::connect(&endpoint, &config).await {
+                Ok(ws_stream) => {
+                    // Messages were missed while disconnected, so kept ones no longer describe
+                    // the books. Resubscribing on connect brings fresh snapshots to start from.
+                    outlets.replay.reset();
                     _ = state_tx.send(ConnectionState::Connected {
                         since: Instant::now(),
                     });
 
-                    // Handle connection
-                    if let Err(e) = Self::handle_connection(
+                    // Handle connection, resetting the attempt counter once it proves stable
+                    let connection = Self::handle_connection(
+                        &endpoint,
                         ws_stream,
                         &mut sender_rx,
-                        &broadcast_tx,
+                        &outlets,
                         state_rx,
                         config.clone(),
                         &interest,
-                    )
-                    .await
-                    {
+                    );
+                    tokio::pin!(connection);
+
+                    let mut stable = false;
+                    let result = tokio::select! {
+                        result = &mut connection => result,
+                        () = sleep(config.reconnect.stability_window) => {
+                            stable = true;
+                            attempt = 0;
+                            backoff.reset();
+                            _ = outlets.attempts_tx.send(attempt);
+                            connection.await
+                        }
+                    };
+
+                    if !stable {
+                        attempt = attempt.saturating_add(1);
+                    }
+
+                    if let Err(e) = result {
                         #[cfg(feature = "tracing")]
                         tracing::error!("Error handling connection: {e:?}");
                         #[cfg(not(feature = "tracing"))]
@@ -152,7 +254,7 @@ impl ConnectionManager {
                     }
                 }
                 Err(e) => {
-                    let error = Error::with_source(Kind::WebSocket, WsError::Connection(e));
+                    let error = Error::with_source(Kind::WebSocket, e);
                     #[cfg(feature = "tracing")]
                     tracing::warn!("Unable to connect: {error:?}");
                     #[cfg(not(feature = "tracing"))]
@@ -160,6 +262,7 @@ impl ConnectionManager {
                     attempt = attempt.saturating_add(1);
                 }
             }
+            _ = outlets.attempts_tx.send(attempt);
 
             // Check if we should stop reconnecting
             if let Some(max) = config.reconnect.max_attempts
@@ -171,6 +274,9 @@ impl ConnectionManager {
 
             // Update state and wait with exponential backoff
             _ = state_tx.send(ConnectionState::Reconnecting { attempt });
+            if let Some(metrics) = &config.metrics {
+                metrics.on_reconnect(&endpoint, attempt);
+            }
 
             if let Some(duration) = backoff.next_backoff() {
                 sleep(duration).await;
@@ -178,11 +284,99 @@ impl ConnectionManager {
         }
     }
 
+    /// Establish a WebSocket connection to `endpoint`, tunneling through the configured proxy (if
+    /// any) and bounded by the configured connect timeout.
+    pub(crate) async fn connect(
+        endpoint: &str,
+        config: &Config,
+    ) -> std::result::Result<WsStream, WsError> {
+        let mut request = endpoint.into_client_request().map_err(WsError::Connect)?;
+        request.headers_mut().extend(config.headers.clone());
+
+        let connect = async {
+            let ws_stream = match &config.proxy {
+                Some(proxy) => {
+                    let stream = Self::tunnel(proxy, request.uri()).await?;
+                    client_async_tls(request, stream).await
+                }
+                None => connect_async(request).await,
+            };
+
+            ws_stream
+                .map(|(ws_stream, _)| ws_stream)
+                .map_err(WsError::Connect)
+        };
+
+        timeout(config.connect_timeout, connect)
+            .await
+            .map_err(|_elapsed| WsError::ConnectTimeout)?
+    }
+
+    /// Open a TCP connection to `proxy` and issue an HTTP `CONNECT` for the host of `uri`,
+    /// returning the tunneled stream once the proxy has accepted it.
+    async fn tunnel(proxy: &ProxyConfig, uri: &Uri) -> std::result::Result<TcpStream, WsError> {
+        let host = uri
+            .host()
+            .ok_or_else(|| WsError::Proxy(format!("Endpoint {uri} has no host")))?;
+        let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+            Some("wss") => 443,
+            _ => 80,
+        });
+
+        let mut stream = TcpStream::connect((proxy.host(), proxy.port()))
+            .await
+            .map_err(|e| WsError::Proxy(format!("Unable to reach proxy: {e}")))?;
+
+        let mut connect = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+        if let Some(authorization) = proxy.authorization() {
+            let _ = write!(connect, "Proxy-Authorization: {authorization}\r\n");
+        }
+        connect.push_str("\r\n");
+
+        stream
+            .write_all(connect.as_bytes())
+            .await
+            .map_err(|e| WsError::Proxy(format!("Unable to send CONNECT request: {e}")))?;
+
+        // Read until the end of the response headers. The proxy sends nothing else until the
+        // client starts talking through the tunnel, so no tunneled bytes are consumed here.
+        let mut response = Vec::new();
+        let mut buffer = [0_u8; 1024];
+        while !response.ends_with(b"\r\n\r\n") {
+            let read = stream
+                .read(&mut buffer)
+                .await
+                .map_err(|e| WsError::Proxy(format!("Unable to read CONNECT response: {e}")))?;
+
+            if read == 0 {
+                return Err(WsError::Proxy(
+                    "Proxy closed the connection during CONNECT".to_owned(),
+                ));
+            }
+
+            response.extend_from_slice(&buffer[..read]);
+
+            if response.len() > MAX_PROXY_RESPONSE_SIZE {
+                return Err(WsError::Proxy("CONNECT response too large".to_owned()));
+            }
+        }
+
+        let response = String::from_utf8_lossy(&response);
+        let status_line = response.lines().next().unwrap_or_default();
+        match status_line.split_whitespace().nth(1) {
+            Some(status) if status.starts_with('2') => Ok(stream),
+            _ => Err(WsError::Proxy(format!(
+                "Proxy refused CONNECT: {status_line}"
+            ))),
+        }
+    }
+
     /// Handle an active WebSocket connection.
     async fn handle_connection(
+        endpoint: &str,
         ws_stream: WsStream,
         sender_rx: &mut mpsc::UnboundedReceiver<String>,
-        broadcast_tx: &broadcast::Sender<WsMessage>,
+        outlets: &Outlets,
         state_rx: watch::Receiver<ConnectionState>,
         config: Config,
         interest: &Arc<InterestTracker>,
@@ -194,8 +388,13 @@ impl ConnectionManager {
 
         let (ping_tx, mut ping_rx) = mpsc::unbounded_channel();
 
+        // Round trips of a previous connection say nothing about this one
+        _ = outlets.latency_tx.send(None);
+        let latency_tx = outlets.latency_tx.clone();
+        let endpoint = endpoint.to_owned();
+
         let heartbeat_handle = tokio::spawn(async move {
-            Self::heartbeat_loop(ping_tx, state_rx, &config, pong_rx).await;
+            Self::heartbeat_loop(ping_tx, state_rx, &config, pong_rx, &latency_tx, &endpoint).await;
         });
 
         loop {
@@ -213,18 +412,21 @@ impl ConnectionManager {
                             // Only deserialize message types that have active consumers
                             match parse_if_interested(text.as_bytes(), &interest.get()) {
                                 Ok(messages) => {
+                                    // Only retain the frame if someone asked for it
+                                    let frame = (outlets.raw_tx.receiver_count() > 0)
+                                        .then(|| Arc::<str>::from(text.as_str()));
+
                                     for message in messages {
                                         #[cfg(feature = "tracing")]
                                         tracing::trace!(?message, "Parsed WebSocket message");
-                                        _ = broadcast_tx.send(message);
+                                        outlets.publish(message, frame.as_ref());
                                     }
 
                                 }
                                 Err(e) => {
                                     #[cfg(feature = "tracing")]
                                     tracing::warn!(%text, error = %e, "Failed to parse WebSocket message");
-                                    #[cfg(not(feature = "tracing"))]
-                                    let _ = (&text, &e);
+                                    _ = outlets.decode_errors_tx.send(Arc::new(e));
                                 }
                             }
                         }
@@ -239,7 +441,7 @@ impl ConnectionManager {
                             heartbeat_handle.abort();
                             return Err(Error::with_source(
                                 Kind::WebSocket,
-                                WsError::Connection(e),
+                                WsError::Transport(e),
                             ));
                         }
                         _ => {
@@ -275,12 +477,15 @@ impl ConnectionManager {
         Ok(())
     }
 
-    /// Heartbeat loop that sends PING messages and monitors PONG responses.
+    /// Heartbeat loop that sends PING messages and monitors PONG responses, publishing each
+    /// round trip to `latency_tx` and the configured metrics observer.
     async fn heartbeat_loop(
         ping_tx: mpsc::UnboundedSender<()>,
         state_rx: watch::Receiver<ConnectionState>,
         config: &Config,
         mut pong_rx: watch::Receiver<Instant>,
+        latency_tx: &watch::Sender<Option<Duration>>,
+        endpoint: &str,
     ) {
         let mut ping_interval = interval(config.heartbeat_interval);
 
@@ -316,6 +521,12 @@ impl ConnectionManager {
                         );
                         break;
                     }
+
+                    let round_trip = last_pong.duration_since(ping_sent);
+                    _ = latency_tx.send(Some(round_trip));
+                    if let Some(metrics) = &config.metrics {
+                        metrics.on_heartbeat(endpoint, round_trip);
+                    }
                 }
                 Ok(Err(_)) => {
                     // Channel closed, connection is terminating
@@ -374,6 +585,58 @@ impl ConnectionManager {
         self.broadcast_tx.subscribe()
     }
 
+    /// Subscribe to incoming messages paired with the raw text frame each was decoded from.
+    ///
+    /// Frames are only retained while at least one such receiver exists, so connections without
+    /// raw subscribers do not pay for copying them.
+    #[must_use]
+    pub fn subscribe_raw(&self) -> broadcast::Receiver<RawMessage> {
+        self.raw_tx.subscribe()
+    }
+
+    /// Subscribe to errors decoding incoming messages, each carrying a [`WsError::Decode`] with
+    /// the raw text of the message.
+    ///
+    /// A message that fails to decode cannot be routed to the subscriptions it was meant for, so
+    /// it is reported here instead.
+    #[must_use]
+    pub fn subscribe_decode_errors(&self) -> broadcast::Receiver<Arc<Error>> {
+        self.decode_errors_tx.subscribe()
+    }
+
+    /// The recent market messages kept for late subscribers.
+    pub(crate) fn replay(&self) -> &Arc<ReplayCache> {
+        &self.replay
+    }
+
+    /// The round trip of the latest answered heartbeat on the current connection, or [`None`]
+    /// if none has been answered since it was established.
+    #[must_use]
+    pub fn latency(&self) -> Option<Duration> {
+        *self.latency_tx.borrow()
+    }
+
+    /// Subscribe to heartbeat round trips, notified each time one is measured and reset to
+    /// [`None`] when a new connection is established.
+    #[must_use]
+    pub fn latency_receiver(&self) -> watch::Receiver<Option<Duration>> {
+        self.latency_tx.subscribe()
+    }
+
+    /// The number of consecutive failed connection attempts, counting connections that dropped
+    /// within the stability window. Reset to zero once a connection stays up for the window.
+    #[must_use]
+    pub fn reconnect_attempts(&self) -> u32 {
+        *self.attempts_tx.borrow()
+    }
+
+    /// Subscribe to the number of consecutive failed connection attempts, notified each time it
+    /// changes.
+    #[must_use]
+    pub fn reconnect_attempts_receiver(&self) -> watch::Receiver<u32> {
+        self.attempts_tx.subscribe()
+    }
+
     /// Subscribe to connection state changes.
     ///
     /// Returns a receiver that notifies when the connection state changes.
@@ -6,18 +6,48 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use super::subscription::ChannelType;
+
 /// WebSocket error variants.
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum WsError {
-    /// Error connecting to or communicating with the WebSocket server
-    Connection(tokio_tungstenite::tungstenite::Error),
-    /// Error parsing a WebSocket message
-    MessageParse(serde_json::Error),
-    /// Subscription request failed
-    SubscriptionFailed(String),
-    /// Authentication failed for authenticated channel
-    AuthenticationFailed,
+    /// Transport error communicating with the WebSocket server after the connection was
+    /// established (I/O failure, protocol violation, etc.)
+    Transport(tokio_tungstenite::tungstenite::Error),
+    /// Error establishing the connection, i.e. before or during the WebSocket handshake
+    Connect(tokio_tungstenite::tungstenite::Error),
+    /// Connection could not be established within the configured `connect_timeout`
+    ConnectTimeout,
+    /// Error tunneling through the configured proxy
+    Proxy(String),
+    /// A message received from the server could not be decoded
+    Decode {
+        /// The raw text of the message that failed to decode
+        raw: String,
+        /// The underlying deserialization error
+        source: serde_json::Error,
+    },
+    /// A subscription or unsubscription request was refused locally, before being sent, because
+    /// its arguments are invalid or the client lacks what it needs to send it
+    InvalidArgument {
+        /// The channel the request targeted
+        channel: ChannelType,
+        /// Why the request was refused
+        reason: String,
+    },
+    /// A subscription request was rejected by the server
+    SubscribeRejected {
+        /// The channel the subscription targeted
+        channel: ChannelType,
+        /// Why the subscription was rejected
+        reason: String,
+    },
+    /// The server rejected the credentials for the authenticated channel
+    AuthFailed {
+        /// Why authentication failed
+        reason: String,
+    },
     /// WebSocket connection was closed
     ConnectionClosed,
     /// Operation timed out
@@ -34,10 +64,23 @@ pub enum WsError {
 impl fmt::Display for WsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Connection(e) => write!(f, "WebSocket connection error: {e}"),
-            Self::MessageParse(e) => write!(f, "Failed to parse WebSocket message: {e}"),
-            Self::SubscriptionFailed(reason) => write!(f, "Subscription failed: {reason}"),
-            Self::AuthenticationFailed => write!(f, "WebSocket authentication failed"),
+            Self::Transport(e) => write!(f, "WebSocket transport error: {e}"),
+            Self::Connect(e) => write!(f, "Failed to establish WebSocket connection: {e}"),
+            Self::ConnectTimeout => write!(f, "Timed out establishing WebSocket connection"),
+            Self::Proxy(reason) => write!(f, "WebSocket proxy error: {reason}"),
+            Self::Decode { raw, source } => {
+                write!(
+                    f,
+                    "Failed to decode WebSocket message: {source} (raw: {raw})"
+                )
+            }
+            Self::InvalidArgument { channel, reason } => {
+                write!(f, "Invalid request for {channel} channel: {reason}")
+            }
+            Self::SubscribeRejected { channel, reason } => {
+                write!(f, "Subscription to {channel} channel rejected: {reason}")
+            }
+            Self::AuthFailed { reason } => write!(f, "WebSocket authentication failed: {reason}"),
             Self::ConnectionClosed => write!(f, "WebSocket connection closed"),
             Self::Timeout => write!(f, "WebSocket operation timed out"),
             Self::InvalidMessage(msg) => write!(f, "Invalid WebSocket message: {msg}"),
@@ -49,8 +92,8 @@ impl fmt::Display for WsError {
 impl StdError for WsError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
-            Self::Connection(e) => Some(e),
-            Self::MessageParse(e) => Some(e),
+            Self::Transport(e) | Self::Connect(e) => Some(e),
+            Self::Decode { source, .. } => Some(source),
             _ => None,
         }
     }
@@ -65,6 +108,67 @@ impl From<WsError> for crate::error::Error {
 
 impl From<tokio_tungstenite::tungstenite::Error> for crate::error::Error {
     fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
-        crate::error::Error::with_source(crate::error::Kind::WebSocket, WsError::Connection(e))
+        crate::error::Error::with_source(crate::error::Kind::WebSocket, WsError::Transport(e))
+    }
+}
+
+impl WsError {
+    /// Builds a [`WsError::Decode`] from the raw message bytes and the deserialization error.
+    pub(crate) fn decode(raw: &[u8], source: serde_json::Error) -> Self {
+        Self::Decode {
+            raw: String::from_utf8_lossy(raw).into_owned(),
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_error_should_include_raw_text() {
+        let raw = br#"{"event_type": "book", "bids": 42}"#;
+        let source = serde_json::from_slice::<Vec<u8>>(b"{").unwrap_err();
+        let err = WsError::decode(raw, source);
+
+        assert!(err.to_string().contains(r#""bids": 42"#));
+        assert!(err.source().is_some());
+        assert!(matches!(err, WsError::Decode { ref raw, .. } if raw.contains("book")));
+    }
+
+    #[test]
+    fn subscribe_rejected_should_name_channel() {
+        let err = WsError::SubscribeRejected {
+            channel: ChannelType::User,
+            reason: "market not found".to_owned(),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Subscription to user channel rejected: market not found"
+        );
+    }
+
+    #[test]
+    fn invalid_argument_should_name_channel() {
+        let err = WsError::InvalidArgument {
+            channel: ChannelType::User,
+            reason: "markets cannot be empty".to_owned(),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid request for user channel: markets cannot be empty"
+        );
+    }
+
+    #[test]
+    fn tungstenite_error_should_convert_to_transport() {
+        let err: crate::error::Error =
+            tokio_tungstenite::tungstenite::Error::ConnectionClosed.into();
+        let ws = err.downcast_ref::<WsError>().unwrap();
+
+        assert!(matches!(ws, WsError::Transport(_)));
     }
 }
@@ -0,0 +1,141 @@
+//! Merging several subscriptions into a single stream.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{BoxStream, SelectAll};
+use futures::{Stream, StreamExt as _};
+
+use super::types::response::WsMessage;
+use crate::Result;
+use crate::error::Error;
+
+/// An item of a [`MultiStream`]: a message, or an error tagged with the channel of the
+/// subscription that yielded it.
+pub type TaggedResult<C, M = WsMessage> = std::result::Result<TaggedMessage<C, M>, (C, Error)>;
+
+/// A message received by one of the subscriptions of a [`MultiStream`], tagged with the channel it
+/// was registered under.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedMessage<C, M = WsMessage> {
+    /// The tag the subscription was registered under.
+    pub channel: C,
+    pub message: M,
+}
+
+/// Merges several subscriptions into one stream, yielding their messages as they arrive, each
+/// tagged with the channel it came from.
+///
+/// Subscriptions are polled independently: one that is reconnecting or quiet does not hold back
+/// the others, and errors are yielded without ending the merged stream, tagged with the channel
+/// they came from. A subscription that ends, such as one that fell behind with a
+/// [`Lagged`](super::WsError::Lagged) error, is dropped, and the merged stream ends once all of
+/// them have; the tag tells which channel to resubscribe.
+///
+/// Subscriptions yielding different types can be merged by mapping them to a common one, such as
+/// [`WsMessage`]:
+///
+/// ```rust, no_run
+/// use futures::{StreamExt as _, TryStreamExt as _};
+/// use polymarket_client_sdk::clob::ws::{Client, MultiStream, WsMessage};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum Feed {
+///     Books,
+///     Prices,
+/// }
+///
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// let client = Client::default();
+/// let assets = vec!["asset_id".to_owned()];
+///
+/// let mut events = MultiStream::new()
+///     .add(Feed::Books, client.subscribe_orderbook(assets.clone())?.map_ok(WsMessage::Book))
+///     .add(Feed::Prices, client.subscribe_price_changes(assets)?.map_ok(WsMessage::PriceChange));
+///
+/// while let Some(event) = events.next().await {
+///     match event {
+///         Ok(event) => println!("{:?}: {:?}", event.channel, event.message),
+///         Err((channel, error)) => eprintln!("{channel:?} failed: {error}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct MultiStream<'stream, C, M = WsMessage> {
+    streams: SelectAll<BoxStream<'stream, TaggedResult<C, M>>>,
+}
+
+impl<'stream, C, M> MultiStream<'stream, C, M>
+where
+    C: Clone + Send + 'stream,
+    M: 'stream,
+{
+    /// Creates a stream with no subscriptions, which ends immediately until some are added.
+    pub fn new() -> Self {
+        Self {
+            streams: SelectAll::new(),
+        }
+    }
+
+    /// Adds `stream` to the merged subscriptions, tagging its messages and errors with `channel`.
+    pub fn add<S>(mut self, channel: C, stream: S) -> Self
+    where
+        S: Stream<Item = Result<M>> + Send + 'stream,
+    {
+        self.push(channel, stream);
+        self
+    }
+
+    /// Adds `stream` to the merged subscriptions, tagging its messages and errors with `channel`,
+    /// while the stream is already being polled.
+    pub fn push<S>(&mut self, channel: C, stream: S)
+    where
+        S: Stream<Item = Result<M>> + Send + 'stream,
+    {
+        let tagged = stream.map(move |message| match message {
+            Ok(message) => Ok(TaggedMessage {
+                channel: channel.clone(),
+                message,
+            }),
+            Err(error) => Err((channel.clone(), error)),
+        });
+        self.streams.push(tagged.boxed());
+    }
+
+    /// Returns the number of subscriptions that have not ended yet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns whether every subscription has ended, or none were added.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<'stream, C, M> Default for MultiStream<'stream, C, M>
+where
+    C: Clone + Send + 'stream,
+    M: 'stream,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, M> Stream for MultiStream<'_, C, M> {
+    type Item = TaggedResult<C, M>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.streams.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams.size_hint()
+    }
+}
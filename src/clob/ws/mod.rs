@@ -8,13 +8,18 @@ pub mod config;
 pub mod connection;
 pub mod error;
 pub mod interest;
+pub mod merge;
+mod replay;
+pub mod store;
 pub mod subscription;
 pub mod types;
 
 // Re-export commonly used types
 pub use client::Client;
-pub use config::{Config, ReconnectConfig};
+pub use config::{Config, ProxyConfig, ReconnectConfig};
 pub use error::WsError;
+pub use merge::{MultiStream, TaggedMessage, TaggedResult};
+pub use store::OrderBookStore;
 pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
@@ -0,0 +1,283 @@
+//! Recent market messages kept per asset, replayed to late subscribers.
+
+use std::collections::VecDeque;
+
+use dashmap::DashMap;
+
+use super::types::response::WsMessage;
+
+/// The latest book snapshot of each tracked asset and the updates received since, so a subscriber
+/// joining an already subscribed asset can start from a consistent state.
+///
+/// At most `capacity` updates are kept per asset, bounding memory.
+#[derive(Debug)]
+pub(crate) struct ReplayCache {
+    capacity: usize,
+    assets: DashMap<String, AssetReplay>,
+}
+
+#[derive(Debug, Default)]
+struct AssetReplay {
+    book: Option<WsMessage>,
+    updates: VecDeque<WsMessage>,
+}
+
+impl ReplayCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            assets: DashMap::new(),
+        }
+    }
+
+    /// Starts keeping messages for `asset_id`, if not already. Returns `true` if it was not
+    /// tracked before.
+    pub(crate) fn track(&self, asset_id: &str) -> bool {
+        let mut tracked = false;
+        self.assets.entry(asset_id.to_owned()).or_insert_with(|| {
+            tracked = true;
+            AssetReplay::default()
+        });
+        tracked
+    }
+
+    /// Stops keeping messages for `asset_id`, releasing those kept so far.
+    pub(crate) fn untrack(&self, asset_id: &str) {
+        self.assets.remove(asset_id);
+    }
+
+    /// Forgets every kept message while still tracking the same assets, e.g. after messages were
+    /// missed and the kept ones no longer describe the book. Tracking resumes from the next
+    /// snapshot.
+    pub(crate) fn reset(&self) {
+        for mut entry in self.assets.iter_mut() {
+            *entry = AssetReplay::default();
+        }
+    }
+
+    /// Keeps `message` if it concerns a tracked asset. A book snapshot replaces everything kept
+    /// for its asset.
+    ///
+    /// Only messages describing the state of the book are kept. Trades are events rather than
+    /// state, and replaying them would make a consumer counting trades count them twice after a
+    /// resubscribe or reconnect.
+    pub(crate) fn record(&self, message: &WsMessage) {
+        if self.assets.is_empty() {
+            return;
+        }
+
+        match message {
+            WsMessage::Book(book) => {
+                if let Some(mut replay) = self.assets.get_mut(&book.asset_id) {
+                    replay.book = Some(message.clone());
+                    replay.updates.clear();
+                }
+            }
+            WsMessage::PriceChange(change) => {
+                for (i, entry) in change.price_changes.iter().enumerate() {
+                    let repeated = change
+                        .price_changes
+                        .iter()
+                        .take(i)
+                        .any(|other| other.asset_id == entry.asset_id);
+                    if repeated {
+                        continue;
+                    }
+                    let Some(mut replay) = self.assets.get_mut(&entry.asset_id) else {
+                        continue;
+                    };
+                    // Keep only this asset's entries so replaying several assets does not repeat
+                    // the others'
+                    let mut change = change.clone();
+                    change
+                        .price_changes
+                        .retain(|other| other.asset_id == entry.asset_id);
+                    self.push(&mut replay, WsMessage::PriceChange(change));
+                }
+            }
+            WsMessage::TickSizeChange(change) => self.push_for(&change.asset_id, message),
+            WsMessage::BestBidAsk(quote) => self.push_for(&quote.asset_id, message),
+            _ => {}
+        }
+    }
+
+    /// Returns the messages kept for `asset_ids`, each asset's snapshot followed by its updates
+    /// in the order they were received.
+    pub(crate) fn replay(&self, asset_ids: &[String]) -> Vec<WsMessage> {
+        asset_ids
+            .iter()
+            .filter_map(|asset_id| self.assets.get(asset_id))
+            .flat_map(|replay| {
+                replay
+                    .book
+                    .iter()
+                    .chain(&replay.updates)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn push_for(&self, asset_id: &str, message: &WsMessage) {
+        if let Some(mut replay) = self.assets.get_mut(asset_id) {
+            self.push(&mut replay, message.clone());
+        }
+    }
+
+    /// Keeps `message` as the latest update to `replay`, unless there is no snapshot for it to
+    /// apply to.
+    ///
+    /// Dropping the oldest update would leave a gap between the snapshot and the rest, so once
+    /// `capacity` is exceeded the asset is forgotten until its next snapshot instead.
+    fn push(&self, replay: &mut AssetReplay, message: WsMessage) {
+        if replay.book.is_none() {
+            return;
+        }
+        if replay.updates.len() >= self.capacity {
+            *replay = AssetReplay::default();
+            return;
+        }
+        replay.updates.push_back(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn message(value: Value) -> WsMessage {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn book(asset_id: &str) -> WsMessage {
+        message(json!({
+            "event_type": "book",
+            "asset_id": asset_id,
+            "market": "market",
+            "timestamp": "1",
+            "bids": [{ "price": "0.5", "size": "100" }],
+            "asks": [{ "price": "0.51", "size": "50" }]
+        }))
+    }
+
+    fn price_change(asset_ids: &[&str]) -> WsMessage {
+        let entries: Vec<Value> = asset_ids
+            .iter()
+            .map(|asset_id| {
+                json!({ "asset_id": asset_id, "price": "0.5", "size": "10", "side": "BUY" })
+            })
+            .collect();
+        message(json!({
+            "event_type": "price_change",
+            "market": "market",
+            "timestamp": "2",
+            "price_changes": entries
+        }))
+    }
+
+    fn assets(messages: &[WsMessage]) -> Vec<Vec<String>> {
+        messages
+            .iter()
+            .map(|message| match message {
+                WsMessage::Book(book) => vec![book.asset_id.clone()],
+                WsMessage::PriceChange(change) => change
+                    .price_changes
+                    .iter()
+                    .map(|entry| entry.asset_id.clone())
+                    .collect(),
+                other => panic!("unexpected message {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replay_should_start_from_the_latest_snapshot() {
+        let cache = ReplayCache::new(10);
+        cache.track("1");
+        cache.track("2");
+
+        cache.record(&price_change(&["1"]));
+        cache.record(&book("1"));
+        cache.record(&book("2"));
+        cache.record(&price_change(&["1", "2", "3"]));
+        cache.record(&book("3"));
+
+        let replayed = cache.replay(&["1".to_owned(), "2".to_owned()]);
+
+        assert_eq!(
+            assets(&replayed),
+            vec![
+                vec!["1".to_owned()],
+                vec!["1".to_owned()],
+                vec!["2".to_owned()],
+                vec!["2".to_owned()],
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_should_skip_trades() {
+        let cache = ReplayCache::new(10);
+        cache.track("1");
+
+        cache.record(&book("1"));
+        cache.record(&message(json!({
+            "event_type": "last_trade_price",
+            "asset_id": "1",
+            "market": "market",
+            "price": "0.5",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "timestamp": "2"
+        })));
+        cache.record(&price_change(&["1"]));
+
+        let replayed = cache.replay(&["1".to_owned()]);
+
+        assert_eq!(
+            assets(&replayed),
+            vec![vec!["1".to_owned()], vec!["1".to_owned()]]
+        );
+    }
+
+    #[test]
+    fn replay_should_forget_an_asset_past_capacity() {
+        let cache = ReplayCache::new(2);
+        cache.track("1");
+
+        cache.record(&book("1"));
+        cache.record(&price_change(&["1"]));
+        cache.record(&price_change(&["1"]));
+        assert_eq!(cache.replay(&["1".to_owned()]).len(), 3);
+
+        cache.record(&price_change(&["1"]));
+        assert!(cache.replay(&["1".to_owned()]).is_empty());
+
+        cache.record(&book("1"));
+        assert_eq!(cache.replay(&["1".to_owned()]).len(), 1);
+
+        cache.untrack("1");
+        assert!(cache.replay(&["1".to_owned()]).is_empty());
+    }
+
+    #[test]
+    fn reset_should_keep_tracking_from_the_next_snapshot() {
+        let cache = ReplayCache::new(10);
+        assert!(cache.track("1"));
+        assert!(!cache.track("1"));
+
+        cache.record(&book("1"));
+        cache.record(&price_change(&["1"]));
+        cache.reset();
+        assert!(cache.replay(&["1".to_owned()]).is_empty());
+
+        cache.record(&price_change(&["1"]));
+        assert!(cache.replay(&["1".to_owned()]).is_empty());
+
+        cache.record(&book("1"));
+        assert_eq!(cache.replay(&["1".to_owned()]).len(), 1);
+    }
+}
@@ -0,0 +1,239 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`OrderBookStore` reads better at use sites than `store::OrderBook`"
+)]
+
+//! Order books maintained from the market channel, with staleness tracking.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::types::response::{BookUpdate, OrderBookLevel, PriceChange, WsMessage};
+use crate::clob::types::Side;
+use crate::types::Decimal;
+
+/// The resting size at each price level of one side of a maintained book.
+pub type Levels = BTreeMap<Decimal, Decimal>;
+
+/// Called with the asset ID and its last update when a book crosses the staleness threshold.
+type StalenessCallback = Box<dyn FnMut(&str, Instant) + Send>;
+
+/// Order books kept up to date from [`BookUpdate`] snapshots and [`PriceChange`] deltas, keyed by
+/// asset ID, along with when each book was last updated.
+///
+/// The store does not subscribe by itself: feed it the messages of
+/// [`Client::subscribe_orderbook`](super::Client::subscribe_orderbook) and
+/// [`Client::subscribe_price_changes`](super::Client::subscribe_price_changes), for instance
+/// merged with a [`MultiStream`](super::MultiStream), and call
+/// [`check_staleness`](Self::check_staleness) periodically to have the staleness callback fire:
+///
+/// ```rust, no_run
+/// use std::time::Duration;
+///
+/// use futures::{StreamExt as _, TryStreamExt as _};
+/// use polymarket_client_sdk::clob::ws::{Client, MultiStream, OrderBookStore, WsMessage};
+///
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// let client = Client::default();
+/// let assets = vec!["asset_id".to_owned()];
+///
+/// let mut events = MultiStream::new()
+///     .add("books", client.subscribe_orderbook(assets.clone())?.map_ok(WsMessage::Book))
+///     .add("prices", client.subscribe_price_changes(assets)?.map_ok(WsMessage::PriceChange));
+/// let mut store = OrderBookStore::new().with_staleness_callback(
+///     Duration::from_secs(10),
+///     |asset_id, _| println!("pulling quotes on {asset_id}"),
+/// );
+/// let mut ticks = tokio::time::interval(Duration::from_secs(1));
+///
+/// loop {
+///     tokio::select! {
+///         Some(event) = events.next() => match event {
+///             Ok(event) => store.apply(&event.message),
+///             Err((_, error)) => return Err(error),
+///         },
+///         _ = ticks.tick() => {
+///             store.check_staleness();
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct OrderBookStore {
+    books: HashMap<String, StoredBook>,
+    staleness: Option<Staleness>,
+}
+
+struct StoredBook {
+    bids: Levels,
+    asks: Levels,
+    last_update: Instant,
+    /// Whether the staleness callback already fired since `last_update`.
+    reported: bool,
+}
+
+struct Staleness {
+    max_age: Duration,
+    callback: StalenessCallback,
+}
+
+impl OrderBookStore {
+    /// Creates an empty store without a staleness callback.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `callback` to be called by [`check_staleness`](Self::check_staleness) once for each
+    /// book that has gone `max_age` without an update. It fires again only after the book has
+    /// been updated and gone stale anew.
+    #[must_use]
+    pub fn with_staleness_callback<F>(mut self, max_age: Duration, callback: F) -> Self
+    where
+        F: FnMut(&str, Instant) + Send + 'static,
+    {
+        self.staleness = Some(Staleness {
+            max_age,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Applies a market channel message, ignoring those that do not change a book.
+    pub fn apply(&mut self, message: &WsMessage) {
+        match message {
+            WsMessage::Book(book) => self.apply_book(book),
+            WsMessage::PriceChange(change) => self.apply_price_change(change),
+            _ => {}
+        }
+    }
+
+    /// Replaces the book of `book.asset_id` with the snapshot.
+    pub fn apply_book(&mut self, book: &BookUpdate) {
+        self.books.insert(
+            book.asset_id.clone(),
+            StoredBook {
+                bids: to_levels(&book.bids),
+                asks: to_levels(&book.asks),
+                last_update: Instant::now(),
+                reported: false,
+            },
+        );
+    }
+
+    /// Applies the level updates of `change` to the books already in the store.
+    ///
+    /// Changes to assets without a snapshot yet are ignored, since a book cannot be built from
+    /// deltas alone.
+    pub fn apply_price_change(&mut self, change: &PriceChange) {
+        let now = Instant::now();
+        for entry in &change.price_changes {
+            let Some(book) = self.books.get_mut(&entry.asset_id) else {
+                continue;
+            };
+
+            if let Some(size) = entry.size {
+                let levels = match entry.side {
+                    Side::Buy => &mut book.bids,
+                    _ => &mut book.asks,
+                };
+                if size.is_zero() {
+                    levels.remove(&entry.price);
+                } else {
+                    levels.insert(entry.price, size);
+                }
+            }
+            book.last_update = now;
+            book.reported = false;
+        }
+    }
+
+    /// Returns the bid levels of `asset_id`, if it has a book.
+    #[must_use]
+    pub fn bids(&self, asset_id: &str) -> Option<&Levels> {
+        self.books.get(asset_id).map(|book| &book.bids)
+    }
+
+    /// Returns the ask levels of `asset_id`, if it has a book.
+    #[must_use]
+    pub fn asks(&self, asset_id: &str) -> Option<&Levels> {
+        self.books.get(asset_id).map(|book| &book.asks)
+    }
+
+    /// Returns the highest bid of `asset_id` and its size.
+    #[must_use]
+    pub fn best_bid(&self, asset_id: &str) -> Option<(Decimal, Decimal)> {
+        self.bids(asset_id)?
+            .last_key_value()
+            .map(|(price, size)| (*price, *size))
+    }
+
+    /// Returns the lowest ask of `asset_id` and its size.
+    #[must_use]
+    pub fn best_ask(&self, asset_id: &str) -> Option<(Decimal, Decimal)> {
+        self.asks(asset_id)?
+            .first_key_value()
+            .map(|(price, size)| (*price, *size))
+    }
+
+    /// Returns when the book of `asset_id` was last updated, by a snapshot or a price change.
+    #[must_use]
+    pub fn last_update(&self, asset_id: &str) -> Option<Instant> {
+        self.books.get(asset_id).map(|book| book.last_update)
+    }
+
+    /// Returns whether the book of `asset_id` has gone `max_age` without an update.
+    ///
+    /// An asset without a book is stale, so that callers never quote against missing data.
+    #[must_use]
+    pub fn is_stale(&self, asset_id: &str, max_age: Duration) -> bool {
+        self.last_update(asset_id)
+            .is_none_or(|last_update| last_update.elapsed() >= max_age)
+    }
+
+    /// Calls the staleness callback for each book that crossed its threshold since the last
+    /// check, returning how many did. Does nothing without a callback.
+    pub fn check_staleness(&mut self) -> usize {
+        let Some(staleness) = &mut self.staleness else {
+            return 0;
+        };
+
+        let mut crossed = 0;
+        for (asset_id, book) in &mut self.books {
+            if !book.reported && book.last_update.elapsed() >= staleness.max_age {
+                book.reported = true;
+                crossed += 1;
+                (staleness.callback)(asset_id, book.last_update);
+            }
+        }
+
+        crossed
+    }
+
+    /// Removes the book of `asset_id`, for instance after unsubscribing from it.
+    pub fn remove(&mut self, asset_id: &str) {
+        self.books.remove(asset_id);
+    }
+}
+
+fn to_levels(levels: &[OrderBookLevel]) -> Levels {
+    levels
+        .iter()
+        .filter(|level| !level.size.is_zero())
+        .map(|level| (level.price, level.size))
+        .collect()
+}
+
+impl fmt::Debug for OrderBookStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderBookStore")
+            .field("assets", &self.books.keys().collect::<Vec<_>>())
+            .field(
+                "max_age",
+                &self.staleness.as_ref().map(|staleness| staleness.max_age),
+            )
+            .finish_non_exhaustive()
+    }
+}
@@ -4,9 +4,11 @@
 )]
 
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Instant;
 
+use alloy::primitives::U256;
 use async_stream::try_stream;
 use dashmap::DashMap;
 use futures::Stream;
@@ -68,6 +70,15 @@ pub enum ChannelType {
     User,
 }
 
+impl fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Market => write!(f, "market"),
+            Self::User => write!(f, "user"),
+        }
+    }
+}
+
 /// Manages active subscriptions and routes messages to subscribers.
 pub struct SubscriptionManager {
     connection: ConnectionManager,
@@ -208,15 +219,193 @@ impl SubscriptionManager {
         asset_ids: Vec<String>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.subscribe_market_with_interest(asset_ids, custom_features, MessageInterest::MARKET)
+    }
+
+    /// Subscribe to public market data channel, only registering `interest` in the message types
+    /// the caller will consume.
+    ///
+    /// Message types that no subscription is interested in are skipped before being decoded, so
+    /// narrow subscriptions avoid the cost of parsing e.g. full book snapshots.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_with_interest(
+        &self,
+        asset_ids: Vec<String>,
+        custom_features: bool,
+        interest: MessageInterest,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.register_market(&asset_ids, custom_features, interest)?;
+
+        // Create filtered stream with its own receiver
+        let mut rx = self.connection.subscribe();
+        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
+
+        Ok(try_stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(msg) => {
+                        if is_for_assets(&msg, &asset_ids_set) {
+                            yield msg
+                        }
+                    }
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        Err(WsError::Lagged { count: n })?;
+                    }
+                    Err(RecvError::Closed) => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Subscribe to public market data channel, pairing each message with the verbatim text frame
+    /// it was received in (e.g. to keep an audit trail of the feed).
+    ///
+    /// Frames are only retained while a raw subscription is active, so other subscriptions are
+    /// unaffected by the cost of copying them.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_raw(
+        &self,
+        asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<(WsMessage, String)>>> {
+        // Subscribe before registering so that no frame is dropped for lack of raw receivers
+        let mut rx = self.connection.subscribe_raw();
+        self.register_market(&asset_ids, false, MessageInterest::MARKET)?;
+
+        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
+
+        Ok(try_stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(raw) => {
+                        if is_for_assets(&raw.message, &asset_ids_set) {
+                            yield (raw.message, raw.frame.to_string())
+                        }
+                    }
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        Err(WsError::Lagged { count: n })?;
+                    }
+                    Err(RecvError::Closed) => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Subscribe to public market data, first yielding the latest book snapshot of each asset
+    /// and the updates received since, then live messages.
+    ///
+    /// Only book snapshots, price changes, tick size changes and best bid/ask updates are
+    /// replayed. Last trade prices are only yielded live, so that trades are never seen twice
+    /// after a resubscribe or reconnect.
+    ///
+    /// An asset's messages are only kept from its first replaying subscription on, and released
+    /// once it is fully unsubscribed. An asset that was already subscribed without replay is
+    /// subscribed again so the server sends a fresh snapshot to start from. A message arriving
+    /// while subscribing may be yielded both replayed and live; books and price levels carry
+    /// absolute sizes, so applying it twice is harmless.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_with_replay(
+        &self,
+        asset_ids: Vec<String>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        let replay = self.connection.replay();
+        // Assets already subscribed without replay will not get a new subscription request, and
+        // with it a fresh snapshot, from `register_market`
+        let resnapshot: Vec<String> = asset_ids
+            .iter()
+            .filter(|asset_id| replay.track(asset_id))
+            .filter(|asset_id| self.subscribed_assets.contains_key(*asset_id))
+            .cloned()
+            .collect();
+
+        if let Err(e) = self.register_market(&asset_ids, false, MessageInterest::MARKET) {
+            for asset_id in &asset_ids {
+                if !self.subscribed_assets.contains_key(asset_id) {
+                    replay.untrack(asset_id);
+                }
+            }
+            return Err(e);
+        }
+
+        if !resnapshot.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                count = resnapshot.len(),
+                ?resnapshot,
+                "Re-subscribing to market assets for a fresh snapshot"
+            );
+            self.connection
+                .send(&SubscriptionRequest::market(resnapshot))?;
+        }
+
+        // Subscribe before reading the replay so that nothing received in between is missed
+        let mut rx = self.connection.subscribe();
+        let replayed = replay.replay(&asset_ids);
+        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
+
+        Ok(try_stream! {
+            for msg in replayed {
+                yield msg;
+            }
+
+            loop {
+                match rx.recv().await {
+                    Ok(msg) => {
+                        if is_for_assets(&msg, &asset_ids_set) {
+                            yield msg
+                        }
+                    }
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        Err(WsError::Lagged { count: n })?;
+                    }
+                    Err(RecvError::Closed) => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Register interest in `asset_ids`, sending a subscription request for those not already
+    /// subscribed to.
+    fn register_market(
+        &self,
+        asset_ids: &[String],
+        custom_features: bool,
+        interest: MessageInterest,
+    ) -> Result<()> {
         if asset_ids.is_empty() {
-            return Err(WsError::SubscriptionFailed(
-                "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
+            return Err(WsError::InvalidArgument {
+                channel: ChannelType::Market,
+                reason: "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
                     .to_owned(),
-            )
+            }
+            .into());
+        }
+
+        if let Some(invalid) = asset_ids.iter().find(|id| !is_valid_asset_id(id)) {
+            return Err(WsError::InvalidArgument {
+                channel: ChannelType::Market,
+                reason: format!(
+                    "{invalid:?} is not a valid asset ID: expected a non-zero decimal token ID"
+                ),
+            }
             .into());
         }
 
-        self.interest.add(MessageInterest::MARKET);
+        self.interest.add(interest);
 
         // Increment refcounts and determine which assets are truly new
         let new_assets: Vec<String> = asset_ids
@@ -259,55 +448,12 @@ impl SubscriptionManager {
         self.active_subs.insert(
             sub_id,
             SubscriptionInfo {
-                target: SubscriptionTarget::Assets(asset_ids.clone()),
+                target: SubscriptionTarget::Assets(asset_ids.to_vec()),
                 created_at: Instant::now(),
             },
         );
 
-        // Create filtered stream with its own receiver
-        let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
-
-        Ok(try_stream! {
-            loop {
-                match rx.recv().await {
-                    Ok(msg) => {
-                        // Filter messages by asset_id
-                        let should_yield = match &msg {
-                            WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
-                            WsMessage::PriceChange(price) => {
-                                price
-                                    .price_changes
-                                    .iter()
-                                    .any(|pc| asset_ids_set.contains(&pc.asset_id))
-                            },
-                            WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
-                            WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
-                            WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
-                            WsMessage::NewMarket(nm) => {
-                                nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            WsMessage::MarketResolved(mr) => {
-                                mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            _ => false,
-                        };
-
-                        if should_yield {
-                            yield msg
-                        }
-                    }
-                    Err(RecvError::Lagged(n)) => {
-                        #[cfg(feature = "tracing")]
-                        tracing::warn!("Subscription lagged, missed {n} messages");
-                        Err(WsError::Lagged { count: n })?;
-                    }
-                    Err(RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-        })
+        Ok(())
     }
 
     /// Subscribe to authenticated user channel.
@@ -417,10 +563,11 @@ impl SubscriptionManager {
     /// are using that asset).
     pub fn unsubscribe_market(&self, asset_ids: &[String]) -> Result<()> {
         if asset_ids.is_empty() {
-            return Err(WsError::SubscriptionFailed(
-                "asset_ids cannot be empty: at least one asset ID must be provided for unsubscription"
+            return Err(WsError::InvalidArgument {
+                channel: ChannelType::Market,
+                reason: "asset_ids cannot be empty: at least one asset ID must be provided for unsubscription"
                     .to_owned(),
-            )
+            }
             .into());
         }
 
@@ -439,6 +586,7 @@ impl SubscriptionManager {
         // Clean up tracking structures for zero-refcount assets
         for id in &to_unsubscribe {
             self.subscribed_assets.remove(id);
+            self.connection.replay().untrack(id);
         }
 
         // Send unsubscribe only for zero-refcount assets
@@ -475,10 +623,11 @@ impl SubscriptionManager {
     /// are using that market).
     pub fn unsubscribe_user(&self, markets: &[String]) -> Result<()> {
         if markets.is_empty() {
-            return Err(WsError::SubscriptionFailed(
-                "markets cannot be empty: at least one market ID must be provided for unsubscription"
+            return Err(WsError::InvalidArgument {
+                channel: ChannelType::User,
+                reason: "markets cannot be empty: at least one market ID must be provided for unsubscription"
                     .to_owned(),
-            )
+            }
             .into());
         }
 
@@ -514,7 +663,11 @@ impl SubscriptionManager {
                 .read()
                 .unwrap_or_else(PoisonError::into_inner)
                 .clone()
-                .ok_or(WsError::AuthenticationFailed)?;
+                .ok_or_else(|| WsError::InvalidArgument {
+                    channel: ChannelType::User,
+                    reason: "no credentials available: subscribe to the user channel before unsubscribing"
+                        .to_owned(),
+                })?;
 
             let request = SubscriptionRequest::user_unsubscribe(to_unsubscribe, auth);
             self.connection.send(&request)?;
@@ -535,3 +688,29 @@ impl SubscriptionManager {
         Ok(())
     }
 }
+
+/// Returns whether `asset_id` is shaped like a token ID, i.e. a non-zero decimal `U256`.
+///
+/// The server silently ignores subscriptions to unknown assets, so catching malformed IDs (a
+/// condition ID, stray whitespace) locally is the only feedback available before subscribing.
+fn is_valid_asset_id(asset_id: &str) -> bool {
+    asset_id.bytes().all(|byte| byte.is_ascii_digit())
+        && U256::from_str_radix(asset_id, 10).is_ok_and(|id| !id.is_zero())
+}
+
+/// Whether `msg` concerns any of `asset_ids`.
+fn is_for_assets(msg: &WsMessage, asset_ids: &HashSet<String>) -> bool {
+    match msg {
+        WsMessage::Book(book) => asset_ids.contains(&book.asset_id),
+        WsMessage::PriceChange(price) => price
+            .price_changes
+            .iter()
+            .any(|pc| asset_ids.contains(&pc.asset_id)),
+        WsMessage::LastTradePrice(ltp) => asset_ids.contains(&ltp.asset_id),
+        WsMessage::TickSizeChange(tsc) => asset_ids.contains(&tsc.asset_id),
+        WsMessage::BestBidAsk(bba) => asset_ids.contains(&bba.asset_id),
+        WsMessage::NewMarket(nm) => nm.asset_ids.iter().any(|id| asset_ids.contains(id)),
+        WsMessage::MarketResolved(mr) => mr.asset_ids.iter().any(|id| asset_ids.contains(id)),
+        _ => false,
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt;
+use std::str::FromStr as _;
 
+use alloy::primitives::U256;
 use serde::de::{IgnoredAny, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer as _, Serialize};
 use serde_json::Deserializer;
@@ -7,8 +9,8 @@ use serde_with::{DisplayFromStr, serde_as};
 
 use crate::auth::ApiKey;
 use crate::clob::types::{Side, TraderSide};
+use crate::clob::ws::error::WsError;
 use crate::clob::ws::interest::MessageInterest;
-use crate::error::Kind;
 use crate::types::Decimal;
 
 /// Top-level WebSocket message wrapper.
@@ -394,6 +396,24 @@ pub struct OrderMessage {
     pub associate_trades: Option<Vec<String>>,
 }
 
+impl OrderMessage {
+    /// Returns [`OrderMessage::asset_id`] as a [`U256`] token ID, or [`None`] if it does not
+    /// parse.
+    #[must_use]
+    pub fn asset_id_u256(&self) -> Option<U256> {
+        U256::from_str(self.asset_id.trim()).ok()
+    }
+
+    /// Returns the size still open on the order, i.e. [`OrderMessage::original_size`] less
+    /// [`OrderMessage::size_matched`], or [`None`] if the original size is absent.
+    #[must_use]
+    pub fn remaining_size(&self) -> Option<Decimal> {
+        let matched = self.size_matched.unwrap_or_default();
+
+        Some(self.original_size? - matched)
+    }
+}
+
 /// Order status for WebSocket order messages.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
@@ -503,8 +523,7 @@ pub fn parse_if_interested(
     bytes: &[u8],
     interest: &MessageInterest,
 ) -> crate::Result<Vec<WsMessage>> {
-    let shape = peek_message_shape(bytes)
-        .map_err(|e| crate::error::Error::with_source(Kind::Internal, Box::new(e)))?;
+    let shape = peek_message_shape(bytes).map_err(|e| WsError::decode(bytes, e))?;
 
     match shape {
         MessageShape::Single(None) => Ok(vec![]),
@@ -512,11 +531,13 @@ pub fn parse_if_interested(
             if !interest.is_interested_in_event(&event_type) {
                 return Ok(vec![]);
             }
-            let msg: WsMessage = serde_json::from_slice(bytes)?;
+            let msg: WsMessage =
+                serde_json::from_slice(bytes).map_err(|e| WsError::decode(bytes, e))?;
             Ok(vec![msg])
         }
         MessageShape::Array => {
-            let messages: Vec<WsMessage> = serde_json::from_slice(bytes)?;
+            let messages: Vec<WsMessage> =
+                serde_json::from_slice(bytes).map_err(|e| WsError::decode(bytes, e))?;
             Ok(messages
                 .into_iter()
                 .filter(|msg| matches_interest(msg, *interest))
@@ -527,6 +548,7 @@ pub fn parse_if_interested(
 
 #[cfg(test)]
 mod tests {
+    use alloy::primitives::uint;
     use rust_decimal_macros::dec;
 
     use super::*;
@@ -704,6 +726,42 @@ mod tests {
         assert_eq!(msgs.len(), 2);
     }
 
+    #[test]
+    fn parse_partially_filled_order_message() {
+        let json = r#"{
+            "event_type": "order",
+            "id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+            "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+            "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+            "side": "SELL",
+            "price": "0.57",
+            "type": "UPDATE",
+            "outcome": "YES",
+            "owner": "9180014b-33c8-9240-a14b-fdca11c0a465",
+            "order_owner": "9180014b-33c8-9240-a14b-fdca11c0a465",
+            "original_size": "125.123456",
+            "size_matched": "47.654321",
+            "timestamp": "1672290687",
+            "associate_trades": ["28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e"]
+        }"#;
+
+        let msg: WsMessage = serde_json::from_str(json).unwrap();
+        let WsMessage::Order(order) = msg else {
+            panic!("Expected Order message");
+        };
+
+        assert_eq!(order.price, dec!(0.57));
+        assert_eq!(order.original_size, Some(dec!(125.123456)));
+        assert_eq!(order.size_matched, Some(dec!(47.654321)));
+        assert_eq!(order.remaining_size(), Some(dec!(77.469135)));
+        assert_eq!(
+            order.asset_id_u256(),
+            Some(uint!(
+                52114319501245915516055106046884209969926127482827954674443846427813813222426_U256
+            ))
+        );
+    }
+
     #[test]
     fn parse_best_bid_ask_message() {
         let json = r#"{
@@ -873,6 +931,16 @@ mod tests {
         assert_eq!(msgs.len(), 2);
     }
 
+    #[test]
+    fn parse_failure_should_carry_raw_text() {
+        let json = r#"{"event_type": "book", "asset_id": 123}"#;
+
+        let err = parse_if_interested(json.as_bytes(), &MessageInterest::ALL).unwrap_err();
+        let ws = err.downcast_ref::<WsError>().unwrap();
+
+        assert!(matches!(ws, WsError::Decode { raw, .. } if raw == json));
+    }
+
     #[test]
     fn parse_new_market_without_event_message() {
         let json = r#"{
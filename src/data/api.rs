@@ -0,0 +1,61 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`DataApi` names the API the trait abstracts over"
+)]
+
+//! Trait-based interface to the Data API.
+
+use async_trait::async_trait;
+
+use super::Client;
+use super::types::request::{
+    ActivityRequest, ClosedPositionsRequest, PositionsRequest, TradesRequest, ValueRequest,
+};
+use super::types::response::{Activity, ClosedPosition, Position, Trade, Value};
+use crate::Result;
+
+/// The Data API endpoints most commonly used by trading code.
+///
+/// Depend on this trait rather than on [`Client`] to be able to substitute a fake in tests, such
+/// as `FakeDataApi` from the `test-util` feature. Each method behaves like the [`Client`] method
+/// of the same name.
+#[async_trait]
+pub trait DataApi: Send + Sync {
+    /// See [`Client::positions`].
+    async fn positions(&self, req: &PositionsRequest) -> Result<Vec<Position>>;
+
+    /// See [`Client::trades`].
+    async fn trades(&self, req: &TradesRequest) -> Result<Vec<Trade>>;
+
+    /// See [`Client::activity`].
+    async fn activity(&self, req: &ActivityRequest) -> Result<Vec<Activity>>;
+
+    /// See [`Client::value`].
+    async fn value(&self, req: &ValueRequest) -> Result<Vec<Value>>;
+
+    /// See [`Client::closed_positions`].
+    async fn closed_positions(&self, req: &ClosedPositionsRequest) -> Result<Vec<ClosedPosition>>;
+}
+
+#[async_trait]
+impl DataApi for Client {
+    async fn positions(&self, req: &PositionsRequest) -> Result<Vec<Position>> {
+        Client::positions(self, req).await
+    }
+
+    async fn trades(&self, req: &TradesRequest) -> Result<Vec<Trade>> {
+        Client::trades(self, req).await
+    }
+
+    async fn activity(&self, req: &ActivityRequest) -> Result<Vec<Activity>> {
+        Client::activity(self, req).await
+    }
+
+    async fn value(&self, req: &ValueRequest) -> Result<Vec<Value>> {
+        Client::value(self, req).await
+    }
+
+    async fn closed_positions(&self, req: &ClosedPositionsRequest) -> Result<Vec<ClosedPosition>> {
+        Client::closed_positions(self, req).await
+    }
+}
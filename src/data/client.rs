@@ -24,14 +24,24 @@
 //! # }
 //! ```
 
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
 use reqwest::{
-    Client as ReqwestClient, Method,
+    Client as ReqwestClient, Method, Request,
     header::{HeaderMap, HeaderValue},
 };
+use rust_decimal::prelude::ToPrimitive as _;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use url::Url;
 
+use super::pnl::{MarketPnl, market_pnl};
+use super::positions::{
+    PositionsDiff, RedeemPositionsRequest, diff_positions, plan_redemptions,
+    sort_closed_positions_by_keys, sort_positions_by_keys,
+};
+use super::scoped::ScopedClient;
 use super::types::request::{
     ActivityRequest, BuilderLeaderboardRequest, BuilderVolumeRequest, ClosedPositionsRequest,
     HoldersRequest, LiveVolumeRequest, OpenInterestRequest, PositionsRequest, TradedRequest,
@@ -41,7 +51,22 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
-use crate::{Result, ToQueryParams as _};
+use super::types::{ActivityType, Hash64, MarketFilter, TradeRole};
+use super::volume::{VolumeInterval, VolumePoint, volume_series};
+use crate::error::Error;
+use crate::metrics::MetricsObserver;
+use crate::middleware::Middleware;
+use crate::types::{Address, Decimal};
+use crate::{
+    ConnectionConfig, DATA_HOST_VAR, POLYGON, RequestContext, Result, ToQueryParams as _,
+    contract_config, host_from_env,
+};
+
+/// The endpoint used by [`Client::default`] and, unless overridden, [`Client::from_env`].
+const DEFAULT_HOST: &str = "https://data-api.polymarket.com";
+
+/// The largest `min_balance` accepted by the `/holders` endpoint.
+const MAX_MIN_BALANCE: i32 = 999_999;
 
 /// HTTP client for the Polymarket Data API.
 ///
@@ -66,13 +91,17 @@ use crate::{Result, ToQueryParams as _};
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    http: ReqwestClient,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    #[cfg(feature = "lenient")]
+    lenient: bool,
+    max_response_bytes: Option<usize>,
+    middleware: Middleware,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://data-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
@@ -87,26 +116,101 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::new_with_connection(host, ConnectionConfig::default())
+    }
+
+    /// Creates a new Data API client with a custom host URL whose HTTP connections use the
+    /// `connection` pool and keep-alive settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn new_with_connection(host: &str, connection: ConnectionConfig) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let http = connection
+            .apply(ReqwestClient::builder().default_headers(headers))
+            .build()?;
 
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            http,
+            metrics: None,
+            #[cfg(feature = "lenient")]
+            lenient: false,
+            max_response_bytes: None,
+            middleware: Middleware::default(),
         })
     }
 
+    /// Creates a new Data API client configured from the environment.
+    ///
+    /// Reads [`DATA_HOST_VAR`] (`POLYMARKET_DATA_HOST`) for the host, defaulting to
+    /// `https://data-api.polymarket.com` when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is set but is not a valid URL.
+    pub fn from_env() -> Result<Client> {
+        Self::new(&host_from_env(DATA_HOST_VAR, DEFAULT_HOST)?)
+    }
+
+    /// Registers a [`MetricsObserver`] that is notified of every request made by this client.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enables lenient deserialization of responses: optional fields that fail to deserialize
+    /// degrade to `None` or their default and are logged instead of failing the request. Required
+    /// fields and list items still fail it.
+    ///
+    /// Strict deserialization is the default.
+    #[cfg(feature = "lenient")]
+    #[must_use]
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Caps the size of a response body this client will buffer at `max_response_bytes`. Larger
+    /// responses fail with [`ResponseTooLarge`](crate::error::ResponseTooLarge) as soon as they
+    /// are known to exceed it, rather than being read in full.
+    ///
+    /// Responses are unlimited by default. Large listings are best read a page at a time with
+    /// `limit` and `offset`.
+    #[must_use]
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Registers [`Middleware`] whose interceptors and observers are applied to every request made
+    /// by this client.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Middleware) -> Self {
+        self.middleware = middleware;
+        self
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
         &self.host
     }
 
+    /// Returns a client whose user-specific calls all target `user`, sharing this client's
+    /// connection pool and configuration.
+    #[must_use]
+    pub fn for_user(&self, user: Address) -> ScopedClient {
+        ScopedClient::new(self.clone(), user)
+    }
+
     async fn get<Req: Serialize, Res: DeserializeOwned>(
         &self,
         path: &str,
@@ -114,10 +218,24 @@ impl Client {
     ) -> Result<Res> {
         let query = req.query_params(None);
         let request = self
-            .client
+            .http
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        self.send(request).await
+    }
+
+    /// Sends `request` with the HTTP client and settings of this client.
+    async fn send<Res: DeserializeOwned>(&self, request: Request) -> Result<Res> {
+        let context = RequestContext {
+            client: &self.http,
+            metrics: self.metrics.as_deref(),
+            #[cfg(feature = "lenient")]
+            lenient: self.lenient,
+            max_response_bytes: self.max_response_bytes,
+            middleware: &self.middleware,
+        };
+
+        crate::request(context, request, None).await
     }
 
     /// Performs a health check on the API.
@@ -139,7 +257,13 @@ impl Client {
     ///
     /// Returns an error if the request fails or the API returns an error response.
     pub async fn positions(&self, req: &PositionsRequest) -> Result<Vec<Position>> {
-        self.get("positions", req).await
+        let mut positions: Vec<Position> = self.get("positions", req).await?;
+
+        if let Some(keys) = req.sort_keys() {
+            sort_positions_by_keys(&mut positions, &keys);
+        }
+
+        Ok(positions)
     }
 
     /// Fetches trade history for a user or markets.
@@ -153,6 +277,332 @@ impl Client {
         self.get("trades", req).await
     }
 
+    /// Fetches the trades matching `req`, both maker and taker fills, each with the role the
+    /// trader played.
+    ///
+    /// Trades do not report their role, so it is derived from two requests: one with
+    /// [`taker_only`](TradesRequest::taker_only) set to false returning every fill, and one with
+    /// it set to true returning only taker fills. Fills of the first not found in the second are
+    /// maker fills. The first request uses `req`'s `limit` and `offset`; taker fills are paged
+    /// from the newest, as the endpoint returns them, until they reach back to the oldest trade of
+    /// that page, so that both cover the same trades.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the API returns an error response, or if covering
+    /// the page would take taker fills beyond the endpoint's pagination cap at an offset of
+    /// 10,000.
+    pub async fn trades_with_role(&self, req: &TradesRequest) -> Result<Vec<(Trade, TradeRole)>> {
+        // The maximum allowed for both `limit` and `offset`
+        const PAGE_SIZE: i32 = 10_000;
+
+        let all = self
+            .trades(&TradesRequest {
+                taker_only: Some(false),
+                ..req.clone()
+            })
+            .await?;
+        let Some(oldest) = all.iter().map(|trade| trade.timestamp).min() else {
+            return Ok(Vec::new());
+        };
+
+        let mut taker = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .trades(&TradesRequest {
+                    limit: Some(PAGE_SIZE),
+                    offset: Some(offset),
+                    taker_only: Some(true),
+                    ..req.clone()
+                })
+                .await?;
+            let is_full = i32::try_from(page.len()).is_ok_and(|len| len == PAGE_SIZE);
+            let is_covered = page.last().is_some_and(|trade| trade.timestamp < oldest);
+            taker.extend(page);
+
+            if !is_full || is_covered {
+                break;
+            }
+            if offset >= PAGE_SIZE {
+                return Err(Error::validation(format!(
+                    "Unable to derive trade roles: Taker fills back to timestamp {oldest} lie \
+                    beyond offset {PAGE_SIZE}"
+                )));
+            }
+            offset += PAGE_SIZE;
+        }
+
+        let key = |trade: &Trade| {
+            (
+                trade.transaction_hash.clone(),
+                trade.proxy_wallet,
+                trade.asset.clone(),
+                trade.side,
+                trade.size,
+                trade.price,
+            )
+        };
+        let mut taker_fills: HashMap<_, usize> = HashMap::new();
+        for trade in &taker {
+            *taker_fills.entry(key(trade)).or_default() += 1;
+        }
+
+        Ok(all
+            .into_iter()
+            .map(|trade| {
+                let role = match taker_fills.get_mut(&key(&trade)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        TradeRole::Taker
+                    }
+                    _ => TradeRole::Maker,
+                };
+                (trade, role)
+            })
+            .collect())
+    }
+
+    /// Builds a time series of the notional volume traded in the market identified by
+    /// `condition_id`, in buckets of width `interval`.
+    ///
+    /// The Data API has no historical volume endpoint, so the series is derived by summing the
+    /// market's taker trades (each fill counted once) with [`volume_series`]. The `/trades`
+    /// endpoint caps pagination at an offset of 10,000, so only the most recent 20,000 trades
+    /// are included; for very active markets the start of the series is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trades span too many buckets of `interval` (see
+    /// [`volume_series`]), or if a request fails or the API returns an error response.
+    pub async fn market_volume_series(
+        &self,
+        condition_id: &str,
+        interval: VolumeInterval,
+    ) -> Result<Vec<VolumePoint>> {
+        // The maximum allowed for both `limit` and `offset`
+        const PAGE_SIZE: i32 = 10_000;
+
+        let mut trades = Vec::new();
+        let mut offset = 0;
+        loop {
+            let request = TradesRequest {
+                filter: Some(MarketFilter::markets([condition_id.to_owned()])),
+                limit: Some(PAGE_SIZE),
+                offset: Some(offset),
+                taker_only: Some(true),
+                ..TradesRequest::default()
+            };
+            let page = self.trades(&request).await?;
+            let is_full = i32::try_from(page.len()).is_ok_and(|len| len == PAGE_SIZE);
+            trades.extend(page);
+
+            if !is_full || offset >= PAGE_SIZE {
+                break;
+            }
+            offset += PAGE_SIZE;
+        }
+
+        volume_series(&trades, interval)
+    }
+
+    /// Plans the redemption of every redeemable position held by `user`, as one
+    /// [`RedeemPositionsRequest`] per resolved condition ready to submit to the Conditional
+    /// Tokens contract on Polygon.
+    ///
+    /// Positions are fetched from `/positions` with `redeemable=true` and no size threshold, then
+    /// planned with [`plan_redemptions`] using USDC as collateral; see there for how the index
+    /// sets are derived and which positions are skipped. The endpoint caps pagination at an
+    /// offset of 10,000, so at most 10,500 positions are considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails, the API returns an error response, or a position
+    /// cannot be planned.
+    pub async fn redeemable_plan(&self, user: Address) -> Result<Vec<RedeemPositionsRequest>> {
+        let collateral = contract_config(POLYGON, false)
+            .ok_or(Error::missing_contract_config(POLYGON, false))?
+            .collateral;
+
+        let positions = self
+            .all_positions(PositionsRequest {
+                user,
+                filter: None,
+                size_threshold: Some(Decimal::ZERO),
+                redeemable: Some(true),
+                mergeable: None,
+                limit: None,
+                offset: None,
+                sort_by: None,
+                sort_direction: None,
+                then_sort_by: Vec::new(),
+                title: None,
+            })
+            .await?;
+
+        plan_redemptions(&positions, collateral)
+    }
+
+    /// Fetches `user`'s current positions and compares them with `previous`, an earlier snapshot
+    /// of the same user's positions, using [`diff_positions`].
+    ///
+    /// Positions are fetched from `/positions` with no size threshold, so a snapshot taken with
+    /// the endpoint's default threshold reports its dust positions as added. The current
+    /// positions are the [`PositionsDiff::added`] and [`PositionsDiff::changed`] ones plus the
+    /// unchanged part of `previous`. The endpoint caps pagination at an offset of 10,000, so at
+    /// most 10,500 positions are considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the API returns an error response.
+    pub async fn positions_changes(
+        &self,
+        user: Address,
+        previous: &[Position],
+    ) -> Result<PositionsDiff> {
+        let current = self
+            .all_positions(PositionsRequest {
+                user,
+                filter: None,
+                size_threshold: Some(Decimal::ZERO),
+                redeemable: None,
+                mergeable: None,
+                limit: None,
+                offset: None,
+                sort_by: None,
+                sort_direction: None,
+                then_sort_by: Vec::new(),
+                title: None,
+            })
+            .await?;
+
+        Ok(diff_positions(previous, &current))
+    }
+
+    /// Reports the profit and loss `user` realized in each market between `start` and `end`
+    /// (inclusive Unix timestamps, in seconds), as computed by [`market_pnl`].
+    ///
+    /// Trades, redemptions and rewards up to `end` are fetched from `/activity`, newest first,
+    /// so that positions opened before `start` have a cost. The endpoint caps pagination at an
+    /// offset of 10,000, so at most the 10,500 most recent of these activities are considered;
+    /// tokens bought before them count as having no recorded cost.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `end` is before `start`, a request fails, or the API returns an
+    /// error response.
+    pub async fn pnl_report(&self, user: Address, start: i64, end: i64) -> Result<Vec<MarketPnl>> {
+        // The maximum allowed for `limit`
+        const PAGE_SIZE: i32 = 500;
+        // The maximum allowed for `offset`
+        const MAX_OFFSET: i32 = 10_000;
+
+        if end < start {
+            return Err(Error::validation(format!(
+                "End {end} must not be before start {start}"
+            )));
+        }
+
+        let mut activities = Vec::new();
+        let mut offset = 0;
+        loop {
+            let request = ActivityRequest {
+                user,
+                filter: None,
+                activity_types: vec![
+                    ActivityType::Trade,
+                    ActivityType::Redeem,
+                    ActivityType::Reward,
+                ],
+                limit: Some(PAGE_SIZE),
+                offset: Some(offset),
+                start: None,
+                end: u64::try_from(end).ok(),
+                sort_by: None,
+                sort_direction: None,
+                side: None,
+            };
+            let page = self.activity(&request).await?;
+            let is_full = i32::try_from(page.len()).is_ok_and(|len| len == PAGE_SIZE);
+            activities.extend(page);
+
+            if !is_full || offset >= MAX_OFFSET {
+                break;
+            }
+            offset = (offset + PAGE_SIZE).min(MAX_OFFSET);
+        }
+
+        Ok(market_pnl(&activities, start, end))
+    }
+
+    /// Breaks the value of `user`'s open positions down by market, as `(condition ID, value)`
+    /// pairs ordered from the largest value to the smallest.
+    ///
+    /// Each market's value is the sum of [`Position::current_value`] over its outcomes, fetched
+    /// from `/positions` with no size threshold and restricted to `markets` unless it is empty.
+    /// The endpoint caps pagination at an offset of 10,000, so at most 10,500 positions are
+    /// considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the API returns an error response.
+    pub async fn value_breakdown(
+        &self,
+        user: Address,
+        markets: &[Hash64],
+    ) -> Result<Vec<(Hash64, Decimal)>> {
+        let positions = self
+            .all_positions(PositionsRequest {
+                user,
+                filter: (!markets.is_empty()).then(|| MarketFilter::markets(markets.to_vec())),
+                size_threshold: Some(Decimal::ZERO),
+                redeemable: None,
+                mergeable: None,
+                limit: None,
+                offset: None,
+                sort_by: None,
+                sort_direction: None,
+                then_sort_by: Vec::new(),
+                title: None,
+            })
+            .await?;
+
+        let mut values: BTreeMap<Hash64, Decimal> = BTreeMap::new();
+        for position in positions {
+            *values.entry(position.condition_id).or_default() += position.current_value;
+        }
+
+        let mut breakdown: Vec<_> = values.into_iter().collect();
+        breakdown.sort_by(|(_, a), (_, b)| b.cmp(a));
+        Ok(breakdown)
+    }
+
+    /// Fetches every page of `/positions` matching `request`, whose `limit` and `offset` are
+    /// overwritten to page through results until the endpoint's maximum offset.
+    async fn all_positions(&self, mut request: PositionsRequest) -> Result<Vec<Position>> {
+        // The maximum allowed for `limit`
+        const PAGE_SIZE: i32 = 500;
+        // The maximum allowed for `offset`
+        const MAX_OFFSET: i32 = 10_000;
+
+        let mut positions = Vec::new();
+        let mut offset = 0;
+        loop {
+            request.limit = Some(PAGE_SIZE);
+            request.offset = Some(offset);
+            let page = self.positions(&request).await?;
+            let is_full = i32::try_from(page.len()).is_ok_and(|len| len == PAGE_SIZE);
+            positions.extend(page);
+
+            if !is_full || offset >= MAX_OFFSET {
+                break;
+            }
+            offset = (offset + PAGE_SIZE).min(MAX_OFFSET);
+        }
+
+        Ok(positions)
+    }
+
     /// Fetches on-chain activity for a user.
     ///
     /// Returns various on-chain operations including trades, splits, merges,
@@ -176,6 +626,39 @@ impl Client {
         self.get("holders", req).await
     }
 
+    /// Fetches top token holders for specified markets, keeping only those holding at least
+    /// `min_amount` tokens.
+    ///
+    /// The server only filters on whole token amounts (`min_balance`), so it is asked for
+    /// holders of at least `min_amount` rounded down, and the exact `min_amount` is then applied
+    /// to the returned [`Holder::amount`](super::types::response::Holder::amount)s. A larger
+    /// `min_balance` already set on `req` is kept. Tokens left with no holders are kept, with an
+    /// empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn holders_with_min_amount(
+        &self,
+        req: &HoldersRequest,
+        min_amount: Decimal,
+    ) -> Result<Vec<MetaHolder>> {
+        let mut request = req.clone();
+        let floor = min_amount
+            .floor()
+            .clamp(Decimal::ZERO, Decimal::from(MAX_MIN_BALANCE))
+            .to_i32()
+            .unwrap_or_default();
+        request.min_balance = Some(request.min_balance.map_or(floor, |min| min.max(floor)));
+
+        let mut holders = self.holders(&request).await?;
+        for meta in &mut holders {
+            meta.holders.retain(|holder| holder.amount >= min_amount);
+        }
+
+        Ok(holders)
+    }
+
     /// Fetches the total value of a user's positions.
     ///
     /// Optionally filtered by specific markets.
@@ -189,7 +672,8 @@ impl Client {
 
     /// Fetches closed (historical) positions for a user.
     ///
-    /// These are positions that have been fully sold or redeemed.
+    /// These are positions that have been fully sold or redeemed. Positions outside the
+    /// request's `start`/`end` window are dropped from the returned page.
     ///
     /// # Errors
     ///
@@ -198,7 +682,17 @@ impl Client {
         &self,
         req: &ClosedPositionsRequest,
     ) -> Result<Vec<ClosedPosition>> {
-        self.get("closed-positions", req).await
+        let positions: Vec<ClosedPosition> = self.get("closed-positions", req).await?;
+
+        let mut positions: Vec<_> = positions
+            .into_iter()
+            .filter(|position| req.contains_timestamp(position.timestamp))
+            .collect();
+        if let Some(keys) = req.sort_keys() {
+            sort_closed_positions_by_keys(&mut positions, &keys);
+        }
+
+        Ok(positions)
     }
 
     /// Fetches trader leaderboard rankings.
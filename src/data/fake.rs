@@ -0,0 +1,123 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`FakeDataApi` reads better at use sites than `fake::DataApi`"
+)]
+
+//! In-memory fake of the Data API for tests.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+
+use super::api::DataApi;
+use super::types::request::{
+    ActivityRequest, ClosedPositionsRequest, PositionsRequest, TradesRequest, ValueRequest,
+};
+use super::types::response::{Activity, ClosedPosition, Position, Trade, Value};
+use crate::{Result, canned};
+
+/// A [`DataApi`] that answers every call with a canned response instead of making HTTP requests.
+///
+/// Each method returns a clone of the response programmed with the matching `with_*` method,
+/// whatever the request, and fails if none was programmed, or with an HTTP status error if it was
+/// made to fail with [`with_failure`](Self::with_failure).
+///
+/// ```
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// use polymarket_client_sdk::data::fake::FakeDataApi;
+/// use polymarket_client_sdk::data::{DataApi, types::request::TradesRequest};
+///
+/// let api = FakeDataApi::new().with_trades(Vec::new());
+///
+/// assert!(api.trades(&TradesRequest::default()).await?.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FakeDataApi {
+    positions: Option<Vec<Position>>,
+    trades: Option<Vec<Trade>>,
+    activity: Option<Vec<Activity>>,
+    value: Option<Vec<Value>>,
+    closed_positions: Option<Vec<ClosedPosition>>,
+    failures: HashMap<&'static str, StatusCode>,
+}
+
+impl FakeDataApi {
+    /// Creates a fake with no canned responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the response to [`DataApi::positions`].
+    #[must_use]
+    pub fn with_positions(mut self, positions: Vec<Position>) -> Self {
+        self.positions = Some(positions);
+        self
+    }
+
+    /// Sets the response to [`DataApi::trades`].
+    #[must_use]
+    pub fn with_trades(mut self, trades: Vec<Trade>) -> Self {
+        self.trades = Some(trades);
+        self
+    }
+
+    /// Sets the response to [`DataApi::activity`].
+    #[must_use]
+    pub fn with_activity(mut self, activity: Vec<Activity>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Sets the response to [`DataApi::value`].
+    #[must_use]
+    pub fn with_value(mut self, value: Vec<Value>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets the response to [`DataApi::closed_positions`].
+    #[must_use]
+    pub fn with_closed_positions(mut self, closed_positions: Vec<ClosedPosition>) -> Self {
+        self.closed_positions = Some(closed_positions);
+        self
+    }
+
+    /// Makes every call to the [`DataApi`] method named `method`, e.g. `"trades"`, fail with the
+    /// error the real client returns when the API answers with `status_code`.
+    #[must_use]
+    pub fn with_failure(mut self, method: &'static str, status_code: StatusCode) -> Self {
+        self.failures.insert(method, status_code);
+        self
+    }
+}
+
+#[async_trait]
+impl DataApi for FakeDataApi {
+    async fn positions(&self, _req: &PositionsRequest) -> Result<Vec<Position>> {
+        canned(&self.failures, self.positions.as_ref(), "positions")
+    }
+
+    async fn trades(&self, _req: &TradesRequest) -> Result<Vec<Trade>> {
+        canned(&self.failures, self.trades.as_ref(), "trades")
+    }
+
+    async fn activity(&self, _req: &ActivityRequest) -> Result<Vec<Activity>> {
+        canned(&self.failures, self.activity.as_ref(), "activity")
+    }
+
+    async fn value(&self, _req: &ValueRequest) -> Result<Vec<Value>> {
+        canned(&self.failures, self.value.as_ref(), "value")
+    }
+
+    async fn closed_positions(&self, _req: &ClosedPositionsRequest) -> Result<Vec<ClosedPosition>> {
+        canned(
+            &self.failures,
+            self.closed_positions.as_ref(),
+            "closed_positions",
+        )
+    }
+}
@@ -1,3 +1,12 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`DataApi` is re-exported next to the `Client` it abstracts over"
+)]
+#![expect(
+    clippy::same_name_method,
+    reason = "`DataApi` mirrors `Client` methods so either can be used interchangeably"
+)]
+
 //! Polymarket Data API client and types.
 //!
 //! This module provides a client for interacting with the Polymarket Data API,
@@ -60,7 +69,25 @@
 //!
 //! The default API endpoint is `https://data-api.polymarket.com`.
 
+pub mod api;
 pub mod client;
+#[cfg(feature = "test-util")]
+pub mod fake;
+pub mod pnl;
+pub mod poller;
+pub mod positions;
+pub mod scoped;
 pub mod types;
+pub mod volume;
 
+pub use api::DataApi;
 pub use client::Client;
+pub use pnl::{MarketPnl, market_pnl};
+pub use poller::{TradePoller, TradeWatermark};
+pub use positions::{
+    PositionChange, PositionsDiff, ReconstructedPosition, RedeemPositionsRequest, diff_positions,
+    net_redeem_payout, plan_redemptions, reconstruct_positions, sort_closed_positions,
+    sort_closed_positions_by_keys, sort_positions, sort_positions_by_keys,
+};
+pub use scoped::ScopedClient;
+pub use volume::{MAX_VOLUME_POINTS, VolumeInterval, VolumePoint, volume_series};
@@ -0,0 +1,138 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`MarketPnl` and `market_pnl` are re-exported from `data` where `pnl` is not in scope"
+)]
+
+//! Realized profit and loss per market, derived from activity history.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::types::response::Activity;
+use super::types::{ActivityType, Hash64, Side};
+use crate::types::Decimal;
+
+/// The profit and loss realized in one market over a window, as computed by [`market_pnl`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MarketPnl {
+    /// The market condition ID.
+    pub condition_id: Hash64,
+    /// Market title/question, if any of its activities carried one.
+    pub title: Option<String>,
+    /// Profit/loss realized by sells and redemptions, plus rewards, in USDC.
+    pub realized_pnl: Decimal,
+    /// Rewards received, in USDC. Already included in `realized_pnl`.
+    pub rewards: Decimal,
+    /// Number of trades.
+    pub trades_count: usize,
+}
+
+impl MarketPnl {
+    fn new(condition_id: Hash64) -> Self {
+        Self {
+            condition_id,
+            title: None,
+            realized_pnl: Decimal::ZERO,
+            rewards: Decimal::ZERO,
+            trades_count: 0,
+        }
+    }
+}
+
+/// Tokens of one asset held and what they cost.
+#[derive(Debug, Default)]
+struct Holding {
+    size: Decimal,
+    cost: Decimal,
+}
+
+/// Computes the profit and loss realized in each market by the activities timestamped within
+/// `start..=end` (Unix seconds), ordered by condition ID.
+///
+/// Costs follow the average cost method, as in
+/// [`reconstruct_positions`](super::reconstruct_positions): a buy adds its `usdc_size` to the
+/// cost of the asset bought, a sell realizes its proceeds minus the average cost of the tokens
+/// sold, and a redemption realizes its payout minus the cost of every token still held in the
+/// market, which it closes. Rewards are realized as received. Activities before `start` are
+/// replayed only to establish costs, so pass the history leading up to the window too; they are
+/// applied in ascending timestamp order whatever order they are given in.
+///
+/// Splits, merges and conversions exchange collateral for token sets at par and are ignored.
+/// Tokens obtained through them, or before the earliest activity given, have no recorded cost,
+/// so the proceeds of selling them beyond the tokens bought are left out rather than counted as
+/// pure profit. The activity feed reports no fees, so amounts are net of whatever fees were
+/// charged.
+#[must_use]
+pub fn market_pnl(activities: &[Activity], start: i64, end: i64) -> Vec<MarketPnl> {
+    let mut ordered: Vec<_> = activities
+        .iter()
+        .filter(|activity| activity.timestamp <= end)
+        .collect();
+    ordered.sort_by_key(|activity| activity.timestamp);
+
+    let mut holdings: HashMap<(&str, &str), Holding> = HashMap::new();
+    let mut markets: BTreeMap<&str, MarketPnl> = BTreeMap::new();
+
+    for activity in ordered {
+        let condition_id = activity.condition_id.as_str();
+        let realized = match activity.activity_type {
+            ActivityType::Trade => {
+                let (Some(asset), Some(side)) = (activity.asset.as_deref(), activity.side) else {
+                    continue;
+                };
+                let holding = holdings.entry((condition_id, asset)).or_default();
+                match side {
+                    Side::Buy => {
+                        holding.size += activity.size;
+                        holding.cost += activity.usdc_size;
+                        Decimal::ZERO
+                    }
+                    Side::Sell if activity.size > Decimal::ZERO => {
+                        let closed = activity.size.min(holding.size);
+                        let released = if holding.size.is_zero() {
+                            Decimal::ZERO
+                        } else {
+                            holding.cost * closed / holding.size
+                        };
+                        holding.size -= closed;
+                        holding.cost -= released;
+                        activity.usdc_size * closed / activity.size - released
+                    }
+                    Side::Sell => Decimal::ZERO,
+                }
+            }
+            ActivityType::Redeem => {
+                let released: Decimal = holdings
+                    .iter_mut()
+                    .filter(|((market, _), _)| *market == condition_id)
+                    .map(|(_, holding)| std::mem::take(holding).cost)
+                    .sum();
+                activity.usdc_size - released
+            }
+            ActivityType::Reward => activity.usdc_size,
+            ActivityType::Split | ActivityType::Merge | ActivityType::Conversion => continue,
+        };
+
+        if activity.timestamp < start {
+            continue;
+        }
+
+        let market = markets
+            .entry(condition_id)
+            .or_insert_with(|| MarketPnl::new(activity.condition_id.clone()));
+        market.realized_pnl += realized;
+        if activity.activity_type == ActivityType::Reward {
+            market.rewards += activity.usdc_size;
+        }
+        if activity.activity_type == ActivityType::Trade {
+            market.trades_count += 1;
+        }
+        if market.title.is_none() {
+            market.title.clone_from(&activity.title);
+        }
+    }
+
+    markets.into_values().collect()
+}
@@ -0,0 +1,157 @@
+//! Incremental polling of the `/trades` endpoint.
+
+#![allow(
+    clippy::module_name_repetitions,
+    reason = "Poller suffix is intentional for clarity"
+)]
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::Client;
+use super::types::request::TradesRequest;
+use super::types::response::Trade;
+use crate::Result;
+
+/// The most recent point in the trade feed that a [`TradePoller`] has observed.
+///
+/// Several trades can share a timestamp, so the watermark records the transaction hash of every
+/// trade seen at `timestamp` rather than a single one. It is serializable so it can be persisted
+/// and handed back to [`TradePoller::with_watermark`] after a restart.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TradeWatermark {
+    /// Unix timestamp of the newest trade(s) observed.
+    pub timestamp: i64,
+    /// Transaction hashes of the trades observed at `timestamp`.
+    pub transaction_hashes: BTreeSet<String>,
+}
+
+impl TradeWatermark {
+    #[must_use]
+    pub fn new(timestamp: i64, transaction_hashes: BTreeSet<String>) -> Self {
+        Self {
+            timestamp,
+            transaction_hashes,
+        }
+    }
+
+    /// Returns `true` if `trade` is newer than this watermark.
+    #[must_use]
+    pub fn is_new(&self, trade: &Trade) -> bool {
+        trade.timestamp > self.timestamp
+            || (trade.timestamp == self.timestamp
+                && !self.transaction_hashes.contains(&trade.transaction_hash))
+    }
+
+    fn advance(&mut self, trade: &Trade) {
+        if trade.timestamp > self.timestamp {
+            self.timestamp = trade.timestamp;
+            self.transaction_hashes.clear();
+        }
+
+        if trade.timestamp == self.timestamp {
+            self.transaction_hashes
+                .insert(trade.transaction_hash.clone());
+        }
+    }
+}
+
+/// Turns the stateless `/trades` endpoint into an incremental feed.
+///
+/// Each call to [`TradePoller::poll`] fetches the trades matching the configured
+/// [`TradesRequest`] and returns only those newer than the [`TradeWatermark`], then advances the
+/// watermark past them.
+///
+/// Each poll fetches a single page, so if more trades than the request's `limit` occur between
+/// two polls, the oldest of them are not returned. Poll often enough, or raise the limit, to
+/// avoid gaps.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_client_sdk::data::{Client, TradePoller, types::request::TradesRequest};
+/// use polymarket_client_sdk::types::address;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = TradesRequest::builder()
+///     .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+///     .build();
+/// let mut poller = TradePoller::new(Client::default(), request);
+///
+/// // The first poll returns the whole page
+/// let trades = poller.poll().await?;
+///
+/// // Later polls only return trades that happened since
+/// for trade in poller.poll().await? {
+///     println!("{} {} @ {}", trade.side, trade.size, trade.price);
+/// }
+///
+/// // Persist this to resume with `TradePoller::with_watermark`
+/// let watermark = poller.watermark().cloned();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TradePoller {
+    client: Client,
+    request: TradesRequest,
+    watermark: Option<TradeWatermark>,
+}
+
+impl TradePoller {
+    /// Creates a poller whose first [`TradePoller::poll`] returns every trade in the page.
+    #[must_use]
+    pub fn new(client: Client, request: TradesRequest) -> Self {
+        Self {
+            client,
+            request,
+            watermark: None,
+        }
+    }
+
+    /// Resumes polling from a previously persisted `watermark`.
+    #[must_use]
+    pub fn with_watermark(mut self, watermark: TradeWatermark) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// The current watermark, or [`None`] if no trade has been observed yet.
+    #[must_use]
+    pub fn watermark(&self) -> Option<&TradeWatermark> {
+        self.watermark.as_ref()
+    }
+
+    /// Fetches the latest trades and returns the ones not returned by a previous poll, in the
+    /// order given by the API (newest first).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response. The watermark
+    /// is left unchanged in that case.
+    pub async fn poll(&mut self) -> Result<Vec<Trade>> {
+        let trades = self.client.trades(&self.request).await?;
+
+        let new_trades: Vec<_> = match &self.watermark {
+            Some(watermark) => trades
+                .into_iter()
+                .filter(|trade| watermark.is_new(trade))
+                .collect(),
+            None => trades,
+        };
+
+        if !new_trades.is_empty() {
+            let watermark = self.watermark.get_or_insert_with(|| TradeWatermark {
+                timestamp: i64::MIN,
+                transaction_hashes: BTreeSet::new(),
+            });
+            for trade in &new_trades {
+                watermark.advance(trade);
+            }
+        }
+
+        Ok(new_trades)
+    }
+}
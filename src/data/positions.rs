@@ -0,0 +1,375 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "The helpers are re-exported from `data`, where `positions` in their names says what they act on"
+)]
+
+//! Offline reconstruction, diffing, sorting, and redemption planning of positions.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::str::FromStr as _;
+
+use alloy::primitives::{B256, U256};
+use chrono::NaiveDate;
+
+use super::types::response::{ClosedPosition, Position, Trade};
+use super::types::{ClosedPositionSortBy, Hash64, PositionSortBy, Side, SortDirection};
+use crate::Result;
+use crate::error::Error;
+use crate::types::{Address, Decimal};
+
+/// A position rebuilt from trades by [`reconstruct_positions`].
+///
+/// Field names mirror [`Position`](super::types::response::Position) so the two can be compared
+/// directly when reconciling against the `/positions` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReconstructedPosition {
+    /// The outcome token asset identifier.
+    pub asset: String,
+    /// The market condition ID.
+    pub condition_id: Hash64,
+    /// Net number of outcome tokens held (bought minus sold).
+    pub size: Decimal,
+    /// Size-weighted average entry price of the tokens currently held.
+    pub avg_price: Decimal,
+    /// Total number of tokens bought.
+    pub total_bought: Decimal,
+    /// Profit/loss realized by selling tokens above or below `avg_price`.
+    pub realized_pnl: Decimal,
+}
+
+impl ReconstructedPosition {
+    fn new(trade: &Trade) -> Self {
+        Self {
+            asset: trade.asset.clone(),
+            condition_id: trade.condition_id.clone(),
+            size: Decimal::ZERO,
+            avg_price: Decimal::ZERO,
+            total_bought: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+        }
+    }
+
+    fn apply(&mut self, side: Side, size: Decimal, price: Decimal) {
+        match side {
+            Side::Buy => {
+                let held = self.size.max(Decimal::ZERO);
+                self.size += size;
+                if self.size > Decimal::ZERO {
+                    // Only the tokens that end up held contribute to the entry price
+                    let added = self.size - held;
+                    self.avg_price = (self.avg_price * held + price * added) / self.size;
+                }
+                self.total_bought += size;
+            }
+            Side::Sell => {
+                let closed = size.min(self.size.max(Decimal::ZERO));
+                self.realized_pnl += (price - self.avg_price) * closed;
+                self.size -= size;
+                if self.size <= Decimal::ZERO {
+                    self.avg_price = Decimal::ZERO;
+                }
+            }
+        }
+    }
+}
+
+/// Rebuilds the current positions implied by `trades`, keyed by asset.
+///
+/// Trades are applied in ascending timestamp order, with trades sharing a timestamp applied in
+/// the order given, so the result does not depend on the newest-first order the `/trades`
+/// endpoint returns. Entry prices use the average cost method: buys update the size-weighted
+/// `avg_price`, while sells realize profit/loss against it and leave it unchanged.
+///
+/// Only trades are considered. Tokens obtained without trading (e.g. by splitting collateral)
+/// are missing from the history, so selling them drives `size` negative without affecting
+/// `avg_price` or `realized_pnl`.
+#[must_use]
+pub fn reconstruct_positions(trades: &[Trade]) -> HashMap<String, ReconstructedPosition> {
+    let mut ordered: Vec<_> = trades.iter().collect();
+    ordered.sort_by_key(|trade| trade.timestamp);
+
+    let mut positions = HashMap::new();
+    for trade in ordered {
+        positions
+            .entry(trade.asset.clone())
+            .or_insert_with(|| ReconstructedPosition::new(trade))
+            .apply(trade.side, trade.size, trade.price);
+    }
+
+    positions
+}
+
+/// How a [`Position`] held in both snapshots compared by [`diff_positions`] changed.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PositionChange {
+    /// The position as it currently stands.
+    pub position: Position,
+    /// Current size minus previous size.
+    pub size_delta: Decimal,
+    /// Current [`Position::current_value`] minus the previous one.
+    pub value_delta: Decimal,
+}
+
+/// The difference between two snapshots of a user's positions, as returned by
+/// [`diff_positions`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct PositionsDiff {
+    /// Positions only in the current snapshot.
+    pub added: Vec<Position>,
+    /// Positions only in the previous snapshot, as they last stood.
+    pub removed: Vec<Position>,
+    /// Positions in both snapshots whose size or value differ.
+    pub changed: Vec<PositionChange>,
+}
+
+impl PositionsDiff {
+    /// Returns `true` if the snapshots hold the same positions with the same sizes and values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two snapshots of a user's positions, matching them by asset.
+///
+/// A position present in both snapshots is reported as changed when its `size` or
+/// `current_value` differ, so price moves alone show up as changes with a zero `size_delta`.
+/// Every list is ordered by asset. Should a snapshot contain an asset more than once, its last
+/// occurrence is used.
+#[must_use]
+pub fn diff_positions(previous: &[Position], current: &[Position]) -> PositionsDiff {
+    let mut previous: BTreeMap<&str, &Position> = previous
+        .iter()
+        .map(|position| (position.asset.as_str(), position))
+        .collect();
+    let current: BTreeMap<&str, &Position> = current
+        .iter()
+        .map(|position| (position.asset.as_str(), position))
+        .collect();
+
+    let mut diff = PositionsDiff::default();
+    for (asset, position) in current {
+        let Some(before) = previous.remove(asset) else {
+            diff.added.push(position.clone());
+            continue;
+        };
+
+        let size_delta = position.size - before.size;
+        let value_delta = position.current_value - before.current_value;
+        if !size_delta.is_zero() || !value_delta.is_zero() {
+            diff.changed.push(PositionChange {
+                position: position.clone(),
+                size_delta,
+                value_delta,
+            });
+        }
+    }
+    diff.removed = previous.into_values().cloned().collect();
+
+    diff
+}
+
+/// Sorts already-fetched `positions` by the criterion the `/positions` endpoint's `sortBy`
+/// parameter describes, e.g. to re-sort cached results without querying again.
+///
+/// The sort is stable, so positions that compare equal keep their relative order. Titles are
+/// compared by Unicode code point. [`PositionSortBy::Resolving`] sorts by `end_date`; positions
+/// whose end date is missing or unparsable are placed last in either direction.
+pub fn sort_positions(positions: &mut [Position], by: PositionSortBy, direction: SortDirection) {
+    sort_positions_by_keys(positions, &[(by, direction)]);
+}
+
+/// Sorts already-fetched `positions` by each `(criterion, direction)` key in turn, so that later
+/// keys break ties left by earlier ones, e.g. by cash `PnL` and then by title.
+///
+/// Each key compares as in [`sort_positions`], and the sort is stable, so positions equal under
+/// every key keep their relative order.
+pub fn sort_positions_by_keys(
+    positions: &mut [Position],
+    keys: &[(PositionSortBy, SortDirection)],
+) {
+    positions.sort_by(|a, b| {
+        keys.iter()
+            .fold(Ordering::Equal, |ordering, &(by, direction)| {
+                ordering.then_with(|| compare_positions(a, b, by, direction))
+            })
+    });
+}
+
+/// Sorts already-fetched closed `positions` by the criterion the `/closed-positions` endpoint's
+/// `sortBy` parameter describes.
+///
+/// The sort is stable, so positions that compare equal keep their relative order. Titles are
+/// compared by Unicode code point.
+pub fn sort_closed_positions(
+    positions: &mut [ClosedPosition],
+    by: ClosedPositionSortBy,
+    direction: SortDirection,
+) {
+    sort_closed_positions_by_keys(positions, &[(by, direction)]);
+}
+
+/// Sorts already-fetched closed `positions` by each `(criterion, direction)` key in turn, so that
+/// later keys break ties left by earlier ones.
+///
+/// Each key compares as in [`sort_closed_positions`], and the sort is stable.
+pub fn sort_closed_positions_by_keys(
+    positions: &mut [ClosedPosition],
+    keys: &[(ClosedPositionSortBy, SortDirection)],
+) {
+    positions.sort_by(|a, b| {
+        keys.iter()
+            .fold(Ordering::Equal, |ordering, &(by, direction)| {
+                ordering.then_with(|| compare_closed_positions(a, b, by, direction))
+            })
+    });
+}
+
+fn compare_positions(
+    a: &Position,
+    b: &Position,
+    by: PositionSortBy,
+    direction: SortDirection,
+) -> Ordering {
+    match by {
+        PositionSortBy::Current => directed(a.current_value.cmp(&b.current_value), direction),
+        PositionSortBy::Initial => directed(a.initial_value.cmp(&b.initial_value), direction),
+        PositionSortBy::Tokens => directed(a.size.cmp(&b.size), direction),
+        PositionSortBy::CashPnl => directed(a.cash_pnl.cmp(&b.cash_pnl), direction),
+        PositionSortBy::PercentPnl => directed(a.percent_pnl.cmp(&b.percent_pnl), direction),
+        PositionSortBy::Title => directed(a.title.cmp(&b.title), direction),
+        PositionSortBy::Resolving => match (end_date(a), end_date(b)) {
+            (Some(a), Some(b)) => directed(a.cmp(&b), direction),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+        PositionSortBy::Price => directed(a.cur_price.cmp(&b.cur_price), direction),
+        PositionSortBy::AvgPrice => directed(a.avg_price.cmp(&b.avg_price), direction),
+    }
+}
+
+fn compare_closed_positions(
+    a: &ClosedPosition,
+    b: &ClosedPosition,
+    by: ClosedPositionSortBy,
+    direction: SortDirection,
+) -> Ordering {
+    let ordering = match by {
+        ClosedPositionSortBy::RealizedPnl => a.realized_pnl.cmp(&b.realized_pnl),
+        ClosedPositionSortBy::Title => a.title.cmp(&b.title),
+        ClosedPositionSortBy::Price => a.cur_price.cmp(&b.cur_price),
+        ClosedPositionSortBy::AvgPrice => a.avg_price.cmp(&b.avg_price),
+        ClosedPositionSortBy::Timestamp => a.timestamp.cmp(&b.timestamp),
+    };
+    directed(ordering, direction)
+}
+
+/// Parses the date part of `end_date`, which the API returns either as a date or a timestamp.
+fn end_date(position: &Position) -> Option<NaiveDate> {
+    let date = position.end_date.get(..10)?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+const fn directed(ordering: Ordering, direction: SortDirection) -> Ordering {
+    match direction {
+        SortDirection::Asc => ordering,
+        SortDirection::Desc => ordering.reverse(),
+    }
+}
+
+/// The arguments of a `redeemPositions` call on the Conditional Tokens contract, as planned by
+/// [`plan_redemptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RedeemPositionsRequest {
+    /// The ERC-20 collateral backing the positions.
+    pub collateral_token: Address,
+    /// The parent collection, zero for positions that are not nested in another condition.
+    pub parent_collection_id: B256,
+    /// The resolved condition.
+    pub condition_id: B256,
+    /// The index sets to redeem, one per outcome held: outcome `i` is the set `1 << i`.
+    pub index_sets: Vec<U256>,
+}
+
+/// Plans one [`RedeemPositionsRequest`] per condition for the redeemable `positions`, paying out
+/// in `collateral_token`.
+///
+/// Each request redeems every outcome held in its condition, so winning tokens are paid out and
+/// losing ones are burned. Requests are ordered by condition ID and their index sets ascend.
+/// Positions that are not redeemable are ignored, as are negative risk positions, which must be
+/// redeemed through the Neg Risk Adapter by amount rather than by index set.
+///
+/// # Errors
+///
+/// Returns an error if a position has a malformed condition ID or an outcome index outside the
+/// 256 outcomes a condition can have.
+pub fn plan_redemptions(
+    positions: &[Position],
+    collateral_token: Address,
+) -> Result<Vec<RedeemPositionsRequest>> {
+    let mut outcomes: BTreeMap<B256, BTreeSet<U256>> = BTreeMap::new();
+
+    for position in positions {
+        if !position.redeemable || position.negative_risk {
+            continue;
+        }
+
+        let condition_id = B256::from_str(&position.condition_id).map_err(|e| {
+            Error::validation(format!(
+                "Invalid condition ID {}: {e}",
+                position.condition_id
+            ))
+        })?;
+        let index_set = usize::try_from(position.outcome_index)
+            .ok()
+            .filter(|index| *index < 256)
+            .map(|index| U256::ONE << index)
+            .ok_or_else(|| {
+                Error::validation(format!(
+                    "Invalid outcome index {} for condition {}",
+                    position.outcome_index, position.condition_id
+                ))
+            })?;
+
+        outcomes.entry(condition_id).or_default().insert(index_set);
+    }
+
+    Ok(outcomes
+        .into_iter()
+        .map(|(condition_id, index_sets)| RedeemPositionsRequest {
+            collateral_token,
+            parent_collection_id: B256::ZERO,
+            condition_id,
+            index_sets: index_sets.into_iter().collect(),
+        })
+        .collect())
+}
+
+/// Returns the collateral redeeming `position` pays out, net of the fee charged on negative risk
+/// redemptions.
+///
+/// The gross payout is `size * cur_price`, which once the market resolves is `size` for a winning
+/// outcome and zero for a losing one. For a [`Position::negative_risk`] position it is then
+/// reduced to `gross * (1 - neg_risk_fee_bips / 10_000)`, with the fee clamped to `0..=10_000`
+/// basis points. The fee is ignored for other positions, and a missing fee is treated as zero.
+///
+/// The Gamma API reports the fee per event, as returned by `Event::neg_risk_redeem_fee_bips` with
+/// the `gamma` feature.
+#[must_use]
+pub fn net_redeem_payout(position: &Position, neg_risk_fee_bips: Option<i64>) -> Decimal {
+    let gross = position.size * position.cur_price;
+
+    match neg_risk_fee_bips {
+        Some(bips) if position.negative_risk => {
+            let fee = Decimal::from(bips.clamp(0, 10_000)) / Decimal::from(10_000);
+            gross * (Decimal::ONE - fee)
+        }
+        _ => gross,
+    }
+}
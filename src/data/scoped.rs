@@ -0,0 +1,146 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`ScopedClient` reads better than a bare `Client` next to the one it wraps"
+)]
+
+//! A [`Client`] bound to a single user.
+
+use super::Client;
+use super::types::request::{
+    ActivityRequest, PositionsRequest, TradedRequest, TradesRequest, ValueRequest,
+};
+use super::types::response::{Activity, Position, Trade, Traded, Value};
+use crate::Result;
+use crate::types::Address;
+
+/// A [`Client`] whose user-specific calls all target the same address, as returned by
+/// [`Client::for_user`].
+///
+/// Each method starts from the endpoint's request with only the user set, and passes it to a
+/// closure to set any filters, sorting or pagination before sending it; pass `|_| {}` to send
+/// the endpoint's defaults. The user is set back to the scoped address afterwards, so a call
+/// never targets another user.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_client_sdk::data::Client;
+/// use polymarket_client_sdk::types::address;
+///
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// let me = Client::default().for_user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"));
+///
+/// let positions = me.positions(|_| {}).await?;
+/// let recent = me
+///     .trades(|request| {
+///         request.limit = Some(10);
+///         request.taker_only = Some(false);
+///     })
+///     .await?;
+/// let value = me.value(|_| {}).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScopedClient {
+    client: Client,
+    user: Address,
+}
+
+impl ScopedClient {
+    pub(crate) fn new(client: Client, user: Address) -> Self {
+        Self { client, user }
+    }
+
+    /// Returns the address every call targets.
+    #[must_use]
+    pub const fn user(&self) -> Address {
+        self.user
+    }
+
+    /// Returns the underlying client.
+    #[must_use]
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Fetches the user's current positions, matching the request as set by `customize`. See
+    /// [`Client::positions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn positions<F>(&self, customize: F) -> Result<Vec<Position>>
+    where
+        F: FnOnce(&mut PositionsRequest),
+    {
+        let mut request = PositionsRequest::builder().user(self.user).build();
+        customize(&mut request);
+        request.user = self.user;
+
+        self.client.positions(&request).await
+    }
+
+    /// Fetches the user's trades, matching the request as set by `customize`. See
+    /// [`Client::trades`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn trades<F>(&self, customize: F) -> Result<Vec<Trade>>
+    where
+        F: FnOnce(&mut TradesRequest),
+    {
+        let mut request = TradesRequest::builder().user(self.user).build();
+        customize(&mut request);
+        request.user = Some(self.user);
+
+        self.client.trades(&request).await
+    }
+
+    /// Fetches the user's on-chain activity, matching the request as set by `customize`. See
+    /// [`Client::activity`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn activity<F>(&self, customize: F) -> Result<Vec<Activity>>
+    where
+        F: FnOnce(&mut ActivityRequest),
+    {
+        let mut request = ActivityRequest::builder().user(self.user).build();
+        customize(&mut request);
+        request.user = self.user;
+
+        self.client.activity(&request).await
+    }
+
+    /// Fetches the total value of the user's positions, matching the request as set by
+    /// `customize`. See [`Client::value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn value<F>(&self, customize: F) -> Result<Vec<Value>>
+    where
+        F: FnOnce(&mut ValueRequest),
+    {
+        let mut request = ValueRequest::builder().user(self.user).build();
+        customize(&mut request);
+        request.user = self.user;
+
+        self.client.value(&request).await
+    }
+
+    /// Fetches the number of unique markets the user has traded. See [`Client::traded`].
+    ///
+    /// The endpoint takes no parameters besides the user, so there is nothing to customize.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn traded(&self) -> Result<Traded> {
+        let request = TradedRequest::builder().user(self.user).build();
+        self.client.traded(&request).await
+    }
+}
@@ -10,6 +10,8 @@ pub mod request;
 pub mod response;
 
 /// Type alias for 64-character hex hashes (condition IDs, market identifiers).
+///
+/// Being a plain `String`, it serializes as a JSON string and can be used as a map key.
 pub type Hash64 = String;
 
 /// Type alias for market title filter strings.
@@ -18,7 +20,9 @@ pub type Title = String;
 /// The side of a trade (buy or sell).
 ///
 /// Used to indicate whether a trade was a purchase or sale of outcome tokens.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
 #[serde(rename_all = "UPPERCASE")]
 #[strum(serialize_all = "UPPERCASE")]
 #[non_exhaustive]
@@ -29,6 +33,18 @@ pub enum Side {
     Sell,
 }
 
+/// The role a trader played in a trade, as derived by
+/// [`Client::trades_with_role`](crate::data::Client::trades_with_role).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum TradeRole {
+    /// The trader's resting order was filled.
+    Maker,
+    /// The trader's order filled against resting orders.
+    Taker,
+}
+
 /// The type of on-chain activity for a user.
 ///
 /// Activities represent various operations that users can perform on the Polymarket protocol.
@@ -286,6 +302,20 @@ impl MarketFilter {
         Self::Markets(ids.into_iter().collect())
     }
 
+    /// Creates a filter for specific markets by their condition IDs, validating that each ID is
+    /// a well-formed [`Hash64`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Hash64Error`] for the first ID that is not a valid [`Hash64`].
+    pub fn try_markets<I, S>(ids: I) -> Result<Self, Hash64Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        validate_hash64s(ids).map(Self::Markets)
+    }
+
     /// Creates a filter for all markets within the specified events.
     #[must_use]
     pub fn event_ids<I: IntoIterator<Item = String>>(ids: I) -> Self {
@@ -293,6 +323,68 @@ impl MarketFilter {
     }
 }
 
+/// Validates that `value` is a `0x`-prefixed, 64-character hex hash such as a condition ID.
+///
+/// # Errors
+///
+/// Returns [`Hash64Error`] if `value` is not a valid [`Hash64`].
+pub fn validate_hash64<S: AsRef<str>>(value: S) -> Result<Hash64, Hash64Error> {
+    let value = value.as_ref();
+    let is_valid = value
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()));
+
+    if is_valid {
+        Ok(value.to_owned())
+    } else {
+        Err(Hash64Error::new(value))
+    }
+}
+
+/// Validates every element of `values` with [`validate_hash64`], e.g. to build the `markets` of a
+/// [`HoldersRequest`](request::HoldersRequest) from string condition IDs.
+///
+/// # Errors
+///
+/// Returns [`Hash64Error`] for the first element that is not a valid [`Hash64`].
+pub fn validate_hash64s<I, S>(values: I) -> Result<Vec<Hash64>, Hash64Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    values.into_iter().map(validate_hash64).collect()
+}
+
+/// Error type for values that are not a valid [`Hash64`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Hash64Error {
+    /// The invalid value.
+    pub value: String,
+}
+
+impl Hash64Error {
+    /// Creates a new `Hash64Error`.
+    #[must_use]
+    pub fn new<S: Into<String>>(value: S) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for Hash64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a 0x-prefixed 64-character hex hash (got {:?})",
+            self.value
+        )
+    }
+}
+
+impl StdError for Hash64Error {}
+
 /// Error type for bounded integer values that are out of range.
 #[derive(Debug)]
 #[non_exhaustive]
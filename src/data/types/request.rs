@@ -53,8 +53,12 @@ fn validate_bound(
 /// - `offset`: Pagination offset (0-10000, default: 0).
 /// - `sort_by`: Sort criteria (default: TOKENS).
 /// - `sort_direction`: Sort order (default: DESC).
+/// - `then_sort_by`: Secondary sort keys, applied client-side to break ties.
 /// - `title`: Filter by market title substring.
 ///
+/// The endpoint sorts by a single key, so `then_sort_by` only reorders positions the server
+/// considers equal under `sort_by`, within each fetched page.
+///
 /// # Example
 ///
 /// ```
@@ -96,11 +100,31 @@ pub struct PositionsRequest {
     /// Sort direction (default: DESC).
     #[serde(rename = "sortDirection")]
     pub sort_direction: Option<SortDirection>,
+    /// Secondary `(criterion, direction)` sort keys, in priority order. Applied client-side.
+    #[serde(skip)]
+    #[builder(default)]
+    pub then_sort_by: Vec<(PositionSortBy, SortDirection)>,
     /// Filter by market title substring (max 100 chars).
     #[builder(into)]
     pub title: Option<Title>,
 }
 
+impl PositionsRequest {
+    /// Returns the full list of sort keys, starting with the server-side `sort_by`, or `None`
+    /// when no secondary keys were requested.
+    pub(crate) fn sort_keys(&self) -> Option<Vec<(PositionSortBy, SortDirection)>> {
+        (!self.then_sort_by.is_empty()).then(|| {
+            let primary = (
+                self.sort_by.unwrap_or_default(),
+                self.sort_direction.unwrap_or_default(),
+            );
+            std::iter::once(primary)
+                .chain(self.then_sort_by.iter().copied())
+                .collect()
+        })
+    }
+}
+
 #[expect(clippy::ref_option, reason = "Need an explicit reference for serde")]
 fn filter_is_none_or_empty(f: &Option<MarketFilter>) -> bool {
     match f {
@@ -154,6 +178,10 @@ pub struct TradesRequest {
     #[builder(with = |v: i32| -> Result<_, BoundedIntError> { validate_bound(v, 0, 10000, "offset") })]
     pub offset: Option<i32>,
     /// Only return taker trades (default: true).
+    ///
+    /// When false, maker fills are returned too, but trades do not say which role the trader
+    /// played, so a fill may appear once per side. Use
+    /// [`Client::trades_with_role`](crate::data::Client::trades_with_role) to tell them apart.
     #[serde(rename = "takerOnly")]
     pub taker_only: Option<bool>,
     /// Filter by minimum trade size. Must provide both type and amount.
@@ -366,6 +394,16 @@ pub struct LiveVolumeRequest {
 /// - `offset`: Pagination offset (0-100000, default: 0).
 /// - `sort_by`: Sort criteria (default: REALIZEDPNL).
 /// - `sort_direction`: Sort order (default: DESC).
+/// - `then_sort_by`: Secondary sort keys, applied client-side to break ties.
+/// - `start`: Start timestamp filter (Unix timestamp, inclusive).
+/// - `end`: End timestamp filter (Unix timestamp, inclusive).
+///
+/// As with [`PositionsRequest`], `then_sort_by` only reorders ties within each fetched page.
+///
+/// The endpoint has no server-side time filter, so `start` and `end` are applied client-side
+/// to each page after it is fetched. A page may therefore hold fewer than `limit` positions;
+/// advance `offset` by `limit` rather than by the number of positions returned, or positions
+/// near the window's edges can be skipped.
 ///
 /// # Example
 ///
@@ -403,6 +441,45 @@ pub struct ClosedPositionsRequest {
     /// Sort direction (default: DESC).
     #[serde(rename = "sortDirection")]
     pub sort_direction: Option<SortDirection>,
+    /// Secondary `(criterion, direction)` sort keys, in priority order. Applied client-side.
+    #[serde(skip)]
+    #[builder(default)]
+    pub then_sort_by: Vec<(ClosedPositionSortBy, SortDirection)>,
+    /// Only keep positions closed at or after this Unix timestamp. Applied client-side.
+    #[serde(skip)]
+    pub start: Option<u64>,
+    /// Only keep positions closed at or before this Unix timestamp. Applied client-side.
+    #[serde(skip)]
+    pub end: Option<u64>,
+}
+
+impl ClosedPositionsRequest {
+    /// Returns whether a position closed at `timestamp` falls within `start..=end`. Every
+    /// timestamp does when neither bound is set, including malformed negative ones.
+    pub(crate) fn contains_timestamp(&self, timestamp: i64) -> bool {
+        if self.start.is_none() && self.end.is_none() {
+            return true;
+        }
+
+        u64::try_from(timestamp).is_ok_and(|timestamp| {
+            self.start.is_none_or(|start| timestamp >= start)
+                && self.end.is_none_or(|end| timestamp <= end)
+        })
+    }
+
+    /// Returns the full list of sort keys, starting with the server-side `sort_by`, or `None`
+    /// when no secondary keys were requested.
+    pub(crate) fn sort_keys(&self) -> Option<Vec<(ClosedPositionSortBy, SortDirection)>> {
+        (!self.then_sort_by.is_empty()).then(|| {
+            let primary = (
+                self.sort_by.unwrap_or_default(),
+                self.sort_direction.unwrap_or_default(),
+            );
+            std::iter::once(primary)
+                .chain(self.then_sort_by.iter().copied())
+                .collect()
+        })
+    }
 }
 
 /// Request parameters for the `/v1/builders/leaderboard` endpoint.
@@ -6,6 +6,8 @@ use serde::{Deserialize, Deserializer};
 use serde_with::{DefaultOnNull, serde_as};
 
 use super::{ActivityType, Hash64, Side};
+use crate::clob::order_builder::to_base_units;
+use crate::serde_helpers::StringFromAny;
 use crate::types::{Address, Decimal};
 
 /// Deserializes an optional Side, treating empty strings as None.
@@ -60,6 +62,9 @@ pub struct Position {
     /// The market condition ID (unique market identifier).
     pub condition_id: Hash64,
     /// Number of outcome tokens held.
+    ///
+    /// Parsed straight into a [`Decimal`] without going through an `f64` amount, so it can be
+    /// converted to the on-chain balance exactly with [`Position::on_chain_size`].
     pub size: Decimal,
     /// Average entry price for the position.
     pub avg_price: Decimal,
@@ -107,6 +112,22 @@ pub struct Position {
     pub negative_risk: bool,
 }
 
+impl Position {
+    /// Returns [`Position::size`] in the base units of a token with `decimals` decimal places.
+    ///
+    /// Outcome tokens have 6 decimals, so `on_chain_size(6)` is the exact balance to sell when
+    /// closing the whole position, free of the float rounding that leads to `not enough balance`
+    /// rejections. Digits beyond `decimals` are truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `size` is negative, which the API never returns, or does not fit in a
+    /// `u128` once scaled.
+    pub fn on_chain_size(&self, decimals: u32) -> crate::Result<u128> {
+        to_base_units(self.size, decimals)
+    }
+}
+
 /// A user's closed (historical) position in a prediction market.
 ///
 /// Returned by the `/closed-positions` endpoint. Represents positions that
@@ -155,6 +176,9 @@ pub struct ClosedPosition {
 ///
 /// Returned by the `/trades` endpoint. Represents an executed order where
 /// outcome tokens were bought or sold.
+///
+/// The API does not report whether the trader was the maker or the taker; see
+/// [`Client::trades_with_role`](crate::data::Client::trades_with_role).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -284,6 +308,20 @@ pub struct Holder {
     pub verified: Option<bool>,
 }
 
+impl Holder {
+    /// Returns [`Holder::amount`] in the base units of a token with `decimals` decimal places,
+    /// e.g. `on_chain_amount(6)` to compare with the holder's ERC-1155 balance of the outcome
+    /// token. Digits beyond `decimals` are truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is negative, which the API never returns, or does not fit in
+    /// a `u128` once scaled.
+    pub fn on_chain_amount(&self, decimals: u32) -> crate::Result<u128> {
+        to_base_units(self.amount, decimals)
+    }
+}
+
 /// Container for holders grouped by token.
 ///
 /// Returned by the `/holders` endpoint. Groups holders by outcome token.
@@ -365,11 +403,13 @@ pub struct LiveVolume {
 ///
 /// Returned by the `/v1/builders/leaderboard` endpoint. Builders are third-party
 /// applications that integrate with Polymarket.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct BuilderLeaderboardEntry {
     /// Rank position in the leaderboard.
+    #[serde_as(deserialize_as = "StringFromAny")]
     pub rank: String,
     /// Builder name or identifier.
     pub builder: String,
@@ -383,10 +423,19 @@ pub struct BuilderLeaderboardEntry {
     pub builder_logo: Option<String>,
 }
 
+impl BuilderLeaderboardEntry {
+    /// Returns [`rank`](Self::rank) as a number, e.g. for sorting, or `None` if it is not one.
+    #[must_use]
+    pub fn rank_num(&self) -> Option<u32> {
+        self.rank.trim().parse().ok()
+    }
+}
+
 /// A builder's daily volume data point.
 ///
 /// Returned by the `/v1/builders/volume` endpoint. Each entry represents
 /// a single day's volume and activity for a builder.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -404,18 +453,29 @@ pub struct BuilderVolumeEntry {
     /// Number of active users for this builder on this date.
     pub active_users: i32,
     /// Rank position on this date.
+    #[serde_as(deserialize_as = "StringFromAny")]
     pub rank: String,
 }
 
+impl BuilderVolumeEntry {
+    /// Returns [`rank`](Self::rank) as a number, e.g. for sorting, or `None` if it is not one.
+    #[must_use]
+    pub fn rank_num(&self) -> Option<u32> {
+        self.rank.trim().parse().ok()
+    }
+}
+
 /// A trader's entry in the leaderboard.
 ///
 /// Returned by the `/v1/leaderboard` endpoint. Shows trader rankings
 /// by profit/loss or volume.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct TraderLeaderboardEntry {
     /// Rank position in the leaderboard.
+    #[serde_as(deserialize_as = "StringFromAny")]
     pub rank: String,
     /// The trader's proxy wallet address.
     pub proxy_wallet: Address,
@@ -432,3 +492,11 @@ pub struct TraderLeaderboardEntry {
     /// Whether the trader has a verified badge.
     pub verified_badge: Option<bool>,
 }
+
+impl TraderLeaderboardEntry {
+    /// Returns [`rank`](Self::rank) as a number, e.g. for sorting, or `None` if it is not one.
+    #[must_use]
+    pub fn rank_num(&self) -> Option<u32> {
+        self.rank.trim().parse().ok()
+    }
+}
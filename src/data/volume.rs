@@ -0,0 +1,103 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "The series types are re-exported from `data`, where `volume` is not in scope"
+)]
+
+//! Traded volume over time, derived from trade history.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::response::Trade;
+use crate::Result;
+use crate::error::Error;
+use crate::types::Decimal;
+
+/// The most buckets [`volume_series`] returns, to bound the memory used to fill gaps.
+pub const MAX_VOLUME_POINTS: usize = 100_000;
+
+/// The width of the buckets of a series built by [`volume_series`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VolumeInterval {
+    /// 1 minute
+    OneMinute,
+    /// 1 hour
+    OneHour,
+    /// 6 hours
+    SixHours,
+    /// 1 day
+    OneDay,
+    /// 1 week
+    OneWeek,
+}
+
+impl VolumeInterval {
+    /// Returns the width of the interval in seconds.
+    #[must_use]
+    pub const fn seconds(self) -> i64 {
+        match self {
+            Self::OneMinute => 60,
+            Self::OneHour => 60 * 60,
+            Self::SixHours => 6 * 60 * 60,
+            Self::OneDay => 24 * 60 * 60,
+            Self::OneWeek => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// The volume traded within one bucket of a series built by [`volume_series`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct VolumePoint {
+    /// Unix timestamp (in seconds) at which the bucket starts.
+    pub t: i64,
+    /// Notional volume (size × price, in USDC) traded within the bucket.
+    pub volume: Decimal,
+}
+
+/// Sums the notional volume of `trades` into consecutive buckets of width `interval`.
+///
+/// Buckets are aligned to multiples of `interval` since the Unix epoch and returned in ascending
+/// order, from the bucket of the oldest trade to that of the newest. Buckets without trades are
+/// included with zero volume, so the series has no gaps.
+///
+/// Each trade contributes `size × price`. Pass each fill once (e.g. only taker trades, as the
+/// `/trades` endpoint returns by default) to avoid counting both sides of a match.
+///
+/// # Errors
+///
+/// Returns an error if the trades span more than [`MAX_VOLUME_POINTS`] buckets, in which case a
+/// wider `interval` should be used.
+pub fn volume_series(trades: &[Trade], interval: VolumeInterval) -> Result<Vec<VolumePoint>> {
+    let width = interval.seconds();
+
+    let mut volumes = BTreeMap::new();
+    for trade in trades {
+        let start = trade.timestamp.div_euclid(width) * width;
+        *volumes.entry(start).or_insert(Decimal::ZERO) += trade.size * trade.price;
+    }
+
+    let (Some(&first), Some(&last)) = (volumes.keys().next(), volumes.keys().next_back()) else {
+        return Ok(Vec::new());
+    };
+
+    let buckets = (i128::from(last) - i128::from(first)) / i128::from(width) + 1;
+    if !usize::try_from(buckets).is_ok_and(|buckets| buckets <= MAX_VOLUME_POINTS) {
+        return Err(Error::validation(format!(
+            "Trades span {buckets} buckets of {interval:?}, more than the maximum of \
+             {MAX_VOLUME_POINTS}"
+        )));
+    }
+
+    let series = std::iter::successors(Some(first), |t| t.checked_add(width))
+        .take_while(|t| *t <= last)
+        .map(|t| VolumePoint {
+            t,
+            volume: volumes.get(&t).copied().unwrap_or(Decimal::ZERO),
+        })
+        .collect();
+
+    Ok(series)
+}
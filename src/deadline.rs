@@ -0,0 +1,112 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`with_deadline` and `WithDeadline` read better at use sites than `deadline::with`"
+)]
+
+//! Deadlines spanning several SDK calls.
+//!
+//! Wrap an operation made of any number of SDK calls in [`with_deadline`] to bound the whole of
+//! it: every HTTP request made while the wrapped future runs is given at most the time remaining
+//! until the deadline, and fails with [`Kind::Timeout`](crate::error::Kind::Timeout) without
+//! being sent once the deadline has passed, or if its response has not been read in full by then.
+//!
+//! ```no_run
+//! use std::time::{Duration, Instant};
+//!
+//! use polymarket_client_sdk::clob::Client;
+//! use polymarket_client_sdk::clob::types::request::MidpointRequest;
+//! use polymarket_client_sdk::deadline::with_deadline;
+//!
+//! # async fn example() -> polymarket_client_sdk::Result<()> {
+//! let client = Client::default();
+//! let yes = MidpointRequest::builder().token_id("1234").build();
+//! let no = MidpointRequest::builder().token_id("5678").build();
+//!
+//! let (yes, no) = with_deadline(Instant::now() + Duration::from_millis(500), async {
+//!     let yes = client.midpoint(&yes).await?;
+//!     let no = client.midpoint(&no).await?;
+//!     Ok::<_, polymarket_client_sdk::error::Error>((yes.mid, no.mid))
+//! })
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::Cell;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+thread_local! {
+    /// The deadline of the [`WithDeadline`] future currently being polled on this thread, if any.
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Runs `future` with `deadline` applied to every SDK request it makes.
+///
+/// Requests only see the deadline when they run as part of `future` itself, not when spawned
+/// onto other tasks. Nested deadlines combine, the earliest one winning. A `tokio` deadline can
+/// be passed by converting it with `tokio::time::Instant::into_std`.
+pub fn with_deadline<F: Future>(deadline: Instant, future: F) -> WithDeadline<F> {
+    WithDeadline {
+        deadline,
+        future: Box::pin(future),
+    }
+}
+
+/// Future returned by [`with_deadline`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct WithDeadline<F> {
+    deadline: Instant,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithDeadline<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let deadline = current().map_or(self.deadline, |outer| outer.min(self.deadline));
+        let _restore = Restore(DEADLINE.replace(Some(deadline)));
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the enclosing deadline once the inner future has been polled, even if it panics.
+struct Restore(Option<Instant>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        DEADLINE.set(self.0);
+    }
+}
+
+/// Returns the deadline of the innermost [`with_deadline`] being polled on this thread.
+pub(crate) fn current() -> Option<Instant> {
+    DEADLINE.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nested_deadlines_should_keep_the_earliest() {
+        let outer = Instant::now() + Duration::from_secs(10);
+        let inner = Instant::now() + Duration::from_secs(1);
+        let later = Instant::now() + Duration::from_secs(60);
+
+        let seen = with_deadline(outer, async {
+            let nested = with_deadline(inner, async { current() }).await;
+            let widened = with_deadline(later, async { current() }).await;
+            (nested, widened, current())
+        })
+        .await;
+
+        assert_eq!(seen, (Some(inner), Some(outer), Some(outer)));
+        assert_eq!(current(), None);
+    }
+}
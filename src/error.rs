@@ -1,12 +1,15 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
-use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
+use alloy::primitives::{B256, ChainId};
 use hmac::digest::InvalidLength;
 use reqwest::{Method, StatusCode, header};
 
+use crate::auth::Credentials;
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
@@ -22,6 +25,14 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Error related to a request running past its deadline, see [`crate::deadline`]
+    Timeout,
+    /// Error related to a response body exceeding the configured size limit
+    ResponseTooLarge,
+    /// Error related to a response carrying fields the SDK does not model, see `strict`
+    UnknownFields,
+    /// Error related to cancelling the orders of a market that has none open
+    NoOpenOrders,
 }
 
 #[derive(Debug)]
@@ -70,13 +81,14 @@ impl Error {
         path: String,
         message: S,
     ) -> Self {
-        Status {
-            status_code,
-            method,
-            path,
-            message: message.into(),
-        }
-        .into()
+        Status::new(status_code, method, path, message.into(), None).into()
+    }
+
+    /// Returns the semantic classification of a non-successful HTTP response, if this error was
+    /// caused by one and its status code maps onto an [`ApiError`] variant.
+    #[must_use]
+    pub fn as_api_error(&self) -> Option<&ApiError> {
+        self.downcast_ref::<Status>()?.api_error.as_ref()
     }
 
     #[must_use]
@@ -109,6 +121,26 @@ pub struct Status {
     pub method: Method,
     pub path: String,
     pub message: String,
+    /// Semantic classification of `status_code` and `message`, see [`Error::as_api_error`]
+    pub api_error: Option<ApiError>,
+}
+
+impl Status {
+    pub(crate) fn new(
+        status_code: StatusCode,
+        method: Method,
+        path: String,
+        message: String,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        Self {
+            api_error: ApiError::classify(status_code, &message, retry_after),
+            status_code,
+            method,
+            path,
+            message,
+        }
+    }
 }
 
 impl fmt::Display for Status {
@@ -123,6 +155,75 @@ impl fmt::Display for Status {
 
 impl StdError for Status {}
 
+/// Semantic classification of a non-successful HTTP response.
+///
+/// Derived from the status code and, where useful, the `error` field of the response body.
+/// Obtained through [`Error::as_api_error`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`ApiError` reads better at use sites than `error::Api`"
+)]
+pub enum ApiError {
+    /// The request was rejected as malformed or invalid (`400` or `422`)
+    Validation(String),
+    /// Missing or invalid credentials (`401`)
+    Unauthorized,
+    /// The credentials are not permitted to perform this request (`403`)
+    Forbidden,
+    /// The requested resource does not exist (`404`)
+    NotFound,
+    /// Too many requests (`429`). `retry_after` is taken from the `Retry-After` header when
+    /// present.
+    RateLimited { retry_after: Option<Duration> },
+    /// The server failed to process the request (`5xx`)
+    Server(String),
+}
+
+impl ApiError {
+    fn classify(
+        status_code: StatusCode,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> Option<Self> {
+        let message = || {
+            serde_json::from_str::<serde_json::Value>(body)
+                .ok()
+                .and_then(|value| value.get("error")?.as_str().map(str::to_owned))
+                .unwrap_or_else(|| body.to_owned())
+        };
+
+        match status_code {
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+                Some(Self::Validation(message()))
+            }
+            StatusCode::UNAUTHORIZED => Some(Self::Unauthorized),
+            StatusCode::FORBIDDEN => Some(Self::Forbidden),
+            StatusCode::NOT_FOUND => Some(Self::NotFound),
+            StatusCode::TOO_MANY_REQUESTS => Some(Self::RateLimited { retry_after }),
+            code if code.is_server_error() => Some(Self::Server(message())),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Validation(message) => write!(f, "validation failed: {message}"),
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::Forbidden => write!(f, "forbidden"),
+            Self::NotFound => write!(f, "not found"),
+            Self::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {retry_after:?}"),
+            Self::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            Self::Server(message) => write!(f, "server error: {message}"),
+        }
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct Validation {
@@ -177,6 +278,204 @@ impl From<MissingContractConfig> for Error {
     }
 }
 
+/// Error indicating that a request was not sent, or did not complete, before the deadline set with
+/// [`with_deadline`](crate::deadline::with_deadline).
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct DeadlineExceeded {
+    pub method: Method,
+    pub path: String,
+}
+
+impl fmt::Display for DeadlineExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "deadline exceeded making {} call to {}",
+            self.method, self.path
+        )
+    }
+}
+
+impl StdError for DeadlineExceeded {}
+
+impl From<DeadlineExceeded> for Error {
+    fn from(err: DeadlineExceeded) -> Self {
+        Error::with_source(Kind::Timeout, err)
+    }
+}
+
+/// Error indicating that a response body was larger than the client's configured
+/// `max_response_bytes`, and was abandoned instead of being buffered.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ResponseTooLarge {
+    pub method: Method,
+    pub path: String,
+    /// The limit that was exceeded, in bytes
+    pub limit: usize,
+}
+
+impl fmt::Display for ResponseTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "response to {} call to {} exceeded {} bytes",
+            self.method, self.path, self.limit
+        )
+    }
+}
+
+impl StdError for ResponseTooLarge {}
+
+impl From<ResponseTooLarge> for Error {
+    fn from(err: ResponseTooLarge) -> Self {
+        Error::with_source(Kind::ResponseTooLarge, err)
+    }
+}
+
+/// Error indicating that a response deserialized under strict mode carried fields its type does
+/// not model, which usually means the API changed.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct UnknownFields {
+    pub method: Method,
+    pub path: String,
+    /// Name of the type the response was deserialized into
+    pub type_name: &'static str,
+    /// Dot-separated paths of the unknown fields, e.g. `data.0.new_field`
+    pub fields: Vec<String>,
+}
+
+impl fmt::Display for UnknownFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "response to {} call to {} has fields not modeled by {}: {}",
+            self.method,
+            self.path,
+            self.type_name,
+            self.fields.join(", ")
+        )
+    }
+}
+
+impl StdError for UnknownFields {}
+
+impl From<UnknownFields> for Error {
+    fn from(err: UnknownFields) -> Self {
+        Error::with_source(Kind::UnknownFields, err)
+    }
+}
+
+/// Error indicating that
+/// [`Client::cancel_market_orders`](crate::clob::Client::cancel_market_orders) found no open
+/// orders to cancel.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct NoOpenOrders {
+    /// The market the orders were cancelled in, if given
+    pub market: Option<String>,
+    /// The asset the orders were cancelled for, if given
+    pub asset_id: Option<String>,
+}
+
+impl fmt::Display for NoOpenOrders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no open orders to cancel")?;
+        if let Some(market) = &self.market {
+            write!(f, " in market {market}")?;
+        }
+        if let Some(asset_id) = &self.asset_id {
+            write!(f, " for asset {asset_id}")?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for NoOpenOrders {}
+
+impl From<NoOpenOrders> for Error {
+    fn from(err: NoOpenOrders) -> Self {
+        Error::with_source(Kind::NoOpenOrders, err)
+    }
+}
+
+/// Error indicating that [`Client::rotate_api_key`](crate::clob::Client::rotate_api_key) created
+/// new credentials, but could neither delete the current key nor the new one again, so both keys
+/// remain valid.
+///
+/// The error takes the [`Kind`] of `delete_error`.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct RotationIncomplete {
+    /// The newly created credentials
+    pub credentials: Credentials,
+    /// Why the current key could not be deleted
+    pub delete_error: Error,
+    /// Why the new key could not be deleted afterwards
+    pub rollback_error: Error,
+}
+
+impl fmt::Display for RotationIncomplete {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "created API key {} but could not delete the current key ({}) nor the new one ({})",
+            self.credentials.key(),
+            self.delete_error,
+            self.rollback_error
+        )
+    }
+}
+
+impl StdError for RotationIncomplete {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.delete_error)
+    }
+}
+
+impl From<RotationIncomplete> for Error {
+    fn from(err: RotationIncomplete) -> Self {
+        Error::with_source(err.delete_error.kind(), err)
+    }
+}
+
+/// Error returned by [`Client::split_and_sell`](crate::clob::Client::split_and_sell) when the split
+/// was confirmed but its sell leg could not be placed, so the complete set is still held.
+///
+/// The error takes the [`Kind`] of `error`.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct SplitUnsold {
+    /// The hash of the confirmed split transaction
+    pub transaction_hash: B256,
+    /// Why the sell leg could not be placed
+    pub error: Error,
+}
+
+impl fmt::Display for SplitUnsold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "split in transaction {} but did not sell: {}",
+            self.transaction_hash, self.error
+        )
+    }
+}
+
+impl StdError for SplitUnsold {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<SplitUnsold> for Error {
+    fn from(err: SplitUnsold) -> Self {
+        Error::with_source(err.error.kind(), err)
+    }
+}
+
 /// Error indicating that the user is blocked from accessing Polymarket due to geographic
 /// restrictions.
 ///
@@ -307,4 +606,48 @@ mod tests {
         assert_eq!(error.kind(), Kind::Geoblock);
         assert!(error.to_string().contains("CU"));
     }
+
+    #[test]
+    fn status_should_classify_api_error() {
+        let classify = |status_code, message: &str| {
+            Error::status(status_code, Method::GET, "/path".to_owned(), message)
+                .as_api_error()
+                .cloned()
+        };
+
+        assert_eq!(
+            classify(StatusCode::BAD_REQUEST, r#"{"error":"invalid order"}"#),
+            Some(ApiError::Validation("invalid order".to_owned()))
+        );
+        assert_eq!(
+            classify(StatusCode::UNPROCESSABLE_ENTITY, "bad payload"),
+            Some(ApiError::Validation("bad payload".to_owned()))
+        );
+        assert_eq!(
+            classify(StatusCode::UNAUTHORIZED, ""),
+            Some(ApiError::Unauthorized)
+        );
+        assert_eq!(
+            classify(StatusCode::FORBIDDEN, ""),
+            Some(ApiError::Forbidden)
+        );
+        assert_eq!(
+            classify(StatusCode::NOT_FOUND, ""),
+            Some(ApiError::NotFound)
+        );
+        assert_eq!(
+            classify(StatusCode::TOO_MANY_REQUESTS, ""),
+            Some(ApiError::RateLimited { retry_after: None })
+        );
+        assert_eq!(
+            classify(StatusCode::BAD_GATEWAY, r#"{"error":"upstream"}"#),
+            Some(ApiError::Server("upstream".to_owned()))
+        );
+        assert_eq!(classify(StatusCode::CONFLICT, ""), None);
+    }
+
+    #[test]
+    fn as_api_error_should_be_none_for_non_status_errors() {
+        assert_eq!(Error::validation("oops").as_api_error(), None);
+    }
 }
@@ -0,0 +1,76 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`GammaApi` names the API the trait abstracts over"
+)]
+
+//! Trait-based interface to the Gamma API.
+
+use async_trait::async_trait;
+
+use super::Client;
+use super::types::request::{
+    EventByIdRequest, EventBySlugRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
+    MarketsRequest, TagsRequest,
+};
+use super::types::response::{Event, Market, Tag};
+use crate::Result;
+
+/// The Gamma API endpoints most commonly used to discover markets.
+///
+/// Depend on this trait rather than on [`Client`] to be able to substitute a fake in tests, such
+/// as `FakeGammaApi` from the `test-util` feature. Each method behaves like the [`Client`] method
+/// of the same name.
+#[async_trait]
+pub trait GammaApi: Send + Sync {
+    /// See [`Client::events`].
+    async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>>;
+
+    /// See [`Client::event_by_id`].
+    async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event>;
+
+    /// See [`Client::event_by_slug`].
+    async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event>;
+
+    /// See [`Client::markets`].
+    async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>>;
+
+    /// See [`Client::market_by_id`].
+    async fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market>;
+
+    /// See [`Client::market_by_slug`].
+    async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market>;
+
+    /// See [`Client::tags`].
+    async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>>;
+}
+
+#[async_trait]
+impl GammaApi for Client {
+    async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>> {
+        Client::events(self, request).await
+    }
+
+    async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
+        Client::event_by_id(self, request).await
+    }
+
+    async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
+        Client::event_by_slug(self, request).await
+    }
+
+    async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
+        Client::markets(self, request).await
+    }
+
+    async fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market> {
+        Client::market_by_id(self, request).await
+    }
+
+    async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
+        Client::market_by_slug(self, request).await
+    }
+
+    async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>> {
+        Client::tags(self, request).await
+    }
+}
@@ -25,8 +25,22 @@
 //! # }
 //! ```
 
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, Instant};
+
+use async_stream::try_stream;
+#[cfg(feature = "new-markets")]
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use futures::future::join_all;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
 use reqwest::{
-    Client as ReqwestClient, Method,
+    Client as ReqwestClient, Method, Request, StatusCode,
     header::{HeaderMap, HeaderValue},
 };
 use serde::Serialize;
@@ -41,11 +55,38 @@ use super::types::request::{
     TagBySlugRequest, TagsRequest, TeamsRequest,
 };
 use super::types::response::{
-    Comment, Event, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
-    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+    Comment, Event, HealthResponse, Market, PublicProfile, PublicProfileBody, RelatedTag,
+    SearchResults, Series, SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+};
+use super::types::{NegRiskBoard, NegRiskOutcome, PriceSource, ResolvedMarket};
+use crate::auth::state::State;
+use crate::clob;
+use crate::clob::types::TickSize;
+use crate::clob::types::request::{LastTradePriceRequest, MidpointRequest};
+use crate::deadline::with_deadline;
+use crate::error::{ApiError, Error, Status};
+use crate::metrics::MetricsObserver;
+use crate::middleware::Middleware;
+use crate::types::{Address, Decimal};
+use crate::{
+    ConnectionConfig, GAMMA_HOST_VAR, MAX_CONCURRENT_REQUESTS, RequestContext, Result,
+    ToQueryParams as _, host_from_env,
 };
-use crate::error::Error;
-use crate::{Result, ToQueryParams as _};
+
+/// The endpoint used by [`Client::default`] and, unless overridden, [`Client::from_env`].
+const DEFAULT_HOST: &str = "https://gamma-api.polymarket.com";
+
+/// Maximum number of IDs requested at once by [`Client::events_by_ids`] and
+/// [`Client::markets_by_ids`], keeping query strings well under server-side length limits.
+const MAX_IDS_PER_REQUEST: usize = 50;
+
+/// How long [`Client::resolve_slug`] results are cached by default. Tick sizes change as prices
+/// approach the extremes, so resolutions are not kept indefinitely.
+const DEFAULT_RESOLVED_SLUG_TTL: Duration = Duration::from_secs(60);
+
+/// Number of markets requested per page by [`Client::new_markets_stream`].
+#[cfg(feature = "new-markets")]
+const NEW_MARKETS_PAGE_SIZE: i32 = 100;
 
 /// HTTP client for the Polymarket Gamma API.
 ///
@@ -70,13 +111,22 @@ use crate::{Result, ToQueryParams as _};
 #[derive(Clone, Debug)]
 pub struct Client {
     host: Url,
-    client: ReqwestClient,
+    http: ReqwestClient,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    #[cfg(feature = "lenient")]
+    lenient: bool,
+    max_response_bytes: Option<usize>,
+    middleware: Middleware,
+    /// Local cache of [`ResolvedMarket`] per market slug and when it was fetched, shared between
+    /// clones
+    resolved_slugs: Arc<DashMap<String, (ResolvedMarket, Instant)>>,
+    /// How long [`Client::resolve_slug`] results are cached
+    resolved_slug_ttl: Duration,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://gamma-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
     }
 }
 
@@ -91,26 +141,109 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::new_with_connection(host, ConnectionConfig::default())
+    }
+
+    /// Creates a new Gamma API client with a custom host URL whose HTTP connections use the
+    /// `connection` pool and keep-alive settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn new_with_connection(host: &str, connection: ConnectionConfig) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let http = connection
+            .apply(ReqwestClient::builder().default_headers(headers))
+            .build()?;
 
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            http,
+            metrics: None,
+            #[cfg(feature = "lenient")]
+            lenient: false,
+            max_response_bytes: None,
+            middleware: Middleware::default(),
+            resolved_slugs: Arc::default(),
+            resolved_slug_ttl: DEFAULT_RESOLVED_SLUG_TTL,
         })
     }
 
+    /// Creates a new Gamma API client configured from the environment.
+    ///
+    /// Reads [`GAMMA_HOST_VAR`] (`POLYMARKET_GAMMA_HOST`) for the host, defaulting to
+    /// `https://gamma-api.polymarket.com` when unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is set but is not a valid URL.
+    pub fn from_env() -> Result<Client> {
+        Self::new(&host_from_env(GAMMA_HOST_VAR, DEFAULT_HOST)?)
+    }
+
+    /// Registers a [`MetricsObserver`] that is notified of every request made by this client.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enables lenient deserialization of responses: optional fields that fail to deserialize
+    /// degrade to `None` or their default and are logged instead of failing the request. Required
+    /// fields and list items still fail it.
+    ///
+    /// Strict deserialization is the default.
+    #[cfg(feature = "lenient")]
+    #[must_use]
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Caps the size of a response body this client will buffer at `max_response_bytes`. Larger
+    /// responses fail with [`ResponseTooLarge`](crate::error::ResponseTooLarge) as soon as they
+    /// are known to exceed it, rather than being read in full.
+    ///
+    /// Responses are unlimited by default. Large listings are best read a page at a time with
+    /// `limit` and `offset`, or through [`Self::search_stream`] for searches.
+    #[must_use]
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Registers [`Middleware`] whose interceptors and observers are applied to every request made
+    /// by this client.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Middleware) -> Self {
+        self.middleware = middleware;
+        self
+    }
+
+    /// Sets how long [`Self::resolve_slug`] results are cached. Defaults to 60 seconds, so that
+    /// a changed tick size is picked up.
+    #[must_use]
+    pub fn with_resolved_slug_ttl(mut self, ttl: Duration) -> Self {
+        self.resolved_slug_ttl = ttl;
+        self
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
         &self.host
     }
 
+    /// Clears the markets cached by [`Client::resolve_slug`].
+    pub fn invalidate_internal_caches(&self) {
+        self.resolved_slugs.clear();
+    }
+
     async fn get<Req: Serialize, Res: DeserializeOwned + Serialize>(
         &self,
         path: &str,
@@ -118,10 +251,24 @@ impl Client {
     ) -> Result<Res> {
         let query = req.query_params(None);
         let request = self
-            .client
+            .http
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        self.send(request).await
+    }
+
+    /// Sends `request` with the HTTP client and settings of this client.
+    async fn send<Res: DeserializeOwned>(&self, request: Request) -> Result<Res> {
+        let context = RequestContext {
+            client: &self.http,
+            metrics: self.metrics.as_deref(),
+            #[cfg(feature = "lenient")]
+            lenient: self.lenient,
+            max_response_bytes: self.max_response_bytes,
+            middleware: &self.middleware,
+        };
+
+        crate::request(context, request, None).await
     }
 
     /// Performs a health check on the API.
@@ -129,11 +276,11 @@ impl Client {
     /// Returns "OK" when the API is healthy.
     pub async fn status(&self) -> Result<HealthResponse> {
         let request = self
-            .client
+            .http
             .request(Method::GET, format!("{}status", self.host))
             .build()?;
 
-        let response = self.client.execute(request).await?;
+        let response = self.http.execute(request).await?;
         let status_code = response.status();
 
         if !status_code.is_success() {
@@ -224,6 +371,86 @@ impl Client {
         self.get("events", request).await
     }
 
+    /// Gets the events with the given IDs.
+    ///
+    /// IDs are requested in chunks of a bounded size, with up to a bounded number of chunks in
+    /// flight at once, and each chunk's limit covers all of its IDs so no further paging is
+    /// needed. Events are returned in chunk order; IDs without an event are skipped.
+    pub async fn events_by_ids(&self, ids: Vec<i32>) -> Result<Vec<Event>> {
+        self.by_ids(ids, |chunk| async move {
+            let request = EventsRequest {
+                limit: Some(chunk_limit(&chunk)),
+                id: chunk,
+                ..EventsRequest::default()
+            };
+            self.events(&request).await
+        })
+        .await
+    }
+
+    /// Returns a stream of the events whose IDs fall in `ids`, e.g. for a backfill, yielding them
+    /// in ID order while fetching up to a bounded number of them concurrently.
+    ///
+    /// IDs without an event, which the API answers with a 404, are skipped silently; once the
+    /// stream has ended, [`EventRangeStream::skipped`] tells how many were. Any other error is
+    /// yielded in place of its event without ending the stream.
+    pub fn events_by_id_range(&self, ids: RangeInclusive<i32>) -> EventRangeStream<'_> {
+        let events = stream::iter(ids)
+            .map(move |id| async move {
+                let request = EventByIdRequest::builder().id(id.to_string()).build();
+                match self.event_by_id(&request).await {
+                    Ok(event) => Ok(Some(event)),
+                    Err(e)
+                        if e.downcast_ref::<Status>()
+                            .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) =>
+                    {
+                        Ok(None)
+                    }
+                    Err(e) => Err(e),
+                }
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .boxed();
+
+        EventRangeStream { events, skipped: 0 }
+    }
+
+    /// Lists the events matching `request` that carry any of the tags with the given IDs.
+    ///
+    /// The API filters on a single tag, so `request` is sent once per tag, overriding its
+    /// `tag_id` and `tag_slug`, with up to a bounded number of requests in flight at once. Its
+    /// `limit` and `offset` therefore apply to each tag separately. Events are returned in tag
+    /// order, each only the first time it is seen.
+    pub async fn events_by_tag_ids(
+        &self,
+        request: &EventsRequest,
+        tag_ids: Vec<i32>,
+    ) -> Result<Vec<Event>> {
+        let requests = tag_ids.into_iter().map(|tag_id| EventsRequest {
+            tag_id: Some(tag_id.to_string()),
+            tag_slug: None,
+            ..request.clone()
+        });
+        self.events_by_tags(requests).await
+    }
+
+    /// Lists the events matching `request` that carry any of the tags with the given slugs,
+    /// e.g. `nba` or `nfl`.
+    ///
+    /// Fetched and merged as in [`Self::events_by_tag_ids`].
+    pub async fn events_by_tag_slugs(
+        &self,
+        request: &EventsRequest,
+        tag_slugs: Vec<String>,
+    ) -> Result<Vec<Event>> {
+        let requests = tag_slugs.into_iter().map(|tag_slug| EventsRequest {
+            tag_id: None,
+            tag_slug: Some(tag_slug),
+            ..request.clone()
+        });
+        self.events_by_tags(requests).await
+    }
+
     /// Gets an event by ID.
     pub async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
         self.get(&format!("events/{}", request.id), request).await
@@ -235,6 +462,206 @@ impl Client {
             .await
     }
 
+    /// Gets every market in an event alongside its current midpoint price from the CLOB.
+    ///
+    /// The price is the midpoint of the market's first (e.g. "Yes") outcome token. Markets
+    /// without an order book, whether disabled in Gamma or unknown to the CLOB (`404`), get an
+    /// `Ok(None)` price, and a midpoint that cannot be fetched for any other reason (e.g. a `5xx`,
+    /// `429` or network error) gets its error, rather than failing the whole call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be fetched.
+    pub async fn event_market_prices<S: State>(
+        &self,
+        clob: &clob::Client<S>,
+        event_id: &str,
+    ) -> Result<Vec<(Market, Result<Option<Decimal>>)>> {
+        let request = EventByIdRequest::builder().id(event_id).build();
+        let markets = self
+            .event_by_id(&request)
+            .await?
+            .markets
+            .unwrap_or_default();
+
+        let prices = join_all(markets.iter().map(|market| async move {
+            if market.enable_order_book == Some(false) {
+                return Ok(None);
+            }
+            let Some(token_id) = market.token_ids().into_iter().next() else {
+                return Ok(None);
+            };
+            let request = MidpointRequest::builder().token_id(token_id).build();
+
+            match clob.midpoint(&request).await {
+                Ok(response) => Ok(Some(response.mid)),
+                Err(e) if e.as_api_error() == Some(&ApiError::NotFound) => Ok(None),
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(market = %market.id, error = %e, "unable to fetch midpoint");
+
+                    Err(e)
+                }
+            }
+        }))
+        .await;
+
+        Ok(markets.into_iter().zip(prices).collect())
+    }
+
+    /// Gets the outcome markets of the negative risk event `event_id` with the CLOB midpoint of
+    /// each one's "Yes" token, to compare prices across the field.
+    ///
+    /// The event's `neg_risk_market_id` groups the outcomes, and markets tagged with a different
+    /// one are left out. Midpoints are fetched in a single batch. Markets without an order
+    /// book, or for which the CLOB reports no midpoint, are kept with a `None` price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event is not a negative risk event, or if the event or the
+    /// midpoints cannot be fetched.
+    pub async fn neg_risk_board<S: State>(
+        &self,
+        clob: &clob::Client<S>,
+        event_id: &str,
+    ) -> Result<NegRiskBoard> {
+        let request = EventByIdRequest::builder().id(event_id).build();
+        let event = self.event_by_id(&request).await?;
+        let Some(neg_risk_market_id) = event
+            .neg_risk_market_id
+            .filter(|_| event.neg_risk == Some(true))
+        else {
+            return Err(Error::validation(format!(
+                "Event {event_id} is not a negative risk event"
+            )));
+        };
+
+        let markets: Vec<Market> = event
+            .markets
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|market| {
+                market
+                    .neg_risk_market_id
+                    .as_ref()
+                    .is_none_or(|id| *id == neg_risk_market_id)
+            })
+            .collect();
+        let token_ids: Vec<Vec<String>> = markets.iter().map(Market::token_ids).collect();
+
+        let requests: Vec<MidpointRequest> = markets
+            .iter()
+            .zip(&token_ids)
+            .filter(|(market, _)| market.enable_order_book != Some(false))
+            .filter_map(|(_, tokens)| tokens.first())
+            .map(|token_id| MidpointRequest::builder().token_id(token_id).build())
+            .collect();
+        let midpoints = if requests.is_empty() {
+            HashMap::new()
+        } else {
+            clob.midpoints(&requests).await?.midpoints
+        };
+
+        let outcomes = markets
+            .into_iter()
+            .zip(token_ids)
+            .map(|(market, tokens)| {
+                let mut tokens = tokens.into_iter();
+                let yes_token_id = tokens.next();
+                let price = yes_token_id
+                    .as_ref()
+                    .filter(|_| market.enable_order_book != Some(false))
+                    .and_then(|token_id| midpoints.get(token_id).copied());
+
+                NegRiskOutcome {
+                    market,
+                    yes_token_id,
+                    no_token_id: tokens.next(),
+                    price,
+                }
+            })
+            .collect();
+
+        Ok(NegRiskBoard {
+            event_id: event.id,
+            neg_risk_market_id,
+            outcomes,
+        })
+    }
+
+    /// Gets the freshest last trade price available for `token_id`, along with its source.
+    ///
+    /// The live price is asked from the CLOB first, giving it up to `timeout` to answer. If it
+    /// fails, times out, or has no trade to report, the price falls back to the last trade
+    /// price of the token's Gamma market. Gamma records it for the market's first outcome, so
+    /// for the second outcome its complement is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `404` [`Status`] error if the CLOB has no price and no Gamma market holds
+    /// `token_id`. Returns a validation error if the Gamma market has no last trade price, or has
+    /// more than two outcomes, since a single price and its complement only describe binary
+    /// markets.
+    pub async fn effective_last_price<S: State>(
+        &self,
+        clob: &clob::Client<S>,
+        token_id: &str,
+        timeout: Duration,
+    ) -> Result<(Decimal, PriceSource)> {
+        let request = LastTradePriceRequest::builder().token_id(token_id).build();
+        match with_deadline(Instant::now() + timeout, clob.last_trade_price(&request)).await {
+            Ok(response) if !response.price.is_zero() => {
+                return Ok((response.price, PriceSource::Clob));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                #[cfg(not(feature = "tracing"))]
+                let _: Error = e;
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(token_id, error = %e, "unable to fetch live last trade price");
+            }
+        }
+
+        let request = MarketsRequest::builder()
+            .clob_token_ids(vec![token_id.to_owned()])
+            .build();
+        let (market, position) = self
+            .markets(&request)
+            .await?
+            .into_iter()
+            .find_map(|market| {
+                let position = market.token_ids().iter().position(|id| id == token_id)?;
+                Some((market, position))
+            })
+            .ok_or_else(|| {
+                Error::status(
+                    StatusCode::NOT_FOUND,
+                    Method::GET,
+                    "markets".to_owned(),
+                    format!("No market found for token {token_id}"),
+                )
+            })?;
+        let outcomes = market.token_ids().len();
+        if outcomes > 2 {
+            return Err(Error::validation(format!(
+                "Market {} has {outcomes} outcomes, so its last trade price cannot price token \
+                {token_id}",
+                market.id
+            )));
+        }
+        let price = market.last_trade_price.ok_or_else(|| {
+            Error::validation(format!("No last trade price for token {token_id}"))
+        })?;
+
+        let price = if position == 0 {
+            price
+        } else {
+            Decimal::ONE - price
+        };
+        Ok((price, PriceSource::Gamma))
+    }
+
     /// Gets tags for an event by ID.
     pub async fn event_tags(&self, request: &EventTagsRequest) -> Result<Vec<Tag>> {
         self.get(&format!("events/{}/tags", request.id), request)
@@ -258,10 +685,25 @@ impl Client {
         };
 
         let req = self
-            .client
+            .http
             .request(Method::GET, format!("{}markets{query}", self.host))
             .build()?;
-        crate::request(&self.client, req, None).await
+        self.send(req).await
+    }
+
+    /// Gets the markets with the given IDs.
+    ///
+    /// See [`Client::events_by_ids`] for how the IDs are chunked.
+    pub async fn markets_by_ids(&self, ids: Vec<i32>) -> Result<Vec<Market>> {
+        self.by_ids(ids, |chunk| async move {
+            let request = MarketsRequest {
+                limit: Some(chunk_limit(&chunk)),
+                id: chunk,
+                ..MarketsRequest::default()
+            };
+            self.markets(&request).await
+        })
+        .await
     }
 
     /// Gets a market by ID.
@@ -269,12 +711,75 @@ impl Client {
         self.get(&format!("markets/{}", request.id), request).await
     }
 
+    /// Gets the full description of a market by ID.
+    ///
+    /// The Gamma API has no endpoint serving only the description, so this fetches the market
+    /// and returns its `description`, or `None` if it has none. Use it to load the full text for a
+    /// detail view without keeping descriptions around for every market of a list.
+    pub async fn market_description(&self, request: &MarketByIdRequest) -> Result<Option<String>> {
+        Ok(self.market_by_id(request).await?.description)
+    }
+
     /// Gets a market by slug.
     pub async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
         self.get(&format!("markets/slug/{}", request.slug), request)
             .await
     }
 
+    /// Resolves the market at `slug`, e.g. taken from a market URL, into what is needed to
+    /// trade it: its condition ID, outcome token IDs, tick size and negative risk flag.
+    ///
+    /// Results are cached per slug for the configured TTL (see [`Client::with_resolved_slug_ttl`]),
+    /// or until [`Client::invalidate_internal_caches`] is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market cannot be fetched, or if it has no condition ID, no token
+    /// IDs, or no valid tick size.
+    pub async fn resolve_slug(&self, slug: &str) -> Result<ResolvedMarket> {
+        if let Some(entry) = self.resolved_slugs.get(slug) {
+            let (resolved, fetched_at) = &*entry;
+            if fetched_at.elapsed() < self.resolved_slug_ttl {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(slug = %slug, "cache hit: resolve_slug");
+                return Ok(resolved.clone());
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(slug = %slug, "cache miss: resolve_slug");
+
+        let request = MarketBySlugRequest::builder().slug(slug).build();
+        let market = self.market_by_slug(&request).await?;
+
+        let token_ids = market.token_ids();
+        if token_ids.is_empty() {
+            return Err(Error::validation(format!(
+                "Market {slug} has no CLOB token IDs"
+            )));
+        }
+        let Some(condition_id) = market.condition_id.clone() else {
+            return Err(Error::validation(format!(
+                "Market {slug} has no condition ID"
+            )));
+        };
+        let Some(tick_size) = market.order_price_min_tick_size else {
+            return Err(Error::validation(format!("Market {slug} has no tick size")));
+        };
+
+        let resolved = ResolvedMarket {
+            condition_id,
+            token_ids,
+            outcomes: market.outcome_names(),
+            tick_size: TickSize::try_from(tick_size)?,
+            neg_risk: market.neg_risk.unwrap_or(false),
+        };
+        self.resolved_slugs
+            .insert(slug.to_owned(), (resolved.clone(), Instant::now()));
+
+        Ok(resolved)
+    }
+
     /// Gets tags for a market by ID.
     pub async fn market_tags(&self, request: &MarketTagsRequest) -> Result<Vec<Tag>> {
         self.get(&format!("markets/{}/tags", request.id), request)
@@ -313,13 +818,294 @@ impl Client {
         .await
     }
 
-    /// Gets a public profile by wallet address.
-    pub async fn public_profile(&self, request: &PublicProfileRequest) -> Result<PublicProfile> {
-        self.get("public-profile", request).await
+    /// Gets a public profile by wallet address, or `None` if the address has no profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicProfileError`](super::types::response::PublicProfileError) if the
+    /// endpoint answers with an error body, and an error if the request fails for any other reason
+    /// than a missing profile.
+    pub async fn public_profile(
+        &self,
+        request: &PublicProfileRequest,
+    ) -> Result<Option<PublicProfile>> {
+        match self.get("public-profile", request).await {
+            Ok(PublicProfileBody::Profile(profile)) => Ok(Some(profile)),
+            Ok(PublicProfileBody::Error(err)) => Err(err.into()),
+            Err(e)
+                if e.downcast_ref::<Status>()
+                    .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets the public profiles for many wallet addresses, fetching up to a bounded number of
+    /// profiles concurrently.
+    ///
+    /// Addresses without a profile are absent from the returned map rather than failing the
+    /// batch. Any other error fails the whole call.
+    pub async fn public_profiles(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<HashMap<Address, PublicProfile>> {
+        stream::iter(addresses)
+            .map(|address| async move {
+                let request = PublicProfileRequest::builder()
+                    .address(format!("{address:#x}"))
+                    .build();
+
+                let profile = self.public_profile(&request).await?;
+                Ok(profile.map(|profile| (address, profile)))
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_filter_map(|profile| async move { Ok(profile) })
+            .try_collect()
+            .await
     }
 
     /// Searches markets, events, and profiles.
+    ///
+    /// Returns a single page of results; [`SearchResults::pagination`] reports whether more
+    /// remain. Use [`Self::search_stream`] to page through all of them.
     pub async fn search(&self, request: &SearchRequest) -> Result<SearchResults> {
         self.get("public-search", request).await
     }
+
+    /// Returns a stream of search result pages, starting at `request.page` (default: 1) and
+    /// bumping `page` until the server reports no more results.
+    ///
+    /// Each page holds that page's events, tags, and profiles together. The stream also stops
+    /// after a page with no results in any category, so a server that never clears `has_more`
+    /// cannot make it loop forever, and ends after the first error.
+    pub fn search_stream<'client>(
+        &'client self,
+        request: &SearchRequest,
+    ) -> impl Stream<Item = Result<SearchResults>> + 'client {
+        let mut request = request.clone();
+
+        try_stream! {
+            let mut page = request.page.unwrap_or(1);
+
+            loop {
+                request.page = Some(page);
+                let results = self.search(&request).await?;
+
+                let is_empty = [
+                    results.events.as_ref().map(Vec::len),
+                    results.tags.as_ref().map(Vec::len),
+                    results.profiles.as_ref().map(Vec::len),
+                ]
+                .into_iter()
+                .flatten()
+                .all(|len| len == 0);
+                let has_more = results
+                    .pagination
+                    .as_ref()
+                    .and_then(|pagination| pagination.has_more)
+                    .unwrap_or(false);
+
+                yield results;
+
+                if is_empty || !has_more {
+                    break;
+                }
+                page += 1;
+            }
+        }
+    }
+
+    /// Returns a stream of the markets matching `request` that are created after it starts,
+    /// checking for them every `poll_interval`.
+    ///
+    /// Each poll pages through `/markets` newest first (`order=createdAt`, `ascending=false`)
+    /// until it reaches markets already seen, then yields the new ones oldest first. The first
+    /// successful poll only records the newest existing market, so markets created before the
+    /// stream started are never yielded, and markets without a `created_at` are skipped. The
+    /// `limit`, `offset`, `order` and `ascending` of `request` are overridden.
+    ///
+    /// The stream never ends on its own: a failed poll yields its error and is retried after
+    /// `poll_interval`, the next successful one picking up every market created meanwhile.
+    ///
+    /// Requires the `new-markets` feature, which pulls in `tokio` for the timer.
+    #[cfg(feature = "new-markets")]
+    pub fn new_markets_stream<'client>(
+        &'client self,
+        request: &MarketsRequest,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Market>> + 'client {
+        let mut request = request.clone();
+        request.limit = Some(NEW_MARKETS_PAGE_SIZE);
+        request.order = Some("createdAt".to_owned());
+        request.ascending = Some(false);
+
+        async_stream::stream! {
+            let mut watermark: Option<Watermark> = None;
+
+            loop {
+                match self.markets_after(&request, watermark.as_ref()).await {
+                    Ok(markets) => {
+                        let baseline = watermark.is_none();
+                        let new = watermark.get_or_insert_default().advance(markets);
+                        if !baseline {
+                            for market in new {
+                                yield Ok(market);
+                            }
+                        }
+                    }
+                    Err(e) => yield Err(e),
+                }
+
+                tokio::time::sleep(poll_interval).await;
+            }
+        }
+    }
+
+    /// Pages through the markets of `request` until reaching ones `watermark` has passed, or
+    /// fetches a single page when there is no watermark yet.
+    #[cfg(feature = "new-markets")]
+    async fn markets_after(
+        &self,
+        request: &MarketsRequest,
+        watermark: Option<&Watermark>,
+    ) -> Result<Vec<Market>> {
+        let mut request = request.clone();
+        let mut markets = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            request.offset = Some(offset);
+            let page = self.markets(&request).await?;
+            let is_last = i32::try_from(page.len()).unwrap_or(i32::MAX) < NEW_MARKETS_PAGE_SIZE;
+            let reached = watermark.is_none_or(|watermark| {
+                page.iter()
+                    .any(|market| market.created_at < watermark.created_at)
+            });
+
+            markets.extend(page);
+            if is_last || reached {
+                return Ok(markets);
+            }
+            offset += NEW_MARKETS_PAGE_SIZE;
+        }
+    }
+
+    /// Sends each of the single tag `requests` and merges their events, dropping those already
+    /// returned for an earlier tag.
+    async fn events_by_tags<I>(&self, requests: I) -> Result<Vec<Event>>
+    where
+        I: IntoIterator<Item = EventsRequest>,
+    {
+        let pages: Vec<Vec<Event>> = stream::iter(requests)
+            .map(|request| async move { self.events(&request).await })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        let mut seen = HashSet::new();
+        Ok(pages
+            .into_iter()
+            .flatten()
+            .filter(|event| seen.insert(event.id.clone()))
+            .collect())
+    }
+
+    /// Splits `ids` into chunks of at most [`MAX_IDS_PER_REQUEST`], fetches each with `fetch`,
+    /// and concatenates the results in chunk order.
+    async fn by_ids<T, F, Fut>(&self, ids: Vec<i32>, fetch: F) -> Result<Vec<T>>
+    where
+        F: Fn(Vec<String>) -> Fut,
+        Fut: Future<Output = Result<Vec<T>>>,
+    {
+        let chunks: Vec<Vec<String>> = ids
+            .chunks(MAX_IDS_PER_REQUEST)
+            .map(|chunk| chunk.iter().map(ToString::to_string).collect())
+            .collect();
+
+        let pages: Vec<Vec<T>> = stream::iter(chunks)
+            .map(fetch)
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        Ok(pages.into_iter().flatten().collect())
+    }
+}
+
+/// The `limit` that fits every record requested by a chunk of IDs into a single page.
+fn chunk_limit(chunk: &[String]) -> i32 {
+    i32::try_from(chunk.len()).unwrap_or(i32::MAX)
+}
+
+/// Stream returned by [`Client::events_by_id_range`].
+#[must_use = "streams do nothing unless polled"]
+pub struct EventRangeStream<'client> {
+    events: BoxStream<'client, Result<Option<Event>>>,
+    skipped: usize,
+}
+
+impl EventRangeStream<'_> {
+    /// Returns how many IDs had no event so far, which is the total once the stream has ended.
+    #[must_use]
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl Stream for EventRangeStream<'_> {
+    type Item = Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.events.poll_next_unpin(cx)) {
+                Some(Ok(None)) => self.skipped += 1,
+                Some(Ok(Some(event))) => return Poll::Ready(Some(Ok(event))),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// The newest creation time seen by [`Client::new_markets_stream`], along with the IDs of the
+/// markets created at exactly that time, so that markets sharing it are neither missed nor
+/// yielded twice.
+#[cfg(feature = "new-markets")]
+#[derive(Debug, Default)]
+struct Watermark {
+    created_at: Option<DateTime<Utc>>,
+    ids: HashSet<String>,
+}
+
+#[cfg(feature = "new-markets")]
+impl Watermark {
+    fn is_new(&self, market: &Market) -> bool {
+        match market.created_at {
+            None => false,
+            Some(_) if market.created_at == self.created_at => !self.ids.contains(&market.id),
+            Some(_) => market.created_at > self.created_at,
+        }
+    }
+
+    /// Keeps the `markets` created since the watermark, oldest first, and moves it past them.
+    fn advance(&mut self, markets: Vec<Market>) -> Vec<Market> {
+        let mut seen = HashSet::new();
+        let mut new: Vec<Market> = markets
+            .into_iter()
+            .filter(|market| self.is_new(market) && seen.insert(market.id.clone()))
+            .collect();
+        new.sort_by_key(|market| market.created_at);
+
+        for market in &new {
+            if market.created_at > self.created_at {
+                self.created_at = market.created_at;
+                self.ids.clear();
+            }
+            self.ids.insert(market.id.clone());
+        }
+
+        new
+    }
 }
@@ -0,0 +1,124 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`FakeGammaApi` reads better at use sites than `fake::GammaApi`"
+)]
+
+//! In-memory fake of the Gamma API for tests.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+
+use super::api::GammaApi;
+use super::types::request::{
+    EventByIdRequest, EventBySlugRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
+    MarketsRequest, TagsRequest,
+};
+use super::types::response::{Event, Market, Tag};
+use crate::error::Error;
+use crate::{Result, canned};
+
+/// A [`GammaApi`] that answers every call with a canned response instead of making HTTP
+/// requests.
+///
+/// Lookups of a single event or market search the programmed lists by ID or slug, so one call to
+/// [`with_events`](Self::with_events) or [`with_markets`](Self::with_markets) serves both the
+/// list and the lookup methods. Lookups that find nothing fail like a missing resource would, and
+/// any method can be made to fail with an HTTP status error with
+/// [`with_failure`](Self::with_failure).
+#[derive(Debug, Clone, Default)]
+pub struct FakeGammaApi {
+    events: Option<Vec<Event>>,
+    markets: Option<Vec<Market>>,
+    tags: Option<Vec<Tag>>,
+    failures: HashMap<&'static str, StatusCode>,
+}
+
+impl FakeGammaApi {
+    /// Creates a fake with no canned responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the events returned by [`GammaApi::events`] and searched by the event lookups.
+    #[must_use]
+    pub fn with_events(mut self, events: Vec<Event>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Sets the markets returned by [`GammaApi::markets`] and searched by the market lookups.
+    #[must_use]
+    pub fn with_markets(mut self, markets: Vec<Market>) -> Self {
+        self.markets = Some(markets);
+        self
+    }
+
+    /// Sets the response to [`GammaApi::tags`].
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Makes every call to the [`GammaApi`] method named `method`, e.g. `"markets"`, fail with the
+    /// error the real client returns when the API answers with `status_code`.
+    #[must_use]
+    pub fn with_failure(mut self, method: &'static str, status_code: StatusCode) -> Self {
+        self.failures.insert(method, status_code);
+        self
+    }
+
+    fn event(&self, method: &str, matches: impl Fn(&Event) -> bool) -> Result<Event> {
+        let events = canned(&self.failures, self.events.as_ref(), method)?;
+        events
+            .into_iter()
+            .find(matches)
+            .ok_or_else(|| Error::validation(format!("No canned event matches `{method}`")))
+    }
+
+    fn market(&self, method: &str, matches: impl Fn(&Market) -> bool) -> Result<Market> {
+        let markets = canned(&self.failures, self.markets.as_ref(), method)?;
+        markets
+            .into_iter()
+            .find(matches)
+            .ok_or_else(|| Error::validation(format!("No canned market matches `{method}`")))
+    }
+}
+
+#[async_trait]
+impl GammaApi for FakeGammaApi {
+    async fn events(&self, _request: &EventsRequest) -> Result<Vec<Event>> {
+        canned(&self.failures, self.events.as_ref(), "events")
+    }
+
+    async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
+        self.event("event_by_id", |event| event.id == request.id)
+    }
+
+    async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
+        self.event("event_by_slug", |event| {
+            event.slug.as_deref() == Some(request.slug.as_str())
+        })
+    }
+
+    async fn markets(&self, _request: &MarketsRequest) -> Result<Vec<Market>> {
+        canned(&self.failures, self.markets.as_ref(), "markets")
+    }
+
+    async fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market> {
+        self.market("market_by_id", |market| market.id == request.id)
+    }
+
+    async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
+        self.market("market_by_slug", |market| {
+            market.slug.as_deref() == Some(request.slug.as_str())
+        })
+    }
+
+    async fn tags(&self, _request: &TagsRequest) -> Result<Vec<Tag>> {
+        canned(&self.failures, self.tags.as_ref(), "tags")
+    }
+}
@@ -1,3 +1,12 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`GammaApi` is re-exported next to the `Client` it abstracts over"
+)]
+#![expect(
+    clippy::same_name_method,
+    reason = "`GammaApi` mirrors `Client` methods so either can be used interchangeably"
+)]
+
 //! Polymarket Gamma API client and types.
 //!
 //! This module provides a client for interacting with the Polymarket Gamma API,
@@ -65,7 +74,11 @@
 //!
 //! The default API endpoint is `https://gamma-api.polymarket.com`.
 
+pub mod api;
 pub mod client;
+#[cfg(feature = "test-util")]
+pub mod fake;
 pub mod types;
 
+pub use api::GammaApi;
 pub use client::Client;
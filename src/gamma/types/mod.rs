@@ -28,8 +28,13 @@
 //!     .build();
 //! ```
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::clob::types::TickSize;
+use crate::error::Error;
+use crate::types::{Address, Decimal};
+
 pub mod request;
 pub mod response;
 
@@ -52,3 +57,360 @@ pub enum ParentEntityType {
     #[strum(serialize = "market")]
     Market,
 }
+
+/// Where a price returned by
+/// [`Client::effective_last_price`](crate::gamma::Client::effective_last_price) came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum PriceSource {
+    /// The live last trade price of the CLOB.
+    Clob,
+    /// The last trade price recorded on the Gamma market, which can lag the CLOB.
+    Gamma,
+}
+
+/// How often the events of a series recur.
+///
+/// Unrecognized values are preserved in [`Recurrence::Other`] so new recurrences
+/// published by the API do not break deserialization. Parsing a string with
+/// [`TryFrom`] only accepts the known recurrences, so that a typo is not sent as a filter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum Recurrence {
+    Daily,
+    Weekly,
+    Monthly,
+    #[serde(untagged)]
+    #[strum(default)]
+    Other(String),
+}
+
+impl TryFrom<&str> for Recurrence {
+    type Error = Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            other => Err(Error::validation(format!(
+                "Unknown recurrence: {other}. Expected one of: daily, weekly, monthly"
+            ))),
+        }
+    }
+}
+
+/// The kind of a sports market, as listed by the `/sports/market-types` endpoint.
+///
+/// Unrecognized values are preserved in [`SportsMarketType::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum SportsMarketType {
+    Moneyline,
+    #[serde(rename = "spreads")]
+    #[strum(serialize = "spreads")]
+    Spread,
+    #[serde(rename = "totals")]
+    #[strum(serialize = "totals")]
+    Total,
+    #[serde(untagged)]
+    #[strum(default)]
+    Other(String),
+}
+
+impl From<&str> for SportsMarketType {
+    fn from(value: &str) -> Self {
+        match value {
+            "moneyline" => Self::Moneyline,
+            "spreads" => Self::Spread,
+            "totals" => Self::Total,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SportsMarketType {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Other(_) => Self::Other(value),
+            known => known,
+        }
+    }
+}
+
+/// The line of a sports market, interpreted according to its [`SportsMarketType`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SportsLine {
+    /// Point spread the favored side must cover
+    Spread(Decimal),
+    /// Combined score the over/under is measured against
+    Total(Decimal),
+}
+
+/// The status of the game behind a sports event, as reported in [`Event::game_status`].
+///
+/// Statuses are matched ignoring case and separators, so `"InProgress"` and `"in_progress"` are
+/// equivalent. Unrecognized values are preserved in [`GameStatus::Other`].
+///
+/// [`Event::game_status`]: response::Event::game_status
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GameStatus {
+    /// The game has not started yet
+    Scheduled,
+    /// The game is being played
+    InProgress,
+    /// The game is paused at halftime or between periods
+    Halftime,
+    /// The game is over
+    Final,
+    /// The game was moved to a later date
+    Postponed,
+    /// The game will not be played
+    Canceled,
+    Other(String),
+}
+
+impl From<&str> for GameStatus {
+    fn from(value: &str) -> Self {
+        let normalized: String = value
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        match normalized.as_str() {
+            "scheduled" | "notstarted" | "pregame" => Self::Scheduled,
+            "inprogress" | "live" => Self::InProgress,
+            "halftime" | "break" => Self::Halftime,
+            "final" | "finished" | "ended" => Self::Final,
+            "postponed" => Self::Postponed,
+            "canceled" | "cancelled" => Self::Canceled,
+            _ => Self::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GameStatus {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Other(_) => Self::Other(value),
+            known => known,
+        }
+    }
+}
+
+/// What is needed to trade a market, resolved from its slug by
+/// [`Client::resolve_slug`](crate::gamma::Client::resolve_slug).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ResolvedMarket {
+    /// Condition ID of the market
+    pub condition_id: String,
+    /// CLOB token IDs of the market's outcomes, in outcome order
+    pub token_ids: Vec<String>,
+    /// Names of the market's outcomes, matching `token_ids`
+    pub outcomes: Vec<String>,
+    /// Minimum price increment of the market's orders
+    pub tick_size: TickSize,
+    /// Whether the market is part of a negative risk event
+    pub neg_risk: bool,
+}
+
+impl ResolvedMarket {
+    /// Returns the token ID of the outcome named `outcome` (e.g. `"Yes"`), compared
+    /// case-insensitively.
+    #[must_use]
+    pub fn token_id(&self, outcome: &str) -> Option<&str> {
+        self.outcomes
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(outcome))
+            .and_then(|index| self.token_ids.get(index))
+            .map(String::as_str)
+    }
+}
+
+/// The outcome markets of a negative risk event together with their live prices, gathered by
+/// [`Client::neg_risk_board`](crate::gamma::Client::neg_risk_board).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct NegRiskBoard {
+    /// ID of the event
+    pub event_id: String,
+    /// Neg risk market ID grouping the outcome markets
+    pub neg_risk_market_id: String,
+    /// One entry per outcome market, in the event's order
+    pub outcomes: Vec<NegRiskOutcome>,
+}
+
+impl NegRiskBoard {
+    /// Returns the sum of the prices of the outcomes that have one.
+    ///
+    /// Exactly one outcome of a negative risk event resolves to "Yes", so when the board
+    /// [`is_fully_priced`](Self::is_fully_priced) this should be close to `1`; a sum far from it
+    /// signals mispricing across the field.
+    #[must_use]
+    pub fn price_sum(&self) -> Decimal {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| outcome.price)
+            .sum()
+    }
+
+    /// Returns `true` if every outcome has a price, so that [`price_sum`](Self::price_sum)
+    /// covers the whole field.
+    #[must_use]
+    pub fn is_fully_priced(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.price.is_some())
+    }
+}
+
+/// An outcome of a [`NegRiskBoard`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct NegRiskOutcome {
+    /// The outcome's market
+    pub market: response::Market,
+    /// CLOB token ID of the market's "Yes" outcome
+    pub yes_token_id: Option<String>,
+    /// CLOB token ID of the market's "No" outcome
+    pub no_token_id: Option<String>,
+    /// Midpoint price of the "Yes" token, or [`None`] if the market has no order book or the CLOB
+    /// reported no midpoint for it
+    pub price: Option<Decimal>,
+}
+
+/// The status of a market's UMA optimistic oracle resolution, as reported in
+/// [`Market::uma_resolution_status`].
+///
+/// Statuses are matched ignoring case and separators. Unrecognized values are preserved in
+/// [`ResolutionStatus::Other`].
+///
+/// [`Market::uma_resolution_status`]: response::Market::uma_resolution_status
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolutionStatus {
+    /// An outcome was proposed and can still be disputed
+    Proposed,
+    /// The proposed outcome was disputed and awaits a vote
+    Disputed,
+    /// The outcome is final
+    Resolved,
+    Other(String),
+}
+
+impl From<&str> for ResolutionStatus {
+    fn from(value: &str) -> Self {
+        let normalized: String = value
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        match normalized.as_str() {
+            "proposed" => Self::Proposed,
+            "disputed" => Self::Disputed,
+            "resolved" => Self::Resolved,
+            _ => Self::Other(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolutionStatus {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Other(_) => Self::Other(value),
+            known => known,
+        }
+    }
+}
+
+/// The UMA resolution of a market, gathered by [`Market::resolution_info`].
+///
+/// [`Market::resolution_info`]: response::Market::resolution_info
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResolutionInfo {
+    /// Current status, falling back to the latest of [`history`](Self::history) when the market
+    /// does not report one
+    pub status: Option<ResolutionStatus>,
+    /// Every status the resolution went through, oldest first
+    pub history: Vec<ResolutionStatus>,
+    /// When the current proposal can no longer be disputed
+    pub end_date: Option<DateTime<Utc>>,
+    /// Address of the resolver
+    pub resolved_by: Option<Address>,
+    /// Bond a proposer or disputer must post, in USDC
+    pub bond: Option<Decimal>,
+    /// Reward paid to the proposer of the accepted outcome, in USDC
+    pub reward: Option<Decimal>,
+}
+
+impl ResolutionInfo {
+    /// Returns `true` if an outcome was proposed and is still open to disputes.
+    #[must_use]
+    pub fn is_proposed(&self) -> bool {
+        self.status == Some(ResolutionStatus::Proposed)
+    }
+
+    /// Returns `true` if the proposed outcome is disputed, so the market's resolution is pending
+    /// a vote.
+    #[must_use]
+    pub fn is_disputed(&self) -> bool {
+        self.status == Some(ResolutionStatus::Disputed)
+    }
+
+    /// Returns `true` if the outcome is final.
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        self.status == Some(ResolutionStatus::Resolved)
+    }
+}
+
+/// The live state of the game behind a sports event, gathered by [`Event::game_state`].
+///
+/// [`Event::game_state`]: response::Event::game_state
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GameState {
+    /// Identifier of the game in the sports data feed
+    pub game_id: Option<i64>,
+    /// Typed [`Event::game_status`](response::Event::game_status)
+    pub status: Option<GameStatus>,
+    /// Whether the event is flagged as live
+    pub live: bool,
+    /// Whether the event is flagged as ended
+    pub ended: bool,
+    /// Current score, in the format published by the API (e.g. `"3-1"`)
+    pub score: Option<String>,
+    /// Current period, in the format published by the API (e.g. `"Q2"`)
+    pub period: Option<String>,
+    /// Time elapsed in the game, in the format published by the API
+    pub elapsed: Option<String>,
+}
+
+impl GameState {
+    /// Returns `true` if the game has started and is not over, including breaks such as
+    /// halftime.
+    ///
+    /// A recognized [`status`](Self::status) takes precedence over the `live` flag, which is only
+    /// consulted when the status is missing or unrecognized. Ended games are never in play.
+    #[must_use]
+    pub fn is_in_play(&self) -> bool {
+        if self.ended {
+            return false;
+        }
+
+        match &self.status {
+            Some(GameStatus::InProgress | GameStatus::Halftime) => true,
+            Some(GameStatus::Other(_)) | None => self.live,
+            Some(_) => false,
+        }
+    }
+}
@@ -5,10 +5,14 @@
 
 use bon::Builder;
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
+use url::form_urlencoded;
 
-use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
+use crate::Result;
+use crate::error::Error;
+use crate::gamma::types::{ParentEntityType, Recurrence, RelatedTagsStatus};
 use crate::types::Decimal;
 
 #[serde_as]
@@ -90,7 +94,8 @@ pub struct RelatedTagsBySlugRequest {
 
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Builder, Default, Serialize)]
+#[derive(Debug, Clone, Builder, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[non_exhaustive]
 pub struct EventsRequest {
     pub limit: Option<i32>,
@@ -122,7 +127,10 @@ pub struct EventsRequest {
     pub cyom: Option<bool>,
     pub include_chat: Option<bool>,
     pub include_template: Option<bool>,
-    pub recurrence: Option<String>,
+    pub recurrence: Option<Recurrence>,
+    pub series_id: Option<i32>,
+    #[builder(into)]
+    pub series_slug: Option<String>,
     pub closed: Option<bool>,
     pub liquidity_min: Option<Decimal>,
     pub liquidity_max: Option<Decimal>,
@@ -134,6 +142,28 @@ pub struct EventsRequest {
     pub end_date_max: Option<DateTime<Utc>>,
 }
 
+impl EventsRequest {
+    /// Returns a builder preset to live events, to be customized further before building.
+    ///
+    /// Sets exactly `active(true)`, `archived(false)` and `closed(false)`, leaving every other
+    /// field unset. These three setters are therefore not available on the returned builder.
+    pub fn live() -> EventsRequestBuilder<
+        events_request_builder::SetClosed<
+            events_request_builder::SetArchived<events_request_builder::SetActive>,
+        >,
+    > {
+        Self::builder().active(true).archived(false).closed(false)
+    }
+
+    /// Parses a query string, as produced by [`ToQueryParams::query_params`], back into an
+    /// [`EventsRequest`]. A leading `?` or a full URL is also accepted.
+    ///
+    /// [`ToQueryParams::query_params`]: crate::ToQueryParams::query_params
+    pub fn from_query_string(query: &str) -> Result<Self> {
+        parse_query(query_part(query))
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -166,7 +196,8 @@ pub struct EventTagsRequest {
 
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Clone, Builder, Default, Serialize)]
+#[derive(Debug, Clone, Builder, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[non_exhaustive]
 pub struct MarketsRequest {
     pub limit: Option<i32>,
@@ -181,7 +212,7 @@ pub struct MarketsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub slug: Vec<String>,
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     #[builder(default)]
     pub clob_token_ids: Vec<String>,
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
@@ -220,6 +251,15 @@ pub struct MarketsRequest {
 }
 
 impl MarketsRequest {
+    /// Returns a builder preset to tradeable markets, to be customized further before building.
+    ///
+    /// Sets exactly `closed(false)`, leaving every other field unset, so that setter is not
+    /// available on the returned builder. Unlike `/events`, the `/markets` endpoint takes no
+    /// `active` or `archived` filter.
+    pub fn tradeable() -> MarketsRequestBuilder<markets_request_builder::SetClosed> {
+        Self::builder().closed(false)
+    }
+
     /// Returns the repeated query parameters for `clob_token_ids`.
     ///
     /// The Gamma API expects `clob_token_ids` as repeated query parameters
@@ -233,6 +273,40 @@ impl MarketsRequest {
             .collect::<Vec<_>>()
             .join("&")
     }
+
+    /// Parses a query string, as sent by [`Client::markets`], back into a [`MarketsRequest`].
+    /// A leading `?` or a full URL is also accepted.
+    ///
+    /// Repeated `clob_token_ids` parameters are collected into [`Self::clob_token_ids`].
+    ///
+    /// [`Client::markets`]: crate::gamma::Client::markets
+    pub fn from_query_string(query: &str) -> Result<Self> {
+        let mut clob_token_ids = Vec::new();
+        let mut rest = form_urlencoded::Serializer::new(String::new());
+
+        for (key, value) in form_urlencoded::parse(query_part(query).as_bytes()) {
+            if key == "clob_token_ids" {
+                clob_token_ids.push(value.into_owned());
+            } else {
+                rest.append_pair(&key, &value);
+            }
+        }
+
+        let mut request: Self = parse_query(&rest.finish())?;
+        request.clob_token_ids = clob_token_ids;
+
+        Ok(request)
+    }
+}
+
+/// Returns the query portion of `input`, which may be a full URL, `?`-prefixed, or bare.
+fn query_part(input: &str) -> &str {
+    input.split_once('?').map_or(input, |(_, query)| query)
+}
+
+fn parse_query<T: DeserializeOwned>(query: &str) -> Result<T> {
+    serde_urlencoded::from_str(query)
+        .map_err(|e| Error::validation(format!("Unable to parse query string: {e}")))
 }
 
 #[skip_serializing_none]
@@ -286,7 +360,7 @@ pub struct SeriesListRequest {
     pub categories_labels: Vec<String>,
     pub closed: Option<bool>,
     pub include_chat: Option<bool>,
-    pub recurrence: Option<String>,
+    pub recurrence: Option<Recurrence>,
 }
 
 #[skip_serializing_none]
@@ -314,6 +388,33 @@ pub struct CommentsRequest {
     pub holders_only: Option<bool>,
 }
 
+impl CommentsRequest {
+    /// Requests the comments on the event with `event_id`.
+    #[must_use]
+    pub fn for_event<ID: Into<String>>(event_id: ID) -> Self {
+        Self::for_entity(ParentEntityType::Event, event_id.into())
+    }
+
+    /// Requests the comments on the series with `series_id`.
+    #[must_use]
+    pub fn for_series<ID: Into<String>>(series_id: ID) -> Self {
+        Self::for_entity(ParentEntityType::Series, series_id.into())
+    }
+
+    /// Requests the comments on the market with `market_id`.
+    #[must_use]
+    pub fn for_market<ID: Into<String>>(market_id: ID) -> Self {
+        Self::for_entity(ParentEntityType::Market, market_id.into())
+    }
+
+    fn for_entity(parent_entity_type: ParentEntityType, parent_entity_id: String) -> Self {
+        Self::builder()
+            .parent_entity_type(parent_entity_type)
+            .parent_entity_id(parent_entity_id)
+            .build()
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -364,7 +465,7 @@ pub struct SearchRequest {
     pub ascending: Option<bool>,
     pub search_tags: Option<bool>,
     pub search_profiles: Option<bool>,
-    pub recurrence: Option<String>,
+    pub recurrence: Option<Recurrence>,
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
@@ -3,12 +3,22 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
-use chrono::{DateTime, Utc};
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use alloy::primitives::U256;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+use crate::error::{Error, Kind};
+use crate::gamma::types::{
+    GameState, GameStatus, Recurrence, ResolutionInfo, ResolutionStatus, SportsLine,
+    SportsMarketType,
+};
 use crate::serde_helpers::StringFromAny;
-use crate::types::Decimal;
+use crate::types::{Decimal, parse_json_string_array};
 
 /// Image optimization metadata.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -327,6 +337,59 @@ pub struct Event {
     pub color: Option<String>,
 }
 
+impl Event {
+    /// Iterates over the event's [`Market`]s, yielding nothing if they were not included.
+    pub fn market_iter(&self) -> impl Iterator<Item = &Market> {
+        self.markets.iter().flatten()
+    }
+
+    /// Returns `true` if the event is active and not closed.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.active == Some(true) && self.closed != Some(true)
+    }
+
+    /// Returns the fee, in basis points, charged when redeeming positions in this negative risk
+    /// event, or [`None`] if the event is not negative risk or reports no fee.
+    ///
+    /// This is the input `data::net_redeem_payout` expects, with the `data` feature.
+    #[must_use]
+    pub fn neg_risk_redeem_fee_bips(&self) -> Option<i64> {
+        if self.neg_risk != Some(true) {
+            return None;
+        }
+        self.neg_risk_fee_bips.map(i64::from)
+    }
+
+    /// Gathers the live game fields of a sports event into a [`GameState`].
+    ///
+    /// Returns [`None`] if the event carries none of them, as is the case for events that are
+    /// not tied to a game.
+    #[must_use]
+    pub fn game_state(&self) -> Option<GameState> {
+        if self.game_id.is_none()
+            && self.game_status.is_none()
+            && self.live.is_none()
+            && self.ended.is_none()
+            && self.score.is_none()
+            && self.period.is_none()
+            && self.elapsed.is_none()
+        {
+            return None;
+        }
+
+        Some(GameState {
+            game_id: self.game_id,
+            status: self.game_status.as_deref().map(GameStatus::from),
+            live: self.live.unwrap_or(false),
+            ended: self.ended.unwrap_or(false),
+            score: self.score.clone(),
+            period: self.period.clone(),
+            elapsed: self.elapsed.clone(),
+        })
+    }
+}
+
 /// A prediction market.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -493,6 +556,183 @@ pub struct Market {
     pub clob_rewards: Option<Vec<ClobReward>>,
 }
 
+impl Market {
+    /// Parses the JSON-encoded [`Market::clob_token_ids`] (e.g. `["123", "456"]`) into the
+    /// individual CLOB token IDs, ordered to match [`Market::outcomes`].
+    ///
+    /// Returns an empty [`Vec`] when the field is absent or malformed.
+    #[must_use]
+    pub fn token_ids(&self) -> Vec<String> {
+        parse_array(self.clob_token_ids.as_deref())
+    }
+
+    /// Parses the JSON-encoded [`Market::outcomes`] (e.g. `["Yes", "No"]`) into the outcome
+    /// names.
+    ///
+    /// Returns an empty [`Vec`] when the field is absent or malformed.
+    #[must_use]
+    pub fn outcome_names(&self) -> Vec<String> {
+        parse_array(self.outcomes.as_deref())
+    }
+
+    /// Parses the JSON-encoded [`Market::short_outcomes`] into the short outcome names.
+    ///
+    /// Returns an empty [`Vec`] when the field is absent or malformed.
+    #[must_use]
+    pub fn short_outcome_names(&self) -> Vec<String> {
+        parse_array(self.short_outcomes.as_deref())
+    }
+
+    /// Parses the JSON-encoded [`Market::outcome_prices`] (e.g. `["0.25", "0.75"]`) into one
+    /// [`Decimal`] price per outcome, ordered to match [`Market::outcomes`].
+    ///
+    /// Returns an empty [`Vec`] when the field is absent or malformed.
+    #[must_use]
+    pub fn outcome_prices_decimal(&self) -> Vec<Decimal> {
+        parse_array(self.outcome_prices.as_deref())
+    }
+
+    /// Returns the market's liquidity as a [`Decimal`].
+    ///
+    /// Reads the string [`Market::liquidity`] field, falling back to [`Market::liquidity_num`]
+    /// only when the string is absent. Returns [`None`] if the string does not parse.
+    #[must_use]
+    pub fn liquidity_decimal(&self) -> Option<Decimal> {
+        parse_or(self.liquidity.as_deref(), self.liquidity_num)
+    }
+
+    /// Returns the market's volume as a [`Decimal`].
+    ///
+    /// Reads the string [`Market::volume`] field, falling back to [`Market::volume_num`] only
+    /// when the string is absent. Returns [`None`] if the string does not parse.
+    #[must_use]
+    pub fn volume_decimal(&self) -> Option<Decimal> {
+        parse_or(self.volume.as_deref(), self.volume_num)
+    }
+
+    /// Returns a single sortable "makeability" score combining the market's liquidity, its
+    /// current [`Market::spread`] and its [`Market::rewards_max_spread`], or [`None`] if any of
+    /// these is missing or the max spread is not positive.
+    ///
+    /// The score is `liquidity * max(0, 1 - (spread / 2) / (rewards_max_spread / 100))`, where
+    /// `liquidity` is [`Market::liquidity_decimal`]. `rewards_max_spread` is in cents and bounds
+    /// each quote's distance from the midpoint, so the factor, clamped to `0..=1`, falls from 1
+    /// for a locked book to 0 once quoting at the touch no longer earns liquidity rewards. The raw
+    /// inputs, along with [`Market::competitive`], remain available to compute other scores.
+    #[must_use]
+    pub fn liquidity_score(&self) -> Option<Decimal> {
+        let liquidity = self.liquidity_decimal()?;
+        let spread = self.spread?;
+        let max_spread = self
+            .rewards_max_spread
+            .filter(|max_spread| *max_spread > Decimal::ZERO)?
+            / Decimal::ONE_HUNDRED;
+
+        // A spread too wide to divide without overflowing is far outside the reward band
+        let fit = (spread / Decimal::TWO)
+            .checked_div(max_spread)
+            .map_or(Decimal::ZERO, |ratio| {
+                (Decimal::ONE - ratio).clamp(Decimal::ZERO, Decimal::ONE)
+            });
+        Some(liquidity * fit)
+    }
+
+    /// Returns the market's fee as a [`Decimal`], parsed from the string [`Market::fee`] field.
+    /// Returns [`None`] if the field is absent or does not parse.
+    #[must_use]
+    pub fn fee_decimal(&self) -> Option<Decimal> {
+        parse_or(self.fee.as_deref(), None)
+    }
+
+    /// Returns [`Market::sports_market_type`] as a typed [`SportsMarketType`].
+    #[must_use]
+    pub fn sports_market_type_typed(&self) -> Option<SportsMarketType> {
+        self.sports_market_type
+            .as_deref()
+            .map(SportsMarketType::from)
+    }
+
+    /// Returns [`Market::line`] interpreted for this market's [`SportsMarketType`].
+    ///
+    /// Returns [`None`] for markets without a line, such as moneyline markets.
+    #[must_use]
+    pub fn sports_line(&self) -> Option<SportsLine> {
+        let line = self.line?;
+
+        match self.sports_market_type_typed()? {
+            SportsMarketType::Spread => Some(SportsLine::Spread(line)),
+            SportsMarketType::Total => Some(SportsLine::Total(line)),
+            _ => None,
+        }
+    }
+
+    /// Gathers the market's UMA resolution fields into a [`ResolutionInfo`], parsing the status,
+    /// dates, resolver address, bond and reward.
+    ///
+    /// The end date is taken from [`Market::uma_end_date`], or from the date-only
+    /// [`Market::uma_end_date_iso`] (at midnight UTC) when the former is absent or malformed.
+    /// Fields that do not parse are left as [`None`].
+    #[must_use]
+    pub fn resolution_info(&self) -> ResolutionInfo {
+        let history: Vec<ResolutionStatus> =
+            parse_array::<String>(self.uma_resolution_statuses.as_deref())
+                .into_iter()
+                .map(ResolutionStatus::from)
+                .collect();
+        let status = self
+            .uma_resolution_status
+            .as_deref()
+            .map(ResolutionStatus::from)
+            .or_else(|| history.last().cloned());
+        let end_date = self
+            .uma_end_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date.trim()).ok())
+            .map(|date| date.with_timezone(&Utc))
+            .or_else(|| {
+                let date = NaiveDate::parse_from_str(self.uma_end_date_iso.as_deref()?, "%Y-%m-%d");
+                Some(date.ok()?.and_time(NaiveTime::MIN).and_utc())
+            });
+
+        ResolutionInfo {
+            status,
+            history,
+            end_date,
+            resolved_by: self
+                .resolved_by
+                .as_deref()
+                .and_then(|address| address.trim().parse().ok()),
+            bond: parse_or(self.uma_bond.as_deref(), None),
+            reward: parse_or(self.uma_reward.as_deref(), None),
+        }
+    }
+}
+
+/// Parses the JSON-encoded array `value`, if present and well-formed.
+fn parse_array<T>(value: Option<&str>) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .and_then(|value| parse_json_string_array(value).ok())
+        .unwrap_or_default()
+}
+
+/// Parses `value` as a [`Decimal`], using `fallback` only when `value` is absent.
+fn parse_or(value: Option<&str>, fallback: Option<Decimal>) -> Option<Decimal> {
+    match value {
+        Some(value) => {
+            let value = value.trim();
+            value
+                .parse()
+                .or_else(|_| Decimal::from_scientific(value))
+                .ok()
+        }
+        None => fallback,
+    }
+}
+
 /// CLOB rewards configuration for a market.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -555,6 +795,33 @@ pub struct Series {
     pub requires_translation: Option<bool>,
 }
 
+impl Series {
+    /// Returns [`Series::recurrence`] as a typed [`Recurrence`].
+    #[must_use]
+    pub fn recurrence_typed(&self) -> Option<Recurrence> {
+        self.recurrence.as_deref().map(|value| {
+            Recurrence::try_from(value).unwrap_or_else(|_| Recurrence::Other(value.to_owned()))
+        })
+    }
+
+    /// Iterates over the series' [`Event`]s, yielding nothing if they were not included.
+    pub fn event_iter(&self) -> impl Iterator<Item = &Event> {
+        self.events.iter().flatten()
+    }
+
+    /// Returns the [`Market`]s of every event in the series, in event order.
+    #[must_use]
+    pub fn all_markets(&self) -> Vec<&Market> {
+        self.event_iter().flat_map(Event::market_iter).collect()
+    }
+
+    /// Returns the events that are active and not closed (see [`Event::is_open`]).
+    #[must_use]
+    pub fn active_events(&self) -> Vec<&Event> {
+        self.event_iter().filter(|event| event.is_open()).collect()
+    }
+}
+
 /// A comment position.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -564,6 +831,22 @@ pub struct CommentPosition {
     pub position_size: Option<String>,
 }
 
+impl CommentPosition {
+    /// Returns [`CommentPosition::position_size`] as a [`Decimal`], or [`None`] if the field is
+    /// absent or does not parse.
+    #[must_use]
+    pub fn position_size_decimal(&self) -> Option<Decimal> {
+        parse_or(self.position_size.as_deref(), None)
+    }
+
+    /// Returns [`CommentPosition::token_id`] as a [`U256`], or [`None`] if the field is absent
+    /// or does not parse.
+    #[must_use]
+    pub fn token_id_u256(&self) -> Option<U256> {
+        U256::from_str(self.token_id.as_deref()?.trim()).ok()
+    }
+}
+
 /// A comment profile.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -646,6 +929,38 @@ pub struct PublicProfile {
     pub verified_badge: Option<bool>,
 }
 
+/// Error body returned by the `/public-profile` endpoint in place of a [`PublicProfile`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PublicProfileError {
+    /// The class of error, e.g. `"not found error"`.
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub error: String,
+}
+
+impl fmt::Display for PublicProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Public profile {}: {}", self.error_type, self.error)
+    }
+}
+
+impl StdError for PublicProfileError {}
+
+impl From<PublicProfileError> for Error {
+    fn from(err: PublicProfileError) -> Self {
+        Error::with_source(Kind::Status, err)
+    }
+}
+
+/// Body of a `/public-profile` response, which is either a profile or an error.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum PublicProfileBody {
+    Error(PublicProfileError),
+    Profile(PublicProfile),
+}
+
 /// A search tag result.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
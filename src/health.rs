@@ -0,0 +1,225 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`HealthReport` reads better at use sites than `health::Report`"
+)]
+
+//! Readiness checks across the Polymarket services.
+//!
+//! [`Services`] holds references to the clients a program depends on, and
+//! [`Services::health_all`] checks all of them concurrently, each bounded by a short timeout.
+//!
+//! ```no_run
+//! use polymarket_client_sdk::clob;
+//! use polymarket_client_sdk::health::Services;
+//!
+//! # async fn example() -> anyhow::Result<()> {
+//! let clob = clob::Client::default();
+//! let ws = clob::ws::Client::default();
+//!
+//! let report = Services::new().clob(&clob).ws(&ws).health_all().await;
+//! anyhow::ensure!(report.is_healthy(), "Dependencies are down: {report:?}");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use futures::future::{BoxFuture, OptionFuture};
+use tokio::time::timeout;
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob;
+
+/// How long each service has to answer before it is reported as down.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The outcome of checking a single service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ServiceStatus {
+    /// The service answered successfully.
+    Up {
+        /// How long the service took to answer.
+        latency: Duration,
+    },
+    /// The service failed to answer, or answered with an error.
+    Down {
+        /// Why the check failed.
+        reason: String,
+        /// How long the check ran before failing.
+        latency: Duration,
+    },
+}
+
+impl ServiceStatus {
+    /// Returns whether the service answered successfully.
+    #[must_use]
+    pub const fn is_up(&self) -> bool {
+        matches!(self, Self::Up { .. })
+    }
+
+    /// Returns how long the check took, whatever its outcome.
+    #[must_use]
+    pub const fn latency(&self) -> Duration {
+        match self {
+            Self::Up { latency } | Self::Down { latency, .. } => *latency,
+        }
+    }
+}
+
+/// The status of each service, as returned by [`Services::health_all`].
+///
+/// A field is `None` when no client was configured for that service, or when the crate feature
+/// providing it is disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HealthReport {
+    /// The Gamma API, checked through its status endpoint.
+    pub gamma: Option<ServiceStatus>,
+    /// The Data API, checked through its health endpoint.
+    pub data_api: Option<ServiceStatus>,
+    /// The CLOB REST API, checked through its `ok` endpoint.
+    pub clob: Option<ServiceStatus>,
+    /// The CLOB WebSocket gateway, checked by completing a handshake.
+    pub ws: Option<ServiceStatus>,
+}
+
+impl HealthReport {
+    /// Returns whether every checked service is up.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        [&self.gamma, &self.data_api, &self.clob, &self.ws]
+            .into_iter()
+            .flatten()
+            .all(ServiceStatus::is_up)
+    }
+}
+
+/// The clients whose services [`Services::health_all`] checks. Services without a client are
+/// skipped.
+#[derive(Default)]
+pub struct Services<'client> {
+    gamma: Option<&'client dyn Probe>,
+    data_api: Option<&'client dyn Probe>,
+    clob: Option<&'client dyn Probe>,
+    ws: Option<&'client dyn Probe>,
+    timeout: Option<Duration>,
+}
+
+impl<'client> Services<'client> {
+    /// Creates an empty set of services, which checks nothing until clients are added.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks the Gamma API through `client`.
+    #[cfg(feature = "gamma")]
+    #[must_use]
+    pub fn gamma(mut self, client: &'client crate::gamma::Client) -> Self {
+        self.gamma = Some(client);
+        self
+    }
+
+    /// Checks the Data API through `client`.
+    #[cfg(feature = "data")]
+    #[must_use]
+    pub fn data_api(mut self, client: &'client crate::data::Client) -> Self {
+        self.data_api = Some(client);
+        self
+    }
+
+    /// Checks the CLOB REST API through `client`.
+    #[must_use]
+    pub fn clob<S: State + Send + Sync>(mut self, client: &'client clob::Client<S>) -> Self {
+        self.clob = Some(client);
+        self
+    }
+
+    /// Checks the CLOB WebSocket gateway with the settings of `client`.
+    #[must_use]
+    pub fn ws<S: State + Send + Sync>(mut self, client: &'client clob::ws::Client<S>) -> Self {
+        self.ws = Some(client);
+        self
+    }
+
+    /// Sets how long each service has to answer (default: [`DEFAULT_TIMEOUT`]).
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Checks every configured service concurrently, giving each the configured timeout to
+    /// answer.
+    pub async fn health_all(&self) -> HealthReport {
+        let limit = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        let run = |service: Option<&'client dyn Probe>| {
+            OptionFuture::from(service.map(|service| check(limit, service.ping())))
+        };
+
+        let (gamma, data_api, clob, ws) = futures::join!(
+            run(self.gamma),
+            run(self.data_api),
+            run(self.clob),
+            run(self.ws),
+        );
+
+        HealthReport {
+            gamma,
+            data_api,
+            clob,
+            ws,
+        }
+    }
+}
+
+/// A client that can check whether its service is reachable.
+trait Probe: Sync {
+    fn ping(&self) -> BoxFuture<'_, Result<()>>;
+}
+
+#[cfg(feature = "gamma")]
+impl Probe for crate::gamma::Client {
+    fn ping(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async { self.status().await.map(drop) })
+    }
+}
+
+#[cfg(feature = "data")]
+impl Probe for crate::data::Client {
+    fn ping(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async { self.health().await.map(drop) })
+    }
+}
+
+impl<S: State + Send + Sync> Probe for clob::Client<S> {
+    fn ping(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async { self.ok().await.map(drop) })
+    }
+}
+
+impl<S: State + Send + Sync> Probe for clob::ws::Client<S> {
+    fn ping(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(clob::ws::Client::probe(self))
+    }
+}
+
+async fn check(limit: Duration, probe: BoxFuture<'_, Result<()>>) -> ServiceStatus {
+    let started = Instant::now();
+    let outcome = timeout(limit, probe).await;
+    let latency = started.elapsed();
+
+    match outcome {
+        Ok(Ok(())) => ServiceStatus::Up { latency },
+        Ok(Err(e)) => ServiceStatus::Down {
+            reason: e.to_string(),
+            latency,
+        },
+        Err(_elapsed) => ServiceStatus::Down {
+            reason: format!("No response within {limit:?}"),
+            latency,
+        },
+    }
+}
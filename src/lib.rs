@@ -6,24 +6,36 @@ pub mod bridge;
 pub mod clob;
 #[cfg(feature = "data")]
 pub mod data;
+pub mod deadline;
 pub mod error;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(feature = "ws")]
+pub mod health;
+pub mod metrics;
+pub mod middleware;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
+#[cfg(feature = "strict")]
+pub mod strict;
 pub mod types;
 
 use std::fmt::Write as _;
+use std::time::{Duration, Instant};
 
 use alloy::primitives::ChainId;
+use bon::Builder;
+use bytes::{Bytes, BytesMut};
 use phf::phf_map;
-use reqwest::header::HeaderMap;
-use reqwest::{Request, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, Request, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
-use crate::error::Error;
+use crate::error::{DeadlineExceeded, Error, ResponseTooLarge, Status};
+use crate::metrics::MetricsObserver;
+use crate::middleware::Middleware;
 use crate::types::{Address, address};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -36,6 +48,24 @@ pub const AMOY: ChainId = 80002;
 
 pub const PRIVATE_KEY_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 
+/// Environment variable read by [`clob::Client::from_env`] for the CLOB host
+/// (default: `https://clob.polymarket.com`).
+pub const CLOB_HOST_VAR: &str = "POLYMARKET_CLOB_HOST";
+
+/// Environment variable read by [`data::Client::from_env`] for the Data API host
+/// (default: `https://data-api.polymarket.com`).
+#[cfg(feature = "data")]
+pub const DATA_HOST_VAR: &str = "POLYMARKET_DATA_HOST";
+
+/// Environment variable read by [`gamma::Client::from_env`] for the Gamma API host
+/// (default: `https://gamma-api.polymarket.com`).
+#[cfg(feature = "gamma")]
+pub const GAMMA_HOST_VAR: &str = "POLYMARKET_GAMMA_HOST";
+
+/// Environment variable read by [`clob::client::AuthenticationBuilder::funder_from_env`] for the
+/// proxy wallet that funds orders (default: unset, trading from the signer's own address).
+pub const PROXY_ADDRESS_VAR: &str = "POLYMARKET_PROXY_ADDRESS";
+
 /// Timestamp in seconds since [`std::time::UNIX_EPOCH`]
 pub(crate) type Timestamp = i64;
 
@@ -91,6 +121,62 @@ pub fn contract_config(chain_id: ChainId, is_neg_risk: bool) -> Option<&'static
     }
 }
 
+/// Maximum number of concurrent requests issued by helpers that fan out over several requests,
+/// such as [`clob::Client::reward_earnings_series`].
+pub(crate) const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// The default maximum number of idle connections kept open per host
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 8;
+
+/// The default time an idle connection is kept open
+const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The default interval of TCP keep-alive probes
+const DEFAULT_TCP_KEEPALIVE: Duration = Duration::from_secs(30);
+
+/// Connection pool and TCP keep-alive settings of an HTTP client.
+///
+/// Unset settings use the SDK defaults, which differ from reqwest's: at most 8 idle connections
+/// are kept per host (reqwest keeps any number), they are closed after 30 seconds (reqwest: 90
+/// seconds), and TCP keep-alive probes are sent every 30 seconds (reqwest sends none).
+///
+/// ```
+/// use polymarket_client_sdk::ConnectionConfig;
+///
+/// // Keep idle connections open until the server closes them, and send no keep-alive probes
+/// let connection = ConnectionConfig::builder()
+///     .pool_idle_timeout(None)
+///     .tcp_keepalive(None)
+///     .build();
+/// ```
+#[derive(Clone, Copy, Debug, Default, Builder)]
+pub struct ConnectionConfig {
+    /// Maximum number of idle connections kept open per host. Set it to 0 to disable connection
+    /// reuse.
+    pool_max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open before being closed, or `None` to keep it open
+    /// until the server closes it.
+    pool_idle_timeout: Option<Option<Duration>>,
+    /// Interval of TCP keep-alive probes on open connections, or `None` to send none.
+    tcp_keepalive: Option<Option<Duration>>,
+}
+
+impl ConnectionConfig {
+    /// Applies these settings, or the SDK defaults for unset ones, to `builder`.
+    pub(crate) fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        builder
+            .pool_max_idle_per_host(
+                self.pool_max_idle_per_host
+                    .unwrap_or(DEFAULT_POOL_MAX_IDLE_PER_HOST),
+            )
+            .pool_idle_timeout(
+                self.pool_idle_timeout
+                    .unwrap_or(Some(DEFAULT_POOL_IDLE_TIMEOUT)),
+            )
+            .tcp_keepalive(self.tcp_keepalive.unwrap_or(Some(DEFAULT_TCP_KEEPALIVE)))
+    }
+}
+
 /// Trait for converting request types to URL query parameters.
 ///
 /// This trait is automatically implemented for all types that implement [`Serialize`].
@@ -129,19 +215,41 @@ pub trait ToQueryParams: Serialize {
 
 impl<T: Serialize> ToQueryParams for T {}
 
+/// The HTTP client and per-client settings that [`request`] makes a request with.
+#[derive(Clone, Copy)]
+pub(crate) struct RequestContext<'client> {
+    pub(crate) client: &'client reqwest::Client,
+    /// Observer notified of the request and its response
+    pub(crate) metrics: Option<&'client dyn MetricsObserver>,
+    /// Whether the response is deserialized leniently
+    #[cfg(feature = "lenient")]
+    pub(crate) lenient: bool,
+    /// Maximum size of the response body to buffer, in bytes
+    pub(crate) max_response_bytes: Option<usize>,
+    /// Interceptors and observers the request goes through
+    pub(crate) middleware: &'client Middleware,
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(
         level = "debug",
-        skip(client, request, headers),
+        skip(context, request, headers),
         fields(method, path, status_code)
     )
 )]
 async fn request<Response: DeserializeOwned>(
-    client: &reqwest::Client,
+    context: RequestContext<'_>,
     mut request: Request,
     headers: Option<HeaderMap>,
 ) -> Result<Response> {
+    let RequestContext {
+        client,
+        metrics,
+        max_response_bytes,
+        middleware,
+        ..
+    } = context;
     let method = request.method().clone();
     let path = request.url().path().to_owned();
 
@@ -156,14 +264,73 @@ async fn request<Response: DeserializeOwned>(
         *request.headers_mut() = h;
     }
 
-    let response = client.execute(request).await?;
+    let deadline = deadline::current();
+    if let Some(deadline) = deadline {
+        let remaining = deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+            .ok_or_else(|| DeadlineExceeded {
+                method: method.clone(),
+                path: path.clone(),
+            })?;
+        let timeout = request
+            .timeout()
+            .map_or(remaining, |timeout| (*timeout).min(remaining));
+        *request.timeout_mut() = Some(timeout);
+    }
+
+    if let Some(metrics) = metrics {
+        metrics.on_request(&path);
+    }
+
+    let started = Instant::now();
+    let response = middleware.execute(client, request).await;
+
+    if let Some(metrics) = metrics {
+        let status = response
+            .as_ref()
+            .ok()
+            .map(|(_, response)| response.status());
+        metrics.on_response(&path, status, started.elapsed());
+    }
+
+    // The timeout covers reading the body as well, so both can run past the deadline
+    let exceeded = |e: Error| {
+        let timed_out = e
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_timeout);
+        if deadline.is_some() && timed_out {
+            DeadlineExceeded {
+                method: method.clone(),
+                path: path.clone(),
+            }
+            .into()
+        } else {
+            e
+        }
+    };
+    let (sent, response) = response.map_err(exceeded)?;
     let status_code = response.status();
+    let response_headers = response.headers().clone();
 
     #[cfg(feature = "tracing")]
     tracing::Span::current().record("status_code", status_code.as_u16());
 
+    let body = read_body(response, max_response_bytes, &method, &path)
+        .await
+        .map_err(exceeded);
+    if let Ok(body) = &body {
+        middleware.observe(sent.as_ref(), status_code, &response_headers, body);
+    }
+
     if !status_code.is_success() {
-        let message = response.text().await.unwrap_or_default();
+        let retry_after = response_headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok()?.trim().parse().ok())
+            .map(Duration::from_secs);
+        let message = body
+            .map(|body| String::from_utf8_lossy(&body).into_owned())
+            .unwrap_or_default();
 
         #[cfg(feature = "tracing")]
         tracing::warn!(
@@ -174,10 +341,26 @@ async fn request<Response: DeserializeOwned>(
             "API request failed"
         );
 
-        return Err(Error::status(status_code, method, path, message));
+        return Err(Status::new(status_code, method, path, message, retry_after).into());
     }
 
-    let json_value = response.json::<serde_json::Value>().await?;
+    let json_value: serde_json::Value = serde_json::from_slice(&body?)?;
+    // Lenient clients opt out of strictness
+    #[cfg(all(feature = "strict", feature = "lenient"))]
+    let deny_unknown = !context.lenient && strict::is_active();
+    #[cfg(all(feature = "strict", not(feature = "lenient")))]
+    let deny_unknown = strict::is_active();
+    #[cfg(feature = "strict")]
+    if deny_unknown {
+        strict::deny_unknown_fields::<Response>(&json_value, &method, &path)?;
+    }
+    #[cfg(feature = "lenient")]
+    let response_data: Option<Response> = if context.lenient {
+        serde_helpers::deserialize_lenient(json_value)?
+    } else {
+        serde_helpers::deserialize_with_warnings(json_value)?
+    };
+    #[cfg(not(feature = "lenient"))]
     let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)?;
 
     if let Some(response) = response_data {
@@ -194,6 +377,97 @@ async fn request<Response: DeserializeOwned>(
     }
 }
 
+/// Buffers the body of `response`, abandoning it as soon as it is known to exceed `limit` bytes
+/// (if any), from its `Content-Length` or from the chunks received so far.
+async fn read_body(
+    mut response: reqwest::Response,
+    limit: Option<usize>,
+    method: &Method,
+    path: &str,
+) -> Result<Bytes> {
+    let Some(limit) = limit else {
+        return Ok(response.bytes().await?);
+    };
+    let too_large = || ResponseTooLarge {
+        method: method.clone(),
+        path: path.to_owned(),
+        limit,
+    };
+
+    if let Some(length) = response.content_length()
+        && u64::try_from(limit).is_ok_and(|limit| length > limit)
+    {
+        return Err(too_large().into());
+    }
+
+    let mut body = BytesMut::new();
+    while let Some(chunk) = response.chunk().await? {
+        if chunk.len() > limit - body.len() {
+            return Err(too_large().into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body.freeze())
+}
+
+/// Reads the environment variable `var`, returning [`None`] when it is unset.
+pub(crate) fn env_var(var: &str) -> Result<Option<String>> {
+    match std::env::var(var) {
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(Error::validation(format!(
+            "Environment variable {var} is not valid Unicode"
+        ))),
+    }
+}
+
+/// Reads a host URL from the environment variable `var`, falling back to `default` when unset.
+pub(crate) fn host_from_env(var: &str, default: &str) -> Result<String> {
+    match env_var(var)? {
+        Some(host) => parse_host(var, host),
+        None => Ok(default.to_owned()),
+    }
+}
+
+/// Checks that `host`, read from the environment variable `var`, is an HTTP(S) URL.
+fn parse_host(var: &str, host: String) -> Result<String> {
+    let url = url::Url::parse(&host)
+        .map_err(|e| Error::validation(format!("Invalid {var} `{host}`: {e}")))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Error::validation(format!(
+            "Invalid {var} `{host}`: expected an http or https URL"
+        )));
+    }
+
+    Ok(host)
+}
+
+/// Returns a clone of a fake's canned `response`, or an error naming the `method` it was missing
+/// for. A `method` programmed to fail in `failures` returns the [`Status`] error the real client
+/// would for that status code instead.
+#[cfg(feature = "test-util")]
+fn canned<T: Clone>(
+    failures: &std::collections::HashMap<&'static str, StatusCode>,
+    response: Option<&T>,
+    method: &str,
+) -> Result<T> {
+    if let Some(&status_code) = failures.get(method) {
+        return Err(Status::new(
+            status_code,
+            Method::GET,
+            format!("/{method}"),
+            "canned failure".to_owned(),
+            None,
+        )
+        .into());
+    }
+
+    response
+        .cloned()
+        .ok_or_else(|| Error::validation(format!("No canned response for `{method}`")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +481,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn host_from_env_should_default_when_unset() {
+        let host = host_from_env("POLYMARKET_UNSET_TEST_HOST", "https://example.com").unwrap();
+        assert_eq!(host, "https://example.com");
+    }
+
+    #[test]
+    fn parse_host_should_accept_http_urls() {
+        for host in ["https://example.com", "http://localhost:8080"] {
+            assert_eq!(parse_host("HOST", host.to_owned()).unwrap(), host);
+        }
+    }
+
+    #[test]
+    fn parse_host_should_reject_non_http_urls() {
+        for host in ["example.com", "mailto:x", r"c:\foo", "ftp://example.com"] {
+            let err = parse_host("HOST", host.to_owned()).unwrap_err();
+            assert!(err.to_string().contains("Invalid HOST"), "{err}");
+        }
+    }
+
     #[test]
     fn config_contains_80002_neg() {
         let cfg = contract_config(AMOY, true).expect("missing config");
@@ -0,0 +1,60 @@
+//! Hooks for observing the SDK's network activity.
+//!
+//! Implement [`MetricsObserver`] to forward request counts, status codes, latencies, WebSocket
+//! heartbeat round trips, and reconnect attempts to a metrics backend such as Prometheus, then
+//! register it on a client:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! use polymarket_client_sdk::clob::{Client, Config};
+//! use polymarket_client_sdk::metrics::MetricsObserver;
+//! use reqwest::StatusCode;
+//!
+//! #[derive(Debug)]
+//! struct Recorder;
+//!
+//! impl MetricsObserver for Recorder {
+//!     fn on_response(&self, endpoint: &str, status: Option<StatusCode>, elapsed: Duration) {
+//!         println!("{endpoint} -> {status:?} in {elapsed:?}");
+//!     }
+//! }
+//!
+//! let config = Config::builder().metrics(Arc::new(Recorder)).build();
+//! let client = Client::new("https://clob.polymarket.com", config)?;
+//! # Ok::<_, polymarket_client_sdk::error::Error>(())
+//! ```
+
+#![allow(
+    clippy::module_name_repetitions,
+    reason = "MetricsObserver is clearer than a bare Observer when imported"
+)]
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Receives callbacks as the SDK performs network operations.
+///
+/// Every method has a no-op default, so implementors only override the events they care about.
+/// Callbacks run inline on the request path and should return quickly.
+pub trait MetricsObserver: Debug + Send + Sync {
+    /// Called before an HTTP request to `endpoint` (the URL path, e.g. `/book`) is sent.
+    fn on_request(&self, _endpoint: &str) {}
+
+    /// Called once an HTTP request to `endpoint` completes.
+    ///
+    /// `status` is [`None`] when no response was received (e.g. connection or timeout errors).
+    /// `elapsed` measures the time until the response status was received.
+    fn on_response(&self, _endpoint: &str, _status: Option<StatusCode>, _elapsed: Duration) {}
+
+    /// Called before the WebSocket connection to `endpoint` is re-established, where `attempt` is
+    /// the number of consecutive failed attempts so far.
+    fn on_reconnect(&self, _endpoint: &str, _attempt: u32) {}
+
+    /// Called each time a WebSocket heartbeat to `endpoint` is answered, with the time between
+    /// sending the `PING` and receiving its `PONG`.
+    fn on_heartbeat(&self, _endpoint: &str, _round_trip: Duration) {}
+}
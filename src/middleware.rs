@@ -0,0 +1,166 @@
+//! Hooks for intercepting the SDK's raw HTTP traffic.
+//!
+//! Implement [`RequestInterceptor`] to inspect or rewrite every outgoing request (e.g. to inject
+//! tracing headers) or to answer it with a synthesized response, and [`ResponseObserver`] to see
+//! every request/response pair (e.g. for auditing or recording fixtures). Register them on a
+//! [`Middleware`], then attach it to a client:
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use async_trait::async_trait;
+//! use polymarket_client_sdk::clob::{Client, Config};
+//! use polymarket_client_sdk::middleware::{Intercepted, Middleware, RequestInterceptor};
+//! use reqwest::Request;
+//! use reqwest::header::HeaderValue;
+//!
+//! #[derive(Debug)]
+//! struct TraceId;
+//!
+//! #[async_trait]
+//! impl RequestInterceptor for TraceId {
+//!     async fn intercept(
+//!         &self,
+//!         mut request: Request,
+//!     ) -> polymarket_client_sdk::Result<Intercepted> {
+//!         request
+//!             .headers_mut()
+//!             .insert("x-trace-id", HeaderValue::from_static("abc123"));
+//!         Ok(Intercepted::Continue(request))
+//!     }
+//! }
+//!
+//! let middleware = Middleware::new().interceptor(Arc::new(TraceId));
+//! let config = Config::builder().middleware(middleware).build();
+//! let client = Client::new("https://clob.polymarket.com", config)?;
+//! # Ok::<_, polymarket_client_sdk::error::Error>(())
+//! ```
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Request, Response, StatusCode};
+
+use crate::Result;
+
+/// The outcome of a [`RequestInterceptor`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Intercepted {
+    /// Pass the (possibly modified) request on to the next interceptor, or send it if this was
+    /// the last one.
+    Continue(Request),
+    /// Skip the remaining interceptors and the network, and handle this response as if the
+    /// server had returned it.
+    Respond(Response),
+}
+
+/// Inspects, rewrites, or answers outgoing HTTP requests.
+///
+/// Interceptors see each request after the SDK has applied its own headers, including
+/// authentication headers, so headers added here are sent as-is.
+///
+/// Authenticated requests are already signed at that point: the L2 HMAC signature covers their
+/// method, path and body. An interceptor must not change the method, URL or body of a request
+/// carrying `POLY_SIGNATURE`, as the server would then reject it as wrongly signed. Adding
+/// headers, or answering the request with [`Intercepted::Respond`], is safe.
+#[async_trait]
+pub trait RequestInterceptor: Debug + Send + Sync {
+    /// Called with each outgoing request.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error fails the request without sending it.
+    async fn intercept(&self, request: Request) -> Result<Intercepted>;
+}
+
+/// Receives every request alongside the response it produced.
+///
+/// Observers run inline on the request path and should return quickly.
+pub trait ResponseObserver: Debug + Send + Sync {
+    /// Called once the body of the response to `request` has been read, whether or not `status`
+    /// indicates success.
+    ///
+    /// `request` is the request as it was sent (or as it was handed to the interceptor that
+    /// answered it). Responses whose body could not be read are not observed.
+    fn on_response(&self, request: &Request, status: StatusCode, headers: &HeaderMap, body: &[u8]);
+}
+
+/// An ordered set of [`RequestInterceptor`]s and [`ResponseObserver`]s applied to every HTTP
+/// request made by a client.
+///
+/// Interceptors run in the order they were added, each receiving the request returned by the
+/// previous one, until one of them returns [`Intercepted::Respond`]. Observers are likewise
+/// notified in the order they were added.
+#[derive(Clone, Debug, Default)]
+pub struct Middleware {
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    observers: Vec<Arc<dyn ResponseObserver>>,
+}
+
+impl Middleware {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `interceptor`, to run after any interceptors already added.
+    #[must_use]
+    pub fn interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Appends `observer`, to be notified after any observers already added.
+    #[must_use]
+    pub fn observer(mut self, observer: Arc<dyn ResponseObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Runs `request` through the interceptors and, unless one of them responds, sends it.
+    ///
+    /// Alongside the response, returns a copy of the request for the observers, or [`None`] if
+    /// there are no observers.
+    pub(crate) async fn execute(
+        &self,
+        client: &Client,
+        mut request: Request,
+    ) -> Result<(Option<Request>, Response)> {
+        for interceptor in &self.interceptors {
+            let snapshot = self.snapshot(&request);
+            match interceptor.intercept(request).await? {
+                Intercepted::Continue(next) => request = next,
+                Intercepted::Respond(response) => return Ok((snapshot, response)),
+            }
+        }
+
+        let snapshot = self.snapshot(&request);
+        Ok((snapshot, client.execute(request).await?))
+    }
+
+    /// Notifies the observers of a response to `request`.
+    pub(crate) fn observe(
+        &self,
+        request: Option<&Request>,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) {
+        if let Some(request) = request {
+            for observer in &self.observers {
+                observer.on_response(request, status, headers, body);
+            }
+        }
+    }
+
+    fn snapshot(&self, request: &Request) -> Option<Request> {
+        if self.observers.is_empty() {
+            None
+        } else {
+            request.try_clone()
+        }
+    }
+}
@@ -6,6 +6,8 @@
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
+use crate::types::Decimal;
+
 /// A `serde_as` type that deserializes strings or integers as `String`.
 ///
 /// Use with `#[serde_as(as = "StringFromAny")]` for `String` fields
@@ -72,6 +74,83 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// A `serde_as` type that deserializes strings or numbers as [`Decimal`] without losing
+/// precision.
+///
+/// Strings may use scientific notation (e.g. `"9.9964e6"`) and an empty string is read as zero,
+/// which the API sends for amounts that do not apply. Floats are converted through their shortest
+/// round-trip representation rather than their binary value, so `0.1` becomes exactly `0.1`.
+///
+/// Use with `#[serde_as(as = "DecimalFromAny")]`.
+pub struct DecimalFromAny;
+
+impl<'de> serde_with::DeserializeAs<'de, Decimal> for DecimalFromAny {
+    fn deserialize_as<D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use std::fmt;
+
+        use serde::de::{self, Visitor};
+
+        struct DecimalVisitor;
+
+        impl Visitor<'_> for DecimalVisitor {
+            type Value = Decimal;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("decimal string or number")
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let v = v.trim();
+                if v.is_empty() {
+                    return Ok(Decimal::ZERO);
+                }
+
+                Decimal::from_str_exact(v)
+                    .or_else(|_| Decimal::from_scientific(v))
+                    .map_err(|e| E::custom(format!("invalid decimal {v:?}: {e}")))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Decimal::from(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Decimal::from(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&v.to_string())
+            }
+        }
+
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+impl serde_with::SerializeAs<Decimal> for DecimalFromAny {
+    fn serialize_as<S>(source: &Decimal, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&source.to_string())
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -142,6 +221,78 @@ pub fn deserialize_with_warnings<T: DeserializeOwned>(value: Value) -> crate::Re
     Ok(serde_json::from_value(value)?)
 }
 
+/// Maximum number of fields [`deserialize_lenient`] drops from one response before giving up.
+/// Each drop deserializes the response again, so this bounds the work to a constant number of
+/// passes.
+#[cfg(feature = "lenient")]
+const MAX_DROPPED_FIELDS: usize = 16;
+
+/// Deserialize JSON, degrading optional fields that fail to deserialize instead of failing
+/// outright.
+///
+/// Whenever deserialization fails at an object field, the field is removed and deserialization
+/// is retried, so an `Option` field degrades to `None` and a `#[serde(default)]` field to its
+/// default. A required field that fails makes its enclosing object fail in turn, which is then
+/// removed the same way if it is itself an object field. Array elements are never removed, as
+/// that would silently drop e.g. an order or a position, so a failure reaching one fails the
+/// whole response, as do failures at the root, at locations that cannot be addressed in the JSON
+/// (e.g. inside flattened fields), and responses needing more than [`MAX_DROPPED_FIELDS`] drops.
+///
+/// Each dropped field is logged as a warning when the `tracing` feature is enabled, and unknown
+/// fields are reported as in [`deserialize_with_warnings`].
+#[cfg(feature = "lenient")]
+pub fn deserialize_lenient<T: DeserializeOwned>(mut value: Value) -> crate::Result<T> {
+    for _ in 0..MAX_DROPPED_FIELDS {
+        let Err(error) = serde_path_to_error::deserialize::<_, T>(&value) else {
+            return deserialize_with_warnings(value);
+        };
+        if !remove_field(&mut value, error.path()) {
+            return Err(error.into_inner().into());
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            type_name = %std::any::type_name::<T>(),
+            field = %error.path(),
+            error = %error.inner(),
+            "dropping field that failed to deserialize"
+        );
+    }
+
+    deserialize_with_warnings(value)
+}
+
+/// Remove the object field at `path` from `value`, returning whether anything was removed.
+///
+/// Array elements are never removed, as dropping them would silently shorten the list.
+#[cfg(feature = "lenient")]
+fn remove_field(value: &mut Value, path: &serde_path_to_error::Path) -> bool {
+    use serde_path_to_error::Segment;
+
+    let mut segments: Vec<_> = path.iter().collect();
+    let Some(Segment::Map { key }) = segments.pop() else {
+        return false;
+    };
+
+    let mut current = value;
+    for segment in segments {
+        let next = match (segment, current) {
+            (Segment::Seq { index }, Value::Array(items)) => items.get_mut(*index),
+            (Segment::Map { key }, Value::Object(map)) => map.get_mut(key),
+            _ => None,
+        };
+        let Some(next) = next else {
+            return false;
+        };
+        current = next;
+    }
+
+    match current {
+        Value::Object(map) => map.remove(key).is_some(),
+        _ => false,
+    }
+}
+
 /// Look up a value in a JSON structure by dot-separated path.
 ///
 /// Handles paths from `serde_ignored` which use:
@@ -198,6 +349,46 @@ mod tests {
         optional_field: Option<i32>,
     }
 
+    #[serde_with::serde_as]
+    #[derive(Debug, Deserialize, serde::Serialize, PartialEq)]
+    struct Amount {
+        #[serde_as(as = "DecimalFromAny")]
+        amount: Decimal,
+    }
+
+    #[test]
+    fn decimal_from_any_should_accept_strings_and_numbers() {
+        let parse = |amount: Value| {
+            serde_json::from_value::<Amount>(serde_json::json!({ "amount": amount }))
+                .map(|parsed| parsed.amount)
+        };
+
+        let expected = Decimal::from(9_996_400);
+        assert_eq!(parse(serde_json::json!("9996400")).unwrap(), expected);
+        assert_eq!(parse(serde_json::json!(9_996_400)).unwrap(), expected);
+        assert_eq!(parse(serde_json::json!(9_996_400.0)).unwrap(), expected);
+        assert_eq!(parse(serde_json::json!("9.9964e6")).unwrap(), expected);
+        assert_eq!(
+            parse(serde_json::json!(9.9964)).unwrap(),
+            Decimal::new(99_964, 4)
+        );
+        assert_eq!(parse(serde_json::json!(0.1)).unwrap(), Decimal::new(1, 1));
+        assert_eq!(parse(serde_json::json!("")).unwrap(), Decimal::ZERO);
+        parse(serde_json::json!("abc")).unwrap_err();
+        parse(serde_json::json!(null)).unwrap_err();
+    }
+
+    #[test]
+    fn decimal_from_any_should_round_trip() {
+        let amount = Amount {
+            amount: Decimal::new(99_964, 4),
+        };
+
+        let json = serde_json::to_value(&amount).unwrap();
+        assert_eq!(json, serde_json::json!({ "amount": "9.9964" }));
+        assert_eq!(serde_json::from_value::<Amount>(json).unwrap(), amount);
+    }
+
     #[test]
     fn deserialize_known_fields_only() {
         let json = serde_json::json!({
@@ -242,6 +433,72 @@ mod tests {
         assert_eq!(result, vec![1, 2, 3]);
     }
 
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct LenientStruct {
+        known_field: String,
+        optional_field: Option<i32>,
+        inner: Option<InnerStruct>,
+        #[serde(default)]
+        values: Vec<i32>,
+    }
+
+    #[cfg(feature = "lenient")]
+    #[test]
+    fn deserialize_lenient_degrades_optional_fields() {
+        let json = serde_json::json!({
+            "known_field": "value",
+            "optional_field": "not a number",
+            "inner": { "value": null },
+            "values": "not a list"
+        });
+
+        let result: LenientStruct = deserialize_lenient(json).expect("deserialization failed");
+        assert_eq!(
+            result,
+            LenientStruct {
+                known_field: "value".to_owned(),
+                optional_field: None,
+                inner: None,
+                values: Vec::new(),
+            }
+        );
+    }
+
+    #[cfg(feature = "lenient")]
+    #[test]
+    fn deserialize_lenient_keeps_array_elements() {
+        let json = serde_json::json!({
+            "known_field": "value",
+            "values": [1, "two", 3]
+        });
+
+        let result: crate::Result<LenientStruct> = deserialize_lenient(json);
+        result.unwrap_err();
+    }
+
+    #[cfg(feature = "lenient")]
+    #[test]
+    fn deserialize_lenient_missing_required_field_fails() {
+        let json = serde_json::json!({
+            "optional_field": 42
+        });
+
+        let result: crate::Result<TestStruct> = deserialize_lenient(json);
+        result.unwrap_err();
+    }
+
+    #[cfg(feature = "lenient")]
+    #[test]
+    fn deserialize_lenient_invalid_required_field_fails() {
+        let json = serde_json::json!([
+            { "known_field": "first" },
+            { "known_field": 2 }
+        ]);
+
+        let result: crate::Result<Vec<LenientStruct>> = deserialize_lenient(json);
+        result.unwrap_err();
+    }
+
     #[derive(Debug, Deserialize, PartialEq)]
     struct NestedStruct {
         outer: String,
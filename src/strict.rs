@@ -0,0 +1,170 @@
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`with_strict` and `WithStrict` read better at use sites than `strict::with`"
+)]
+
+//! Strict deserialization, to detect API drift.
+//!
+//! Responses normally tolerate fields the SDK does not model, so that new fields added by
+//! Polymarket do not break existing programs. Wrap calls in [`with_strict`] to fail on them
+//! instead: every response deserialized while the wrapped future runs is rejected with
+//! [`UnknownFields`](crate::error::UnknownFields) if it carries a field its type does not model.
+//! Run contract tests against live responses this way to catch API changes early, while
+//! production code keeps the tolerant default.
+//!
+//! Clients with lenient deserialization enabled (with the `lenient` feature) are not affected.
+//!
+//! ```no_run
+//! use polymarket_client_sdk::clob::Client;
+//! use polymarket_client_sdk::clob::types::request::MidpointRequest;
+//! use polymarket_client_sdk::strict::with_strict;
+//!
+//! # async fn example() -> polymarket_client_sdk::Result<()> {
+//! let client = Client::default();
+//! let request = MidpointRequest::builder().token_id("1234").build();
+//!
+//! with_strict(client.midpoint(&request)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::any::type_name;
+use std::cell::Cell;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::Result;
+use crate::error::UnknownFields;
+
+thread_local! {
+    /// Whether a [`WithStrict`] future is currently being polled on this thread.
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `future` with strict deserialization applied to every SDK response it receives.
+///
+/// Like [`with_deadline`](crate::deadline::with_deadline), responses are only checked when the
+/// requests run as part of `future` itself, not when spawned onto other tasks.
+pub fn with_strict<F: Future>(future: F) -> WithStrict<F> {
+    WithStrict {
+        future: Box::pin(future),
+    }
+}
+
+/// Future returned by [`with_strict`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct WithStrict<F> {
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithStrict<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _restore = Restore(STRICT.replace(true));
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+/// Restores the enclosing strictness once the inner future has been polled, even if it panics.
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        STRICT.set(self.0);
+    }
+}
+
+/// Returns whether a [`with_strict`] future is being polled on this thread.
+pub(crate) fn is_active() -> bool {
+    STRICT.get()
+}
+
+/// Fails if `value` has fields that `T` does not model.
+///
+/// Values that do not deserialize into `T` at all pass, leaving the regular deserialization to
+/// report the error.
+pub(crate) fn deny_unknown_fields<T: DeserializeOwned>(
+    value: &Value,
+    method: &Method,
+    path: &str,
+) -> Result<()> {
+    let mut fields = Vec::new();
+    let deserialized = serde_ignored::deserialize::<_, _, Option<T>>(value, |field| {
+        // `serde_ignored` marks `Option` wrappers with `?`, which have no counterpart in JSON
+        let field = field.to_string();
+        let segments: Vec<_> = field
+            .split('.')
+            .filter(|segment| !segment.is_empty() && *segment != "?")
+            .collect();
+        fields.push(segments.join("."));
+    });
+
+    if deserialized.is_err() || fields.is_empty() {
+        return Ok(());
+    }
+
+    Err(UnknownFields {
+        method: method.clone(),
+        path: path.to_owned(),
+        type_name: type_name::<T>(),
+        fields,
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Outer {
+        known: String,
+        inner: Option<Inner>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Inner {
+        value: i32,
+    }
+
+    #[test]
+    fn deny_unknown_fields_should_report_nested_fields() {
+        let json = serde_json::json!({
+            "known": "a",
+            "extra": 1,
+            "inner": { "value": 1, "surprise": true }
+        });
+
+        let err = deny_unknown_fields::<Outer>(&json, &Method::GET, "/outer").unwrap_err();
+        let unknown = err.downcast_ref::<UnknownFields>().unwrap();
+
+        assert_eq!(unknown.path, "/outer");
+        assert_eq!(unknown.fields, vec!["extra", "inner.surprise"]);
+    }
+
+    #[test]
+    fn deny_unknown_fields_should_accept_modeled_or_invalid_values() {
+        let modeled = serde_json::json!({ "known": "a", "inner": { "value": 1 } });
+        let invalid = serde_json::json!({ "known": 1, "extra": 1 });
+
+        deny_unknown_fields::<Outer>(&modeled, &Method::GET, "/outer").unwrap();
+        deny_unknown_fields::<Outer>(&invalid, &Method::GET, "/outer").unwrap();
+    }
+
+    #[tokio::test]
+    async fn with_strict_should_only_apply_while_polled() {
+        let seen = with_strict(async { is_active() }).await;
+
+        assert!(seen);
+        assert!(!is_active());
+    }
+}
@@ -1,2 +1,90 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
 pub use alloy::primitives::{Address, address};
-pub use rust_decimal::Decimal;
+pub use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::Value;
+
+use crate::Result;
+use crate::error::Error;
+
+/// Parses a JSON array encoded as a string, as the Polymarket APIs return for fields such as
+/// `outcomes`, `outcomePrices` and `clobTokenIds` (e.g. `"[\"0.25\", \"0.75\"]"`), into its
+/// elements.
+///
+/// Each element may be a JSON string or number, and is parsed with [`FromStr`] after trimming
+/// surrounding whitespace.
+///
+/// ```
+/// use polymarket_client_sdk::types::{Decimal, parse_json_string_array};
+///
+/// let prices: Vec<Decimal> = parse_json_string_array(r#"["0.25", "0.75"]"#)?;
+/// assert_eq!(prices, [Decimal::new(25, 2), Decimal::new(75, 2)]);
+/// # Ok::<_, polymarket_client_sdk::error::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `value` is not a JSON array of strings and numbers, or if an element does
+/// not parse as `T`.
+pub fn parse_json_string_array<T>(value: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let elements: Vec<Value> = serde_json::from_str(value)
+        .map_err(|e| Error::validation(format!("Invalid JSON-encoded array `{value}`: {e}")))?;
+
+    elements
+        .into_iter()
+        .map(|element| {
+            let text = match &element {
+                Value::String(text) => text.trim().to_owned(),
+                Value::Number(number) => number.to_string(),
+                _ => {
+                    return Err(Error::validation(format!(
+                        "Invalid element {element} in JSON-encoded array `{value}`"
+                    )));
+                }
+            };
+
+            text.parse().map_err(|e| {
+                Error::validation(format!(
+                    "Invalid element `{text}` in JSON-encoded array `{value}`: {e}"
+                ))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn parse_json_string_array_should_parse_strings_and_numbers() {
+        let prices: Vec<Decimal> = parse_json_string_array(r#" [ " 0.25", 0.75 ] "#).unwrap();
+        let outcomes: Vec<String> = parse_json_string_array(r#"["Yes","No"]"#).unwrap();
+
+        assert_eq!(prices, [dec!(0.25), dec!(0.75)]);
+        assert_eq!(outcomes, ["Yes", "No"]);
+    }
+
+    #[test]
+    fn parse_json_string_array_should_accept_empty_arrays() {
+        let empty: Vec<String> = parse_json_string_array("[]").unwrap();
+
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn parse_json_string_array_should_reject_malformed_input() {
+        parse_json_string_array::<String>("").unwrap_err();
+        parse_json_string_array::<String>(r#"["Yes", "No""#).unwrap_err();
+        parse_json_string_array::<String>(r#""Yes""#).unwrap_err();
+        parse_json_string_array::<String>(r#"[["Yes"]]"#).unwrap_err();
+        parse_json_string_array::<Decimal>(r#"["0.25", "abc"]"#).unwrap_err();
+    }
+}
@@ -26,6 +26,7 @@ use crate::common::{
 };
 
 mod unauthenticated {
+    use std::time::Duration;
 
     use chrono::{TimeDelta, TimeZone as _};
     use futures_util::future;
@@ -35,14 +36,14 @@ mod unauthenticated {
         PriceRequest, SpreadRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
-        FeeRateResponse, GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse,
-        MarketResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-        OrderBookSummaryResponse, OrderSummary, Page, PriceHistoryResponse, PricePoint,
-        PriceResponse, PricesResponse, Rewards, SimplifiedMarketResponse, SpreadResponse,
-        SpreadsResponse, TickSizeResponse, Token,
+        FeeRateResponse, FeeSchedule, GeoblockResponse, LastTradePriceResponse,
+        LastTradesPricesResponse, MarketParams, MarketResponse, MidpointResponse,
+        MidpointsResponse, NegRiskResponse, OrderBookSummaryResponse, OrderSummary, Page,
+        PriceHistoryResponse, PricePoint, PriceResponse, PricesResponse, Quote, Rewards,
+        SimplifiedMarketResponse, SpreadResponse, SpreadsResponse, TickSizeResponse, Token,
     };
     use polymarket_client_sdk::clob::types::{Interval, Side, TickSize, TimeRange};
-    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::error::{Status, Validation};
     use reqwest::Method;
 
     use super::*;
@@ -65,6 +66,59 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn client_with_pool_settings_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .pool_max_idle_per_host(1)
+            .pool_idle_timeout(Some(Duration::from_secs(5)))
+            .tcp_keepalive(Some(Duration::from_secs(10)))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        assert_eq!(client.ok().await?, "OK");
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn client_from_env_should_succeed() -> anyhow::Result<()> {
+        let client = Client::from_env()?;
+
+        assert!(client.host().as_str().starts_with("http"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_with_pool_settings_disabled_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .pool_max_idle_per_host(0)
+            .pool_idle_timeout(None)
+            .tcp_keepalive(None)
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        assert_eq!(client.ok().await?, "OK");
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn server_time_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -209,6 +263,55 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn quotes_should_fetch_both_sides_in_one_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/prices")
+                .json_body(json!([
+                    { "token_id": "1", "side": "BUY" },
+                    { "token_id": "1", "side": "SELL" },
+                    { "token_id": "2", "side": "BUY" },
+                    { "token_id": "2", "side": "SELL" }
+                ]));
+            then.status(StatusCode::OK).json_body(json!({
+                "1": { "BUY": 0.48, "SELL": 0.52 },
+                "2": { "BUY": 0.1 }
+            }));
+        });
+
+        let quotes = client.quotes(&["1".to_owned(), "2".to_owned()]).await?;
+
+        assert_eq!(quotes["1"], Quote::new(Some(dec!(0.48)), Some(dec!(0.52))));
+        assert_eq!(quotes["1"].mid, Some(dec!(0.5)));
+        assert_eq!(quotes["1"].spread, Some(dec!(0.04)));
+        assert_eq!(quotes["2"].bid, Some(dec!(0.1)));
+        assert_eq!(quotes["2"].ask, None);
+        assert_eq!(quotes["2"].mid, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn quote_should_default_for_unpriced_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/prices");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        assert_eq!(client.quote("1").await?, Quote::default());
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn all_prices_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -518,6 +621,380 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn book_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", "100");
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0xaabbcc",
+                "asset_id": "100",
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789",
+                "bids": [
+                    { "price": "0.3", "size": "150" },
+                    { "price": "0.4", "size": "100" }
+                ],
+                "asks": [
+                    { "price": "0.7", "size": "25.5" },
+                    { "price": "0.6", "size": "100" }
+                ]
+            }));
+        });
+
+        let book = client.book(U256::from(100)).await?;
+
+        assert_eq!(book.token_id, U256::from(100));
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.asks.get(&dec!(0.7)), Some(&dec!(25.5)));
+        assert_eq!(book.best_bid(), Some((dec!(0.4), Decimal::ONE_HUNDRED)));
+        assert_eq!(book.best_ask(), Some((dec!(0.6), Decimal::ONE_HUNDRED)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn books_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/books")
+                .json_body(json!([{ "token_id": "1" }, { "token_id": "2" }]));
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "market": "market",
+                    "asset_id": "1",
+                    "tick_size": TickSize::Hundredth.as_decimal(),
+                    "min_order_size": "5",
+                    "neg_risk": false,
+                    "timestamp": "1",
+                    "asks": [{ "price": "0.5", "size": "10" }]
+                },
+                {
+                    "market": "market",
+                    "asset_id": "2",
+                    "tick_size": TickSize::Hundredth.as_decimal(),
+                    "min_order_size": "5",
+                    "neg_risk": false,
+                    "timestamp": "1",
+                    "bids": [{ "price": "0.5", "size": "10" }]
+                }
+            ]));
+        });
+
+        let books = client.books(&[U256::from(1), U256::from(2)]).await?;
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].token_id, U256::ONE);
+        assert_eq!(books[0].best_ask(), Some((dec!(0.5), dec!(10))));
+        assert_eq!(books[1].best_bid(), Some((dec!(0.5), dec!(10))));
+        assert!(books[1].asks.is_empty());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_params_should_format_prices_and_sizes() {
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .fee_bps(0)
+            .build();
+
+        assert_eq!(params.price_decimals(), 2);
+        assert_eq!(params.size_decimals(), 6);
+        assert_eq!(params.format_price(dec!(0.5)), "0.50");
+        assert_eq!(params.format_price(dec!(0.456)), "0.46");
+        assert_eq!(params.format_size(dec!(21.04)), "21.040000");
+    }
+
+    #[tokio::test]
+    async fn market_params_should_cache_until_ttl_expires() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let book_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0xabc",
+                "asset_id": "1",
+                "tick_size": TickSize::Thousandth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": true,
+                "timestamp": "1"
+            }));
+        });
+        let fee_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/fee-rate")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 25 }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let params = client.market_params("1").await?;
+        assert_eq!(params.tick_size, TickSize::Thousandth);
+        assert_eq!(params.min_order_size, dec!(5));
+        assert!(params.neg_risk);
+        assert_eq!(params.fee_bps, 25);
+
+        assert_eq!(client.market_params("1").await?, params);
+        assert!(client.neg_risk("1").await?.neg_risk);
+        book_mock.assert_hits(1);
+        fee_mock.assert_hits(1);
+
+        let config = Config::builder().market_params_ttl(Duration::ZERO).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        client.market_params("1").await?;
+        client.market_params("1").await?;
+        book_mock.assert_hits(3);
+        fee_mock.assert_hits(3);
+
+        Ok(())
+    }
+
+    pub(super) fn binary_market_json(condition_id: &str) -> serde_json::Value {
+        json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": null,
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": condition_id,
+            "question_id": "q",
+            "question": "Will it happen?",
+            "description": "",
+            "market_slug": "will-it-happen",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "maker_base_fee": "0",
+            "taker_base_fee": "0",
+            "notifications_enabled": false,
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+            "icon": "",
+            "image": "",
+            "rewards": {
+                "rates": null,
+                "min_size": "0",
+                "max_spread": "0"
+            },
+            "is_50_50_outcome": false,
+            "tokens": [
+                { "token_id": "100", "outcome": "Yes", "price": "0.55" },
+                { "token_id": "200", "outcome": "No", "price": "0.45" }
+            ],
+            "tags": []
+        })
+    }
+
+    #[tokio::test]
+    async fn book_by_condition_should_resolve_and_cache_token_id() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json("0xabc"));
+        });
+        let book_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", "200");
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0xabc",
+                "asset_id": "200",
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1",
+                "bids": [{ "price": "0.44", "size": "10" }]
+            }));
+        });
+
+        let book = client.book_by_condition("0xabc", 1).await?;
+        assert_eq!(book.token_id, U256::from(200));
+        assert_eq!(book.best_bid(), Some((dec!(0.44), dec!(10))));
+
+        let token_id = client.token_id_by_condition("0xabc", 0).await?;
+        assert_eq!(token_id, U256::from(100));
+
+        market_mock.assert_hits(1);
+        book_mock.assert();
+
+        client.invalidate_internal_caches();
+        client.token_id_by_condition("0xabc", 0).await?;
+        market_mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn book_by_condition_with_out_of_range_outcome_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json("0xabc"));
+        });
+
+        let err = client.book_by_condition("0xabc", 2).await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Outcome index 2 is out of range for condition 0xabc with 2 outcomes"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fee_schedule_should_succeed_and_cache() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mut market = binary_market_json("0xabc");
+        market["maker_base_fee"] = json!(0);
+        market["taker_base_fee"] = json!(1000);
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK).json_body(market);
+        });
+
+        let schedule = client.fee_schedule("0xabc").await?;
+        assert_eq!(
+            schedule,
+            FeeSchedule::builder().maker_bps(0).taker_bps(1000).build()
+        );
+
+        client.fee_schedule("0xabc").await?;
+        mock.assert_hits(1);
+
+        client.invalidate_internal_caches();
+        client.fee_schedule("0xabc").await?;
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fee_schedule_with_fractional_fee_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mut market = binary_market_json("0xabc");
+        market["taker_base_fee"] = json!("2.5");
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK).json_body(market);
+        });
+
+        let err = client.fee_schedule("0xabc").await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Invalid taker fee 2.5 for condition 0xabc");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn complete_set_price_and_arbitrage_gap_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json("0xabc"));
+        });
+        let midpoints_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/midpoints")
+                .json_body(json!([{ "token_id": "100" }, { "token_id": "200" }]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "100": "0.55", "200": "0.47" }));
+        });
+
+        assert_eq!(client.complete_set_price("0xabc").await?, dec!(1.02));
+        assert_eq!(client.arbitrage_gap("0xabc").await?, dec!(-0.02));
+        market_mock.assert_hits(1);
+        midpoints_mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn complete_set_price_with_missing_midpoint_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json("0xabc"));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/midpoints");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "100": "0.55" }));
+        });
+
+        let err = client.complete_set_price("0xabc").await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Missing midpoint for token 200 of condition 0xabc");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn arbitrage_gap_without_outcome_tokens_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mut market = binary_market_json("0xabc");
+        market["tokens"] = json!([]);
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xabc");
+            then.status(StatusCode::OK).json_body(market);
+        });
+        let midpoints = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/midpoints");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let err = client.arbitrage_gap("0xabc").await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Condition 0xabc has no outcome tokens to price");
+        midpoints.assert_calls(0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_books_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1266,28 +1743,32 @@ mod unauthenticated {
 }
 
 mod authenticated {
-    use alloy::primitives::Signature;
+    use alloy::primitives::{B256, Signature};
     use alloy::signers::Signer as _;
     use alloy::signers::local::LocalSigner;
     use chrono::NaiveDate;
     use httpmock::Method::{DELETE, GET, POST};
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
-        OrdersRequest, TradesRequest, UserRewardsEarningRequest,
+        OrdersRequest, SplitAndSellRequest, TradesRequest, UserRewardsEarningRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
         ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
         CurrentRewardResponse, Earning, MakerOrder, MarketRewardResponse, MarketRewardsConfig,
         NotificationPayload, NotificationResponse, OpenOrderResponse, OrderScoringResponse, Page,
-        PostOrderResponse, RewardsConfig, Token, TotalUserEarningResponse, TradeResponse,
-        UserEarningResponse, UserRewardsEarningResponse,
+        PostOrderResponse, RewardEarningPoint, RewardsConfig, Token, TotalUserEarningResponse,
+        TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
     };
     use polymarket_client_sdk::clob::types::{
         AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
         TraderSide,
     };
+    use polymarket_client_sdk::error::{
+        Error, Kind, NoOpenOrders, RotationIncomplete, SplitUnsold, Validation,
+    };
     use polymarket_client_sdk::types::{Address, address};
 
+    use super::unauthenticated::binary_market_json;
     use super::*;
     use crate::common::{
         API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
@@ -1314,18 +1795,187 @@ mod authenticated {
         let expected = ApiKeysResponse::builder().keys(vec![API_KEY]).build();
 
         assert_eq!(response, expected);
+        assert_eq!(response.keys(), [API_KEY]);
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
+    async fn rotate_api_key_should_create_then_delete() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
 
-        let mock = server.mock(|when, then| {
-            when.method(DELETE)
+        let new_key = Uuid::max();
+        let create = server.mock(|when, then| {
+            when.method(POST)
+                .path("/auth/api-key")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_NONCE, "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": new_key.to_string(),
+                "passphrase": "new-passphrase",
+                "secret": SECRET
+            }));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::OK).body("\"\"");
+        });
+
+        let credentials = client.rotate_api_key(&signer, Some(1)).await?;
+
+        assert_eq!(credentials.key(), new_key);
+        create.assert();
+        delete.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_delete_new_key_when_old_cannot_be() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let new_key = Uuid::max();
+        server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": new_key.to_string(),
+                "passphrase": "new-passphrase",
+                "secret": SECRET
+            }));
+        });
+        let delete_old = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "unavailable" }));
+        });
+        let delete_new = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, new_key.to_string())
+                .header(POLY_PASSPHRASE, "new-passphrase");
+            then.status(StatusCode::OK).body("\"\"");
+        });
+
+        client.rotate_api_key(&signer, Some(1)).await.unwrap_err();
+
+        delete_old.assert();
+        delete_new.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_return_new_credentials_when_rollback_fails() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let new_key = Uuid::max();
+        server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": new_key.to_string(),
+                "passphrase": "new-passphrase",
+                "secret": SECRET
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(DELETE).path("/auth/api-key");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "unavailable" }));
+        });
+
+        let err = client.rotate_api_key(&signer, Some(1)).await.unwrap_err();
+        let incomplete = err.downcast_ref::<RotationIncomplete>().unwrap();
+
+        assert_eq!(incomplete.credentials.key(), new_key);
+        assert_eq!(err.kind(), Kind::Status);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_fail_for_another_signer() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK);
+        });
+
+        let err = client.rotate_api_key(&signer, Some(1)).await.unwrap_err();
+
+        assert!(err.downcast_ref::<Validation>().is_some());
+        create.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn l1_headers_should_sign_with_the_wallet() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let headers = client.l1_headers(&signer, None).await?;
+
+        assert_eq!(
+            headers[POLY_ADDRESS],
+            client.address().to_string().to_lowercase()
+        );
+        assert_eq!(headers[POLY_NONCE], "0");
+        assert_eq!(headers[POLY_SIGNATURE], SIGNATURE);
+        assert_eq!(headers[POLY_TIMESTAMP], TIMESTAMP);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn l2_headers_should_match_the_client_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let headers = client
+            .l2_headers(reqwest::Method::GET, "/auth/api-keys", None)
+            .await?;
+
+        assert_eq!(headers[POLY_API_KEY], API_KEY.to_string());
+        assert_eq!(headers[POLY_PASSPHRASE], PASSPHRASE);
+        assert_eq!(headers[POLY_TIMESTAMP], TIMESTAMP);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_SIGNATURE, headers[POLY_SIGNATURE].to_str().unwrap());
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        client.api_keys().await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
                 .path("/auth/api-key")
                 .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
                 .header(POLY_API_KEY, API_KEY)
@@ -1548,6 +2198,8 @@ mod authenticated {
             .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
             .status(OrderStatusType::Matched)
             .success(true)
+            .filled_size(Decimal::from(50))
+            .avg_fill_price(Decimal::from(2))
             .transaction_hashes(vec![
                 "0x2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6".to_owned(),
             ])
@@ -1559,6 +2211,84 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sign_with_should_match_sign() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "0", TickSize::Hundredth);
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let expected = client.sign(&signer, SignableOrder::default()).await?;
+        let signed_order = client
+            .sign_with(POLYGON, SignableOrder::default(), |hash| async move {
+                Ok(signer.sign_hash(&hash).await?)
+            })
+            .await?;
+
+        assert_eq!(signed_order, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_report_partial_fill() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "10.5",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "21.0000004"
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert_eq!(response.filled_size, Decimal::from(21));
+        assert_eq!(response.avg_fill_price, Some(dec!(0.5)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_accept_numeric_amounts() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": 4.99822,
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": 9_996_400
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert_eq!(response.making_amount, dec!(4.99822));
+        assert_eq!(response.taking_amount, dec!(9996400));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1769,101 +2499,503 @@ mod authenticated {
     }
 
     #[tokio::test]
-    async fn cancel_orders_should_succeed() -> anyhow::Result<()> {
+    async fn replace_order_should_post_once_canceled() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let mock = server.mock(|when, then| {
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let cancel = server.mock(|when, then| {
             when.method(DELETE)
-                .path("/orders")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .json_body(json!(["1"]));
+                .path("/order")
+                .json_body(json!({ "orderId": "1" }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1"], "notCanceled": {} }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
             then.status(StatusCode::OK).json_body(json!({
-                    "canceled": ["1"]
-                }
-            ));
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "2",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
         });
 
-        let response = client.cancel_orders(&["1"]).await?;
-
-        let expected = CancelOrdersResponse::builder()
-            .canceled(vec!["1".to_owned()])
-            .build();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.replace_order("1", signed_order).await?;
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert!(response.canceled);
+        assert_eq!(response.cancel.canceled, vec!["1".to_owned()]);
+        assert_eq!(
+            response.post.map(|post| post.order_id),
+            Some("2".to_owned())
+        );
+        cancel.assert();
+        post.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
+    async fn replace_order_should_not_post_when_not_canceled() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let mock = server.mock(|when, then| {
-            when.method(DELETE)
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .path("/cancel-all");
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let cancel = server.mock(|when, then| {
+            when.method(DELETE).path("/order");
             then.status(StatusCode::OK).json_body(json!({
-                    "canceled": ["2"],
-                    "notCanceled": {
-                        "1": "the order is already canceled"
-                    }
-                }
-            ));
+                "canceled": [],
+                "notCanceled": { "1": "order already matched" }
+            }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
         });
 
-        let response = client.cancel_all_orders().await?;
-
-        let expected = CancelOrdersResponse::builder()
-            .canceled(vec!["2".to_owned()])
-            .not_canceled(HashMap::from_iter([(
-                "1".to_owned(),
-                "the order is already canceled".to_owned(),
-            )]))
-            .build();
-
-        assert_eq!(response, expected);
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.replace_order("1", signed_order).await?;
 
-        mock.assert();
+        assert!(!response.canceled);
+        assert_eq!(response.post, None);
+        assert_eq!(
+            response.cancel.not_canceled.get("1").map(String::as_str),
+            Some("order already matched")
+        );
+        cancel.assert();
+        post.assert_calls(0);
 
         Ok(())
     }
 
+    fn open_order_json() -> serde_json::Value {
+        json!({
+            "id": "1",
+            "status": "LIVE",
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": "market_abc",
+            "asset_id": "1",
+            "side": "buy",
+            "original_size": "10",
+            "size_matched": "2.5",
+            "price": "0.45",
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+
     #[tokio::test]
-    async fn cancel_market_orders_should_succeed() -> anyhow::Result<()> {
+    async fn amend_order_size_should_replace_with_smaller_order() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let mock = server.mock(|when, then| {
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let order = server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(open_order_json());
+        });
+        let cancel = server.mock(|when, then| {
             when.method(DELETE)
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .path("/cancel-market-orders");
-            then.status(StatusCode::OK).json_body(json!({
-                "market": "m",
-                "asset_id": "a",
-            }));
+                .path("/order")
+                .json_body(json!({ "orderId": "1" }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1"], "notCanceled": {} }));
         });
-
-        let request = CancelMarketOrderRequest::builder()
-            .market("m")
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "2",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let response = client.amend_order_size(&signer, "1", dec!(5)).await?;
+
+        assert_eq!(response.order_id, "2");
+        order.assert();
+        cancel.assert();
+        post.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn amend_order_size_should_refuse_growing_the_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let order = server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(open_order_json());
+        });
+        let cancel = server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let err = client
+            .amend_order_size(&signer, "1", dec!(7.5))
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to amend order 1: New size 7.5 must be positive and below its remaining size 7.5"
+        );
+        order.assert();
+        cancel.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn amend_order_size_should_fail_when_not_canceled() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(open_order_json());
+        });
+        server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [],
+                "notCanceled": { "1": "order already matched" }
+            }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let err = client
+            .amend_order_size(&signer, "1", dec!(5))
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(msg, "Unable to amend order 1: order already matched");
+        post.assert_calls(0);
+
+        Ok(())
+    }
+
+    const CONDITION_ID: &str = "0x0101010101010101010101010101010101010101010101010101010101010101";
+
+    fn split_and_sell_request(max_slippage: Decimal) -> SplitAndSellRequest {
+        SplitAndSellRequest::builder()
+            .condition_id(CONDITION_ID)
+            .token_id("100")
+            .amount(dec!(10))
+            .max_slippage(max_slippage)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn split_and_sell_should_split_then_sell_the_leg() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "100", TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/markets/{CONDITION_ID}"));
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json(CONDITION_ID));
+        });
+        let midpoint = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", "100");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.55" }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "10",
+                "orderID": "1",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "5.4"
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let transaction_hash = B256::repeat_byte(2);
+        let response = client
+            .split_and_sell(&signer, &split_and_sell_request(dec!(0.015)), |split| {
+                assert_eq!(
+                    split.partition,
+                    vec![U256::from(1), U256::from(2)],
+                    "binary markets split into both outcomes"
+                );
+                assert_eq!(split.amount, U256::from(10_000_000), "amount in base units");
+                async move { Ok(transaction_hash) }
+            })
+            .await?;
+
+        assert_eq!(response.transaction_hash, transaction_hash);
+        assert_eq!(response.sale.order_id, "1");
+        midpoint.assert_calls(2);
+        post.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_and_sell_should_return_split_when_midpoint_moved() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/markets/{CONDITION_ID}"));
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json(CONDITION_ID));
+        });
+        let mut midpoint = server.mock(|when, then| {
+            when.method(GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.55" }));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let err = client
+            .split_and_sell(&signer, &split_and_sell_request(dec!(0.01)), |_| {
+                midpoint.delete();
+                server.mock(|when, then| {
+                    when.method(GET).path("/midpoint");
+                    then.status(StatusCode::OK)
+                        .json_body(json!({ "mid": "0.5" }));
+                });
+                async { Ok(B256::repeat_byte(2)) }
+            })
+            .await
+            .unwrap_err();
+        let unsold = err.downcast_ref::<SplitUnsold>().unwrap();
+
+        assert_eq!(unsold.transaction_hash, B256::repeat_byte(2));
+        assert_eq!(
+            unsold.error.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to sell 100: Midpoint moved to 0.5, below the minimum price 0.54"
+        );
+        post.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_and_sell_should_not_split_unknown_tokens() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/markets/{CONDITION_ID}"));
+            then.status(StatusCode::OK)
+                .json_body(binary_market_json(CONDITION_ID));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let request = SplitAndSellRequest::builder()
+            .condition_id(CONDITION_ID)
+            .token_id("300")
+            .amount(dec!(10))
+            .max_slippage(dec!(0.01))
+            .build();
+        let err = client
+            .split_and_sell(&signer, &request, |_| {
+                std::future::ready(Err(Error::validation("split must not be called")))
+            })
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            &format!(
+                "Unable to split and sell: Token 300 is not an outcome of market {CONDITION_ID}"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .json_body(json!(["1"]));
+            then.status(StatusCode::OK).json_body(json!({
+                    "canceled": ["1"]
+                }
+            ));
+        });
+
+        let response = client.cancel_orders(&["1"]).await?;
+
+        let expected = CancelOrdersResponse::builder()
+            .canceled(vec!["1".to_owned()])
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .path("/cancel-all");
+            then.status(StatusCode::OK).json_body(json!({
+                    "canceled": ["2"],
+                    "notCanceled": {
+                        "1": "the order is already canceled"
+                    }
+                }
+            ));
+        });
+
+        let response = client.cancel_all_orders().await?;
+
+        let expected = CancelOrdersResponse::builder()
+            .canceled(vec!["2".to_owned()])
+            .not_canceled(HashMap::from_iter([(
+                "1".to_owned(),
+                "the order is already canceled".to_owned(),
+            )]))
+            .build();
+
+        assert_eq!(response, expected);
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_market_orders_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .path("/cancel-market-orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1", "2"],
+                "not_canceled": {}
+            }));
+        });
+
+        let request = CancelMarketOrderRequest::builder()
+            .market("m")
             .asset_id("a")
             .build();
 
-        client.cancel_market_orders(&request).await?;
+        let response = client.cancel_market_orders(&request).await?;
 
+        assert_eq!(response.canceled, ["1", "2"]);
         mock.assert();
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_market_orders_without_open_orders_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        server.mock(|when, then| {
+            when.method(DELETE).path("/cancel-market-orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [],
+                "not_canceled": {}
+            }));
+        });
+
+        let request = CancelMarketOrderRequest::builder().market("m").build();
+        let err = client.cancel_market_orders(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::NoOpenOrders);
+        let no_open_orders = err.downcast_ref::<NoOpenOrders>().unwrap();
+        assert_eq!(no_open_orders.market.as_deref(), Some("m"));
+        assert_eq!(
+            no_open_orders.to_string(),
+            "no open orders to cancel in market m"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_market_orders_without_market_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/cancel-market-orders");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let err = client
+            .cancel_market_orders(&CancelMarketOrderRequest::default())
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to cancel market orders without a market or asset ID"
+        );
+        mock.assert_hits(0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn trades_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2313,41 +3445,100 @@ mod authenticated {
     }
 
     #[tokio::test]
-    async fn user_earnings_and_markets_config_should_succeed() -> anyhow::Result<()> {
+    async fn reward_earnings_series_should_sum_each_day_at_asset_rates() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let today = Utc::now();
-        let mock = server.mock(|when, then| {
+        let first = NaiveDate::from_ymd_opt(2025, 12, 8).unwrap();
+        let second = NaiveDate::from_ymd_opt(2025, 12, 9).unwrap();
+        let first_mock = server.mock(|when, then| {
             when.method(GET)
                 .path("/rewards/user/total")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .query_param("date", today.date_naive().to_string())
-                .query_param("order_by", "")
-                .query_param("position", "")
-                .query_param("no_competition", "false")
-                .query_param("signature_type", (SignatureType::Eoa as u8).to_string());
-            then.status(StatusCode::OK).json_body(json!(
-                [
-                    {
-                        "condition_id": "cond_123",
-                        "question": "Will BTC be above $50k on December 31, 2025?",
-                        "market_slug": "btc-above-50k-2025-12-31",
-                        "event_slug": "btc-above-50k-2025",
-                        "image": "https://example.com/markets/btc.png",
-                        "rewards_max_spread": "0.05",
-                        "rewards_min_size": "10.0",
-                        "market_competitiveness": "0.80",
-                        "tokens": [
-                            {
-                                "token_id": "YES_TOKEN",
-                                "outcome": "YES",
-                                "price": "0.55",
-                                "winner": true
-                            },
-                            {
+                .query_param("date", first.to_string());
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "date": "2025-12-08",
+                    "asset_address": "0x0000000000000000000000000000000000000001",
+                    "maker_address": "0x0000000000000000000000000000000000000002",
+                    "earnings": "1.5",
+                    "asset_rate": "1"
+                },
+                {
+                    "date": "2025-12-08",
+                    "asset_address": "0x0000000000000000000000000000000000000003",
+                    "maker_address": "0x0000000000000000000000000000000000000002",
+                    "earnings": "0.25",
+                    "asset_rate": "2"
+                }
+            ]));
+        });
+        let second_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/rewards/user/total")
+                .query_param("date", second.to_string());
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let series = client.reward_earnings_series(first, second).await?;
+
+        let expected = vec![
+            RewardEarningPoint::builder()
+                .date(first)
+                .value(dec!(2))
+                .build(),
+            RewardEarningPoint::builder()
+                .date(second)
+                .value(Decimal::ZERO)
+                .build(),
+        ];
+        assert_eq!(series, expected);
+        first_mock.assert();
+        second_mock.assert();
+
+        client
+            .reward_earnings_series(second, first)
+            .await
+            .unwrap_err();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_earnings_and_markets_config_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let today = Utc::now();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/rewards/user/total")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .query_param("date", today.date_naive().to_string())
+                .query_param("order_by", "")
+                .query_param("position", "")
+                .query_param("no_competition", "false")
+                .query_param("signature_type", (SignatureType::Eoa as u8).to_string());
+            then.status(StatusCode::OK).json_body(json!(
+                [
+                    {
+                        "condition_id": "cond_123",
+                        "question": "Will BTC be above $50k on December 31, 2025?",
+                        "market_slug": "btc-above-50k-2025-12-31",
+                        "event_slug": "btc-above-50k-2025",
+                        "image": "https://example.com/markets/btc.png",
+                        "rewards_max_spread": "0.05",
+                        "rewards_min_size": "10.0",
+                        "market_competitiveness": "0.80",
+                        "tokens": [
+                            {
+                                "token_id": "YES_TOKEN",
+                                "outcome": "YES",
+                                "price": "0.55",
+                                "winner": true
+                            },
+                            {
                                 "token_id": "NO_TOKEN",
                                 "outcome": "NO",
                                 "price": "0.45",
@@ -3001,3 +4192,579 @@ mod builder_authenticated {
         Ok(())
     }
 }
+
+mod metrics {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use polymarket_client_sdk::clob::types::request::MidpointRequest;
+    use polymarket_client_sdk::metrics::MetricsObserver;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        requests: Mutex<Vec<String>>,
+        responses: Mutex<Vec<(String, Option<StatusCode>)>>,
+    }
+
+    impl MetricsObserver for Recorder {
+        fn on_request(&self, endpoint: &str) {
+            self.requests.lock().unwrap().push(endpoint.to_owned());
+        }
+
+        fn on_response(&self, endpoint: &str, status: Option<StatusCode>, _elapsed: Duration) {
+            self.responses
+                .lock()
+                .unwrap()
+                .push((endpoint.to_owned(), status));
+        }
+    }
+
+    #[tokio::test]
+    async fn observer_should_see_requests_and_statuses() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let recorder = Arc::new(Recorder::default());
+        let config = Config::builder().metrics(Arc::clone(&recorder)).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", "2");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        client
+            .midpoint(&MidpointRequest::builder().token_id("1").build())
+            .await?;
+        client
+            .midpoint(&MidpointRequest::builder().token_id("2").build())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            *recorder.requests.lock().unwrap(),
+            vec!["/midpoint".to_owned(), "/midpoint".to_owned()]
+        );
+        assert_eq!(
+            *recorder.responses.lock().unwrap(),
+            vec![
+                ("/midpoint".to_owned(), Some(StatusCode::OK)),
+                (
+                    "/midpoint".to_owned(),
+                    Some(StatusCode::INTERNAL_SERVER_ERROR)
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
+
+mod middleware {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use polymarket_client_sdk::middleware::{
+        Intercepted, Middleware, RequestInterceptor, ResponseObserver,
+    };
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::{Request, Response};
+
+    use super::*;
+
+    /// Appends `name` to the `x-interceptors` header, recording the order interceptors ran in.
+    #[derive(Debug)]
+    struct Tag(&'static str);
+
+    #[async_trait]
+    impl RequestInterceptor for Tag {
+        async fn intercept(
+            &self,
+            mut request: Request,
+        ) -> polymarket_client_sdk::Result<Intercepted> {
+            let value = match request.headers().get("x-interceptors") {
+                Some(previous) => format!("{},{}", previous.to_str().unwrap_or_default(), self.0),
+                None => self.0.to_owned(),
+            };
+            request
+                .headers_mut()
+                .insert("x-interceptors", HeaderValue::from_str(&value).unwrap());
+            Ok(Intercepted::Continue(request))
+        }
+    }
+
+    /// Answers every request with a canned body instead of sending it.
+    #[derive(Debug)]
+    struct Canned(&'static str);
+
+    #[async_trait]
+    impl RequestInterceptor for Canned {
+        async fn intercept(&self, _request: Request) -> polymarket_client_sdk::Result<Intercepted> {
+            Ok(Intercepted::Respond(Response::from(http::Response::new(
+                self.0,
+            ))))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        exchanges: Mutex<Vec<(String, StatusCode, String)>>,
+    }
+
+    impl ResponseObserver for Recorder {
+        fn on_response(
+            &self,
+            request: &Request,
+            status: StatusCode,
+            _headers: &HeaderMap,
+            body: &[u8],
+        ) {
+            self.exchanges.lock().unwrap().push((
+                request.url().path().to_owned(),
+                status,
+                String::from_utf8_lossy(body).into_owned(),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn interceptors_should_run_in_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let middleware = Middleware::new()
+            .interceptor(Arc::new(Tag("first")))
+            .interceptor(Arc::new(Tag("second")));
+        let config = Config::builder().middleware(middleware).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("x-interceptors", "first,second");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let response = client.ok().await?;
+
+        assert_eq!(response, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn interceptor_should_short_circuit_with_synthesized_response() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let recorder = Arc::new(Recorder::default());
+        let middleware = Middleware::new()
+            .interceptor(Arc::new(Canned("\"synthesized\"")))
+            .interceptor(Arc::new(Tag("unreachable")))
+            .observer(Arc::clone(&recorder));
+        let config = Config::builder().middleware(middleware).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let response = client.ok().await?;
+
+        assert_eq!(response, "synthesized");
+        assert_eq!(
+            *recorder.exchanges.lock().unwrap(),
+            vec![("/".to_owned(), StatusCode::OK, "\"synthesized\"".to_owned())]
+        );
+        mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn observer_should_see_error_responses() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let recorder = Arc::new(Recorder::default());
+        let middleware = Middleware::new().observer(Arc::clone(&recorder));
+        let config = Config::builder().middleware(middleware).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body("upstream unavailable");
+        });
+
+        client.server_time().await.unwrap_err();
+
+        assert_eq!(
+            *recorder.exchanges.lock().unwrap(),
+            vec![(
+                "/time".to_owned(),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "upstream unavailable".to_owned()
+            )]
+        );
+
+        Ok(())
+    }
+}
+
+mod deadline {
+    use std::io::{Read as _, Write as _};
+    use std::time::{Duration, Instant};
+
+    use polymarket_client_sdk::clob::types::request::MidpointRequest;
+    use polymarket_client_sdk::deadline::with_deadline;
+    use polymarket_client_sdk::error::{DeadlineExceeded, Kind};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn passed_deadline_should_fail_without_sending() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        });
+
+        let request = MidpointRequest::builder().token_id("1").build();
+        let err = with_deadline(Instant::now(), client.midpoint(&request))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert_eq!(
+            err.downcast_ref::<DeadlineExceeded>().unwrap().path,
+            "/midpoint"
+        );
+        mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deadline_should_bound_slow_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .delay(Duration::from_secs(5))
+                .json_body(json!({ "mid": "0.5" }));
+        });
+
+        let request = MidpointRequest::builder().token_id("1").build();
+        let started = Instant::now();
+        let err = with_deadline(
+            Instant::now() + Duration::from_millis(200),
+            client.midpoint(&request),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deadline_should_bound_slow_response_bodies() -> anyhow::Result<()> {
+        // Answers with the headers right away but stalls before finishing the body
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let host = format!("http://{}", listener.local_addr()?);
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0_u8; 1024];
+            _ = stream.read(&mut request).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 13\r\n\r\n{\"mid\":")
+                .unwrap();
+            std::thread::sleep(Duration::from_secs(5));
+        });
+        let client = Client::new(&host, Config::default())?;
+
+        let request = MidpointRequest::builder().token_id("1").build();
+        let started = Instant::now();
+        let err = with_deadline(
+            Instant::now() + Duration::from_millis(200),
+            client.midpoint(&request),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Timeout);
+        assert_eq!(
+            err.downcast_ref::<DeadlineExceeded>().unwrap().path,
+            "/midpoint"
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_should_succeed_within_deadline() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        });
+
+        let request = MidpointRequest::builder().token_id("1").build();
+        let response = with_deadline(Instant::now() + Duration::from_secs(10), async {
+            client.midpoint(&request).await?;
+            client.midpoint(&request).await
+        })
+        .await?;
+
+        assert_eq!(response.mid, dec!(0.5));
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "quotes")]
+mod quotes {
+    use std::time::Duration;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use futures_util::StreamExt as _;
+    use httpmock::Method::{GET, POST};
+    use polymarket_client_sdk::clob::quotes::{DesiredQuote, QuoteRefresher, RefreshAction};
+    use polymarket_client_sdk::clob::types::{Side, TickSize};
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn refresher_should_replace_missing_quotes_only() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "2", TickSize::Hundredth);
+
+        let orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "id": "resting",
+                        "status": "LIVE",
+                        "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                        "maker_address": "0x2222222222222222222222222222222222222222",
+                        "market": "market_abc",
+                        "asset_id": "1",
+                        "side": "buy",
+                        "original_size": "10",
+                        "size_matched": "0",
+                        "price": "0.45",
+                        "outcome": "YES",
+                        "created_at": 1_705_322_096,
+                        "expiration": "0",
+                        "order_type": "GTC"
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+        let placed = server.mock(|when, then| {
+            when.method(GET).path("/data/order/placed");
+            then.status(StatusCode::OK)
+                .json_body(order_json("placed", "CANCELED", "2"));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "placed",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let resting = DesiredQuote::builder()
+            .token_id("1")
+            .side(Side::Buy)
+            .price(dec!(0.45))
+            .size(dec!(10))
+            .build();
+        let missing = DesiredQuote::builder()
+            .token_id("2")
+            .side(Side::Sell)
+            .price(dec!(0.55))
+            .size(dec!(10))
+            .build();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let refresher = QuoteRefresher::new(&client, &signer, vec![resting, missing.clone()])
+            .with_poll_interval(Duration::from_millis(10))
+            .with_min_refresh_interval(Duration::from_secs(60));
+        let actions: Vec<_> = timeout(
+            Duration::from_secs(5),
+            refresher.into_stream().take(2).collect::<Vec<_>>(),
+        )
+        .await?;
+
+        let RefreshAction::Placed {
+            quote,
+            previous_order_id,
+            response,
+        } = actions[0].as_ref().unwrap()
+        else {
+            panic!("expected a placement, got {:?}", actions[0]);
+        };
+        assert_eq!(*quote, missing);
+        assert_eq!(*previous_order_id, None);
+        assert_eq!(response.order_id, "placed");
+        assert_eq!(
+            *actions[1].as_ref().unwrap(),
+            RefreshAction::Throttled { quote: missing }
+        );
+        orders.assert_hits(2);
+        placed.assert_hits(1);
+        post.assert_hits(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresher_should_drop_filled_quotes() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "2", TickSize::Hundredth);
+
+        let orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 0,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+        let placed = server.mock(|when, then| {
+            when.method(GET).path("/data/order/placed");
+            then.status(StatusCode::OK)
+                .json_body(order_json("placed", "MATCHED", "2"));
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "placed",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let quote = DesiredQuote::builder()
+            .token_id("2")
+            .side(Side::Sell)
+            .price(dec!(0.55))
+            .size(dec!(10))
+            .build();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let refresher = QuoteRefresher::new(&client, &signer, vec![quote.clone()])
+            .with_poll_interval(Duration::from_millis(10))
+            .with_min_refresh_interval(Duration::ZERO);
+        let actions: Vec<_> = timeout(
+            Duration::from_secs(5),
+            refresher.into_stream().collect::<Vec<_>>(),
+        )
+        .await?;
+
+        assert_eq!(actions.len(), 2, "{actions:?}");
+        assert!(matches!(actions[0], Ok(RefreshAction::Placed { .. })));
+        assert_eq!(
+            *actions[1].as_ref().unwrap(),
+            RefreshAction::Filled {
+                quote,
+                order_id: "placed".to_owned()
+            }
+        );
+        orders.assert_hits(2);
+        placed.assert_hits(1);
+        post.assert_hits(1);
+
+        Ok(())
+    }
+
+    fn order_json(id: &str, status: &str, asset_id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "status": status,
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": "market_abc",
+            "asset_id": asset_id,
+            "side": "sell",
+            "original_size": "10",
+            "size_matched": "0",
+            "price": "0.55",
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+}
+
+#[cfg(feature = "strict")]
+mod strict {
+    use polymarket_client_sdk::clob::types::request::MidpointRequest;
+    use polymarket_client_sdk::error::{Kind, UnknownFields};
+    use polymarket_client_sdk::strict::with_strict;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn strict_should_reject_unknown_fields_only_when_wrapped() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5", "spread": "0.01" }));
+        });
+
+        let request = MidpointRequest::builder().token_id("1").build();
+        let tolerant = client.midpoint(&request).await?;
+        let err = with_strict(client.midpoint(&request)).await.unwrap_err();
+
+        assert_eq!(tolerant.mid, dec!(0.5));
+        assert_eq!(err.kind(), Kind::UnknownFields);
+        let unknown = err.downcast_ref::<UnknownFields>().unwrap();
+        assert_eq!(unknown.path, "/midpoint");
+        assert_eq!(unknown.fields, vec!["spread".to_owned()]);
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+}
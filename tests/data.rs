@@ -20,7 +20,10 @@ fn test_asset() -> String {
 }
 
 mod health {
+    use std::time::Duration;
+
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::ConnectionConfig;
     use polymarket_client_sdk::data::Client;
     use reqwest::StatusCode;
     use serde_json::json;
@@ -44,17 +47,85 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn health_with_connection_settings_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let connection = ConnectionConfig::builder()
+            .pool_max_idle_per_host(0)
+            .pool_idle_timeout(None)
+            .tcp_keepalive(Some(Duration::from_secs(10)))
+            .build();
+        let client = Client::new_with_connection(&server.base_url(), connection)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "OK"
+            }));
+        });
+
+        assert_eq!(client.health().await?.data, "OK");
+        assert_eq!(client.health().await?.data, "OK");
+        mock.assert_hits(2);
+
+        Ok(())
+    }
 }
 
 mod positions {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
+    use polymarket_client_sdk::data::types::response::Position;
+    use polymarket_client_sdk::data::types::{PositionSortBy, SortDirection};
+    use polymarket_client_sdk::data::{Client, diff_positions, types::request::PositionsRequest};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
 
     use super::{test_condition_id, test_user};
 
+    #[test]
+    fn position_amounts_should_accept_numbers_and_strings() -> anyhow::Result<()> {
+        let position = |amount: serde_json::Value| -> serde_json::Result<Position> {
+            serde_json::from_value(json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "asset": "0x1111",
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "size": amount,
+                "avgPrice": amount,
+                "initialValue": amount,
+                "currentValue": amount,
+                "cashPnl": amount,
+                "percentPnl": amount,
+                "totalBought": amount,
+                "realizedPnl": amount,
+                "percentRealizedPnl": amount,
+                "curPrice": amount,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "",
+                "slug": "",
+                "icon": "",
+                "eventSlug": "",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            }))
+        };
+
+        for amount in [json!(0.1), json!("0.1")] {
+            let position = position(amount)?;
+            assert_eq!(position.size, dec!(0.1));
+            assert_eq!(position.cash_pnl, dec!(0.1));
+            assert_eq!(position.cur_price, dec!(0.1));
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn positions_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -104,6 +175,7 @@ mod positions {
         assert_eq!(pos.proxy_wallet, test_user());
         assert_eq!(pos.condition_id.as_str(), test_condition_id().as_str());
         assert_eq!(pos.size, dec!(100.5));
+        assert_eq!(pos.on_chain_size(6)?, 100_500_000);
         assert_eq!(pos.title, "Will BTC hit $100k?");
         assert!(!pos.redeemable);
         mock.assert();
@@ -140,11 +212,148 @@ mod positions {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn positions_with_secondary_sort_should_break_ties() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let position = |title: &str| {
+            json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "size": 10.0,
+                "avgPrice": 0.5,
+                "initialValue": 5.0,
+                "currentValue": 5.0,
+                "cashPnl": 1.5,
+                "percentPnl": 30.0,
+                "totalBought": 10.0,
+                "realizedPnl": 0.0,
+                "percentRealizedPnl": 0.0,
+                "curPrice": 0.5,
+                "redeemable": false,
+                "mergeable": false,
+                "title": title,
+                "slug": "",
+                "icon": "",
+                "eventSlug": "",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            })
+        };
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("sortBy", "CASHPNL")
+                .query_param_missing("thenSortBy");
+            then.status(StatusCode::OK)
+                .json_body(json!([position("Beta"), position("Alpha")]));
+        });
+
+        let request = PositionsRequest::builder()
+            .user(test_user())
+            .sort_by(PositionSortBy::CashPnl)
+            .then_sort_by(vec![(PositionSortBy::Title, SortDirection::Asc)])
+            .build();
+
+        let response = client.positions(&request).await?;
+
+        let titles: Vec<_> = response.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, ["Alpha", "Beta"]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn positions_changes_should_diff_against_previous_snapshot() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let position = |asset: &str, size: f64, current_value: f64| {
+            json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "asset": asset,
+                "conditionId": "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+                "size": size,
+                "avgPrice": 0.5,
+                "initialValue": 5.0,
+                "currentValue": current_value,
+                "cashPnl": 0.0,
+                "percentPnl": 0.0,
+                "totalBought": 10.0,
+                "realizedPnl": 0.0,
+                "percentRealizedPnl": 0.0,
+                "curPrice": 0.5,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "",
+                "slug": "",
+                "icon": "",
+                "eventSlug": "",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            })
+        };
+        let previous: Vec<Position> = serde_json::from_value(json!([
+            position("1", 10.0, 5.0),
+            position("2", 10.0, 5.0),
+            position("3", 10.0, 5.0),
+            position("4", 10.0, 5.0),
+        ]))?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("sizeThreshold", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                position("5", 2.0, 1.0),
+                position("4", 10.0, 5.0),
+                position("3", 10.0, 6.0),
+                position("2", 15.0, 7.5),
+            ]));
+        });
+
+        let diff = client.positions_changes(test_user(), &previous).await?;
+
+        let assets = |positions: &[Position]| -> Vec<String> {
+            positions.iter().map(|held| held.asset.clone()).collect()
+        };
+        assert_eq!(assets(&diff.added), ["5"]);
+        assert_eq!(assets(&diff.removed), ["1"]);
+        assert_eq!(diff.changed.len(), 2);
+        assert_eq!(diff.changed[0].position.asset, "2");
+        assert_eq!(diff.changed[0].size_delta, dec!(5));
+        assert_eq!(diff.changed[0].value_delta, dec!(2.5));
+        assert_eq!(diff.changed[1].position.asset, "3");
+        assert_eq!(diff.changed[1].size_delta, dec!(0));
+        assert_eq!(diff.changed[1].value_delta, dec!(1));
+        assert!(!diff.is_empty());
+        assert!(diff_positions(&previous, &previous).is_empty());
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod trades {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::data::{Client, types::Side, types::request::TradesRequest};
+    use polymarket_client_sdk::data::{
+        Client,
+        types::request::TradesRequest,
+        types::{Side, TradeRole},
+    };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -197,6 +406,720 @@ mod trades {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn trades_with_role_should_split_maker_and_taker_fills() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let trade = |wallet: &str, side: &str| {
+            json!({
+                "proxyWallet": wallet,
+                "side": side,
+                "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "size": 10,
+                "price": 0.5,
+                "timestamp": 1_703_980_800,
+                "title": "Market Title",
+                "slug": "market-slug",
+                "icon": "",
+                "eventSlug": "event-slug",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+            })
+        };
+        let taker = trade("0x1234567890abcdef1234567890abcdef12345678", "BUY");
+        let maker = trade("0xabcdefabcdefabcdefabcdefabcdefabcdefabcd", "SELL");
+
+        let all = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("takerOnly", "false");
+            then.status(StatusCode::OK)
+                .json_body(json!([maker.clone(), taker.clone()]));
+        });
+        let taker_only = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("takerOnly", "true");
+            then.status(StatusCode::OK).json_body(json!([taker]));
+        });
+
+        let response = client.trades_with_role(&TradesRequest::default()).await?;
+
+        let roles: Vec<_> = response
+            .iter()
+            .map(|(trade, role)| (trade.side, *role))
+            .collect();
+        assert_eq!(
+            roles,
+            vec![
+                (Side::Sell, TradeRole::Maker),
+                (Side::Buy, TradeRole::Taker)
+            ]
+        );
+        assert_eq!(response[1].0.proxy_wallet, test_user());
+        all.assert();
+        taker_only.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trades_with_role_should_page_taker_fills_from_the_newest() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let trade = |timestamp: i64| {
+            json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "side": "BUY",
+                "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "size": 10,
+                "price": 0.5,
+                "timestamp": timestamp,
+                "title": "Market Title",
+                "slug": "market-slug",
+                "icon": "",
+                "eventSlug": "event-slug",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "transactionHash": format!("0x{timestamp:064x}")
+            })
+        };
+
+        let all = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("takerOnly", "false")
+                .query_param("limit", "1")
+                .query_param("offset", "1");
+            then.status(StatusCode::OK).json_body(json!([trade(100)]));
+        });
+        let taker_only = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("takerOnly", "true")
+                .query_param("limit", "10000")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade(200), trade(100), trade(50)]));
+        });
+
+        let request = TradesRequest::builder().limit(1)?.offset(1)?.build();
+        let response = client.trades_with_role(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].0.timestamp, 100);
+        assert_eq!(response[0].1, TradeRole::Taker);
+        all.assert();
+        taker_only.assert();
+
+        Ok(())
+    }
+}
+
+mod trade_poller {
+    use std::collections::BTreeSet;
+
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::{
+        Client, TradePoller, TradeWatermark, types::request::TradesRequest,
+    };
+    use reqwest::StatusCode;
+    use serde_json::{Value, json};
+
+    fn trade_json(timestamp: i64, transaction_hash: &str) -> Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "side": "BUY",
+            "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "size": 10,
+            "price": 0.5,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "",
+            "eventSlug": "event-slug",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": transaction_hash
+        })
+    }
+
+    fn hashes<const N: usize>(hashes: [&str; N]) -> BTreeSet<String> {
+        hashes.into_iter().map(str::to_owned).collect()
+    }
+
+    #[tokio::test]
+    async fn poll_should_only_return_new_trades() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let mut poller = TradePoller::new(client, TradesRequest::default());
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path("/trades");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade_json(200, "0xb"), trade_json(100, "0xa")]));
+        });
+
+        let trades = poller.poll().await?;
+        assert_eq!(trades.len(), 2);
+        assert_eq!(
+            poller.watermark(),
+            Some(&TradeWatermark::new(200, hashes(["0xb"])))
+        );
+        mock.delete();
+
+        // A second trade sharing the watermark's timestamp is still new
+        mock = server.mock(|when, then| {
+            when.method(GET).path("/trades");
+            then.status(StatusCode::OK).json_body(json!([
+                trade_json(200, "0xc"),
+                trade_json(200, "0xb"),
+                trade_json(100, "0xa")
+            ]));
+        });
+
+        let trades = poller.poll().await?;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].transaction_hash, "0xc");
+        assert_eq!(
+            poller.watermark(),
+            Some(&TradeWatermark::new(200, hashes(["0xb", "0xc"])))
+        );
+
+        let trades = poller.poll().await?;
+        assert!(trades.is_empty());
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn poll_with_watermark_should_resume() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let watermark: TradeWatermark =
+            serde_json::from_value(json!({ "timestamp": 200, "transaction_hashes": ["0xb"] }))?;
+        let mut poller =
+            TradePoller::new(client, TradesRequest::default()).with_watermark(watermark);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/trades");
+            then.status(StatusCode::OK).json_body(json!([
+                trade_json(300, "0xd"),
+                trade_json(200, "0xb"),
+                trade_json(100, "0xa")
+            ]));
+        });
+
+        let trades = poller.poll().await?;
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].transaction_hash, "0xd");
+        assert_eq!(
+            poller.watermark(),
+            Some(&TradeWatermark::new(300, hashes(["0xd"])))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod reconstruct_positions {
+    use polymarket_client_sdk::data::reconstruct_positions;
+    use polymarket_client_sdk::data::types::response::Trade;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::{TEST_ASSET_STR, test_condition_id};
+
+    const OTHER_ASSET: &str = "0x2222";
+
+    fn trade(
+        asset: &str,
+        side: &str,
+        size: f64,
+        price: f64,
+        timestamp: i64,
+    ) -> serde_json::Result<Trade> {
+        serde_json::from_value(json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "side": side,
+            "asset": asset,
+            "conditionId": test_condition_id(),
+            "size": size,
+            "price": price,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "",
+            "eventSlug": "event-slug",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": format!("0x{timestamp}")
+        }))
+    }
+
+    #[test]
+    fn should_track_net_size_and_average_entry() -> anyhow::Result<()> {
+        // Newest first, as returned by `/trades`
+        let trades = [
+            trade(OTHER_ASSET, "BUY", 10.0, 0.2, 5)?,
+            trade(TEST_ASSET_STR, "SELL", 50.0, 0.6, 3)?,
+            trade(TEST_ASSET_STR, "BUY", 100.0, 0.5, 2)?,
+            trade(TEST_ASSET_STR, "BUY", 100.0, 0.3, 1)?,
+        ];
+
+        let positions = reconstruct_positions(&trades);
+
+        assert_eq!(positions.len(), 2);
+        let position = &positions[TEST_ASSET_STR];
+        assert_eq!(position.condition_id, test_condition_id());
+        assert_eq!(position.size, dec!(150));
+        assert_eq!(position.avg_price, dec!(0.4));
+        assert_eq!(position.total_bought, dec!(200));
+        assert_eq!(position.realized_pnl, dec!(10));
+
+        let other = &positions[OTHER_ASSET];
+        assert_eq!(other.size, dec!(10));
+        assert_eq!(other.avg_price, dec!(0.2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn closed_position_should_reset_average_entry() -> anyhow::Result<()> {
+        let trades = [
+            trade(TEST_ASSET_STR, "BUY", 10.0, 0.4, 1)?,
+            trade(TEST_ASSET_STR, "SELL", 10.0, 0.1, 2)?,
+            trade(TEST_ASSET_STR, "SELL", 5.0, 0.2, 3)?,
+        ];
+
+        let position = &reconstruct_positions(&trades)[TEST_ASSET_STR];
+
+        assert_eq!(position.size, dec!(-5));
+        assert_eq!(position.avg_price, dec!(0));
+        assert_eq!(position.realized_pnl, dec!(-3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_trades_should_return_no_positions() {
+        assert!(reconstruct_positions(&[]).is_empty());
+    }
+}
+
+mod sort_positions {
+    use polymarket_client_sdk::data::types::response::{ClosedPosition, Position};
+    use polymarket_client_sdk::data::types::{ClosedPositionSortBy, PositionSortBy, SortDirection};
+    use polymarket_client_sdk::data::{
+        sort_closed_positions, sort_positions, sort_positions_by_keys,
+    };
+    use serde_json::json;
+
+    use super::{TEST_ASSET_STR, test_condition_id};
+
+    fn position(title: &str, size: f64, end_date: &str) -> serde_json::Result<Position> {
+        serde_json::from_value(json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "asset": TEST_ASSET_STR,
+            "conditionId": test_condition_id(),
+            "size": size,
+            "avgPrice": 0.5,
+            "initialValue": 1.0,
+            "currentValue": 1.0,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": size,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 0.5,
+            "redeemable": false,
+            "mergeable": false,
+            "title": title,
+            "slug": "",
+            "icon": "",
+            "eventSlug": "",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "",
+            "endDate": end_date,
+            "negativeRisk": false
+        }))
+    }
+
+    fn closed_position(title: &str, timestamp: i64) -> serde_json::Result<ClosedPosition> {
+        serde_json::from_value(json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "asset": TEST_ASSET_STR,
+            "conditionId": test_condition_id(),
+            "avgPrice": 0.5,
+            "totalBought": 10.0,
+            "realizedPnl": 1.0,
+            "curPrice": 1.0,
+            "timestamp": timestamp,
+            "title": title,
+            "slug": "",
+            "icon": "",
+            "eventSlug": "",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "",
+            "endDate": "2025-12-31"
+        }))
+    }
+
+    fn titles(positions: &[Position]) -> Vec<&str> {
+        positions.iter().map(|p| p.title.as_str()).collect()
+    }
+
+    #[test]
+    fn sorts_by_decimal_field_in_both_directions() -> anyhow::Result<()> {
+        let mut positions = vec![
+            position("b", 2.5, "2025-12-31")?,
+            position("a", 10.0, "2025-12-31")?,
+            position("c", 0.1, "2025-12-31")?,
+        ];
+
+        sort_positions(&mut positions, PositionSortBy::Tokens, SortDirection::Desc);
+        assert_eq!(titles(&positions), ["a", "b", "c"]);
+
+        sort_positions(&mut positions, PositionSortBy::Tokens, SortDirection::Asc);
+        assert_eq!(titles(&positions), ["c", "b", "a"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ties_keep_their_original_order() -> anyhow::Result<()> {
+        let mut positions = vec![
+            position("first", 1.0, "2025-12-31")?,
+            position("big", 5.0, "2025-12-31")?,
+            position("second", 1.0, "2025-12-31")?,
+        ];
+
+        sort_positions(&mut positions, PositionSortBy::Tokens, SortDirection::Desc);
+
+        assert_eq!(titles(&positions), ["big", "first", "second"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn later_keys_break_ties() -> anyhow::Result<()> {
+        let mut positions = vec![
+            position("b", 1.0, "2025-12-31")?,
+            position("big", 5.0, "2025-12-31")?,
+            position("a", 1.0, "2025-12-31")?,
+        ];
+
+        sort_positions_by_keys(
+            &mut positions,
+            &[
+                (PositionSortBy::Tokens, SortDirection::Desc),
+                (PositionSortBy::Title, SortDirection::Asc),
+            ],
+        );
+
+        assert_eq!(titles(&positions), ["big", "a", "b"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolving_places_missing_end_dates_last() -> anyhow::Result<()> {
+        let mut positions = vec![
+            position("none", 1.0, "")?,
+            position("late", 1.0, "2026-06-30")?,
+            position("early", 1.0, "2025-01-01T00:00:00Z")?,
+        ];
+
+        sort_positions(
+            &mut positions,
+            PositionSortBy::Resolving,
+            SortDirection::Asc,
+        );
+        assert_eq!(titles(&positions), ["early", "late", "none"]);
+
+        sort_positions(
+            &mut positions,
+            PositionSortBy::Resolving,
+            SortDirection::Desc,
+        );
+        assert_eq!(titles(&positions), ["late", "early", "none"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sorts_closed_positions_by_timestamp() -> anyhow::Result<()> {
+        let mut positions = vec![
+            closed_position("old", 1_000)?,
+            closed_position("new", 3_000)?,
+            closed_position("mid", 2_000)?,
+        ];
+
+        sort_closed_positions(
+            &mut positions,
+            ClosedPositionSortBy::Timestamp,
+            SortDirection::Desc,
+        );
+
+        let titles: Vec<_> = positions.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, ["new", "mid", "old"]);
+
+        Ok(())
+    }
+}
+
+mod redeemable_plan {
+    use alloy::primitives::{B256, U256};
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::types::response::Position;
+    use polymarket_client_sdk::data::{Client, net_redeem_payout, plan_redemptions};
+    use polymarket_client_sdk::types::{Address, address};
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::{Value, json};
+
+    use super::{TEST_CONDITION_ID_STR, test_user};
+
+    const OTHER_CONDITION_ID: &str =
+        "0x1111111111111111111111111111111111111111111111111111111111111111";
+    const USDC: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+
+    fn position_json(condition_id: &str, outcome_index: i32, negative_risk: bool) -> Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "asset": "0x2222",
+            "conditionId": condition_id,
+            "size": 10.0,
+            "avgPrice": 0.5,
+            "initialValue": 5.0,
+            "currentValue": 10.0,
+            "cashPnl": 5.0,
+            "percentPnl": 100.0,
+            "totalBought": 10.0,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 1.0,
+            "redeemable": true,
+            "mergeable": false,
+            "title": "Market Title",
+            "slug": "",
+            "icon": "",
+            "eventSlug": "",
+            "outcome": "Yes",
+            "outcomeIndex": outcome_index,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "",
+            "endDate": "2025-12-31",
+            "negativeRisk": negative_risk
+        })
+    }
+
+    fn position(
+        condition_id: &str,
+        outcome_index: i32,
+        negative_risk: bool,
+    ) -> serde_json::Result<Position> {
+        serde_json::from_value(position_json(condition_id, outcome_index, negative_risk))
+    }
+
+    #[test]
+    fn should_group_outcomes_by_condition() -> anyhow::Result<()> {
+        let positions = [
+            position(TEST_CONDITION_ID_STR, 1, false)?,
+            position(OTHER_CONDITION_ID, 0, false)?,
+            position(TEST_CONDITION_ID_STR, 0, false)?,
+            position(TEST_CONDITION_ID_STR, 1, false)?,
+        ];
+
+        let plan = plan_redemptions(&positions, USDC)?;
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].condition_id, OTHER_CONDITION_ID.parse::<B256>()?);
+        assert_eq!(plan[0].index_sets, [U256::from(1)]);
+        assert_eq!(plan[1].condition_id, TEST_CONDITION_ID_STR.parse::<B256>()?);
+        assert_eq!(plan[1].index_sets, [U256::from(1), U256::from(2)]);
+        assert_eq!(plan[1].collateral_token, USDC);
+        assert_eq!(plan[1].parent_collection_id, B256::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_skip_negative_risk_positions() -> anyhow::Result<()> {
+        let positions = [position(TEST_CONDITION_ID_STR, 0, true)?];
+
+        assert!(plan_redemptions(&positions, USDC)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn net_redeem_payout_should_charge_fee_on_negative_risk_only() -> anyhow::Result<()> {
+        let standard = position(TEST_CONDITION_ID_STR, 0, false)?;
+        let neg_risk = position(TEST_CONDITION_ID_STR, 0, true)?;
+
+        assert_eq!(net_redeem_payout(&standard, Some(250)), dec!(10));
+        assert_eq!(net_redeem_payout(&neg_risk, Some(250)), dec!(9.75));
+        assert_eq!(net_redeem_payout(&neg_risk, None), dec!(10));
+        assert_eq!(net_redeem_payout(&neg_risk, Some(20_000)), dec!(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_malformed_condition_id() -> anyhow::Result<()> {
+        let positions = [position("0x1234", 0, false)?];
+
+        plan_redemptions(&positions, USDC).unwrap_err();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_query_redeemable_positions() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("redeemable", "true")
+                .query_param("sizeThreshold", "0")
+                .query_param("limit", "500")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([position_json(
+                TEST_CONDITION_ID_STR,
+                0,
+                false
+            )]));
+        });
+
+        let plan = client.redeemable_plan(test_user()).await?;
+
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].collateral_token, USDC);
+        assert_eq!(plan[0].index_sets, [U256::from(1)]);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod volume_series {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::{
+        Client, MAX_VOLUME_POINTS, VolumeInterval, VolumePoint, volume_series,
+    };
+    use polymarket_client_sdk::types::Decimal;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::{Value, json};
+
+    use super::{TEST_ASSET_STR, TEST_CONDITION_ID_STR};
+
+    fn trade_json(size: f64, price: f64, timestamp: i64) -> Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "side": "BUY",
+            "asset": TEST_ASSET_STR,
+            "conditionId": TEST_CONDITION_ID_STR,
+            "size": size,
+            "price": price,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "",
+            "eventSlug": "event-slug",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": format!("0x{timestamp}")
+        })
+    }
+
+    fn volumes(points: &[VolumePoint]) -> Vec<(i64, Decimal)> {
+        points.iter().map(|point| (point.t, point.volume)).collect()
+    }
+
+    #[tokio::test]
+    async fn market_volume_series_should_bucket_trades() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("market", TEST_CONDITION_ID_STR)
+                .query_param("takerOnly", "true")
+                .query_param("limit", "10000")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                trade_json(10.0, 0.5, 7250),
+                trade_json(20.0, 0.25, 3700),
+                trade_json(100.0, 0.1, 3600),
+            ]));
+        });
+
+        let series = client
+            .market_volume_series(TEST_CONDITION_ID_STR, VolumeInterval::OneHour)
+            .await?;
+
+        assert_eq!(volumes(&series), vec![(3600, dec!(15)), (7200, dec!(5))]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn volume_series_should_fill_empty_buckets() -> anyhow::Result<()> {
+        let trades = [
+            serde_json::from_value(trade_json(4.0, 0.5, 250))?,
+            serde_json::from_value(trade_json(1.0, 0.5, 59))?,
+        ];
+
+        let series = volume_series(&trades, VolumeInterval::OneMinute)?;
+
+        assert_eq!(
+            volumes(&series),
+            vec![
+                (0, dec!(0.5)),
+                (60, dec!(0)),
+                (120, dec!(0)),
+                (180, dec!(0)),
+                (240, dec!(2)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn volume_series_should_reject_too_many_buckets() -> anyhow::Result<()> {
+        let span = i64::try_from(MAX_VOLUME_POINTS)? * 60;
+        let trades = [
+            serde_json::from_value(trade_json(1.0, 0.5, 0))?,
+            serde_json::from_value(trade_json(1.0, 0.5, span))?,
+        ];
+
+        volume_series(&trades, VolumeInterval::OneMinute).unwrap_err();
+        assert_eq!(volume_series(&trades, VolumeInterval::OneHour)?.len(), 1667);
+        assert!(volume_series(&[], VolumeInterval::OneMinute)?.is_empty());
+
+        Ok(())
+    }
 }
 
 mod activity {
@@ -268,6 +1191,112 @@ mod activity {
     }
 }
 
+mod pnl_report {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::Client;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::{Value, json};
+
+    use super::{TEST_CONDITION_ID_STR, test_condition_id, test_user};
+
+    const OTHER_CONDITION_ID: &str =
+        "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn activity(
+        timestamp: i64,
+        condition_id: &str,
+        kind: &str,
+        trade: Option<(&str, f64)>,
+        usdc_size: f64,
+    ) -> Value {
+        let mut activity = json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "timestamp": timestamp,
+            "conditionId": condition_id,
+            "type": kind,
+            "size": 0.0,
+            "usdcSize": usdc_size,
+            "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222",
+            "title": "Market"
+        });
+        if let Some((side, size)) = trade {
+            activity["side"] = json!(side);
+            activity["size"] = json!(size);
+            activity["asset"] = json!("0x3333");
+        }
+        activity
+    }
+
+    #[tokio::test]
+    async fn pnl_report_should_realize_against_average_cost() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/activity")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("type", "TRADE,REDEEM,REWARD")
+                .query_param("end", "550")
+                .query_param("limit", "500")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                activity(
+                    600,
+                    TEST_CONDITION_ID_STR,
+                    "TRADE",
+                    Some(("SELL", 10.0)),
+                    9.0
+                ),
+                activity(500, OTHER_CONDITION_ID, "REWARD", None, 2.5),
+                activity(400, TEST_CONDITION_ID_STR, "REDEEM", None, 150.0),
+                activity(
+                    300,
+                    TEST_CONDITION_ID_STR,
+                    "TRADE",
+                    Some(("SELL", 50.0)),
+                    35.0
+                ),
+                activity(
+                    200,
+                    TEST_CONDITION_ID_STR,
+                    "TRADE",
+                    Some(("BUY", 100.0)),
+                    60.0
+                ),
+                activity(
+                    100,
+                    TEST_CONDITION_ID_STR,
+                    "TRADE",
+                    Some(("BUY", 100.0)),
+                    40.0
+                ),
+            ]));
+        });
+
+        let report = client.pnl_report(test_user(), 250, 550).await?;
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].condition_id, OTHER_CONDITION_ID);
+        assert_eq!(report[0].realized_pnl, dec!(2.5));
+        assert_eq!(report[0].rewards, dec!(2.5));
+        assert_eq!(report[0].trades_count, 0);
+        // Selling 50 of 200 tokens costing 100 realizes 35 - 25, and redeeming the other 150
+        // realizes 150 - 75
+        assert_eq!(report[1].condition_id, test_condition_id());
+        assert_eq!(report[1].realized_pnl, dec!(85));
+        assert_eq!(report[1].rewards, dec!(0));
+        assert_eq!(report[1].trades_count, 1);
+        assert_eq!(report[1].title.as_deref(), Some("Market"));
+        mock.assert();
+
+        client.pnl_report(test_user(), 550, 250).await.unwrap_err();
+
+        Ok(())
+    }
+}
+
 mod holders {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::HoldersRequest};
@@ -275,7 +1304,7 @@ mod holders {
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::{address, test_asset, test_condition_id, test_user};
+    use super::{TEST_ASSET_STR, address, test_asset, test_condition_id, test_user};
 
     #[tokio::test]
     async fn holders_should_succeed() -> anyhow::Result<()> {
@@ -335,6 +1364,53 @@ mod holders {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn holders_with_min_amount_should_filter_exactly() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/holders")
+                .query_param("minBalance", "49999");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "token": TEST_ASSET_STR,
+                    "holders": [
+                        {
+                            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                            "asset": TEST_ASSET_STR,
+                            "amount": "50000.000001",
+                            "outcomeIndex": 0
+                        },
+                        {
+                            "proxyWallet": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                            "asset": TEST_ASSET_STR,
+                            "amount": 49999.99,
+                            "outcomeIndex": 0
+                        }
+                    ]
+                }
+            ]));
+        });
+
+        let request = HoldersRequest::builder()
+            .markets(vec![test_condition_id()])
+            .build();
+
+        let response = client
+            .holders_with_min_amount(&request, dec!(49999.999999))
+            .await?;
+
+        let holders = &response[0].holders;
+        assert_eq!(holders.len(), 1);
+        assert_eq!(holders[0].proxy_wallet, test_user());
+        assert_eq!(holders[0].on_chain_amount(6)?, 50_000_000_001);
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod value {
@@ -344,32 +1420,104 @@ mod value {
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::test_user;
+    use super::{TEST_CONDITION_ID_STR, test_condition_id, test_user};
+
+    #[tokio::test]
+    async fn value_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/value")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "user": "0x1234567890abcdef1234567890abcdef12345678",
+                    "value": 12345.67
+                }
+            ]));
+        });
+
+        let request = ValueRequest::builder().user(test_user()).build();
+
+        let response = client.value(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].user, test_user());
+        assert_eq!(response[0].value, dec!(12345.67));
+        mock.assert();
+
+        Ok(())
+    }
 
     #[tokio::test]
-    async fn value_should_succeed() -> anyhow::Result<()> {
+    async fn value_breakdown_should_sum_positions_per_market() -> anyhow::Result<()> {
+        const OTHER_CONDITION_ID: &str =
+            "0x2222222222222222222222222222222222222222222222222222222222222222";
+
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
+        let position = |condition_id: &str, outcome_index: i32, current_value: f64| {
+            json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "asset": "0x1111",
+                "conditionId": condition_id,
+                "size": 10.0,
+                "avgPrice": 0.5,
+                "initialValue": 5.0,
+                "currentValue": current_value,
+                "cashPnl": 0.0,
+                "percentPnl": 0.0,
+                "totalBought": 10.0,
+                "realizedPnl": 0.0,
+                "percentRealizedPnl": 0.0,
+                "curPrice": 0.5,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "",
+                "slug": "",
+                "icon": "",
+                "eventSlug": "",
+                "outcome": "Yes",
+                "outcomeIndex": outcome_index,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            })
+        };
         let mock = server.mock(|when, then| {
             when.method(GET)
-                .path("/value")
-                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678");
+                .path("/positions")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param(
+                    "market",
+                    format!("{TEST_CONDITION_ID_STR},{OTHER_CONDITION_ID}"),
+                )
+                .query_param("sizeThreshold", "0");
             then.status(StatusCode::OK).json_body(json!([
-                {
-                    "user": "0x1234567890abcdef1234567890abcdef12345678",
-                    "value": 12345.67
-                }
+                position(TEST_CONDITION_ID_STR, 0, 3.25),
+                position(OTHER_CONDITION_ID, 0, 10.0),
+                position(TEST_CONDITION_ID_STR, 1, 4.5),
             ]));
         });
 
-        let request = ValueRequest::builder().user(test_user()).build();
-
-        let response = client.value(&request).await?;
+        let breakdown = client
+            .value_breakdown(
+                test_user(),
+                &[test_condition_id(), OTHER_CONDITION_ID.to_owned()],
+            )
+            .await?;
 
-        assert_eq!(response.len(), 1);
-        assert_eq!(response[0].user, test_user());
-        assert_eq!(response[0].value, dec!(12345.67));
+        assert_eq!(
+            breakdown,
+            [
+                (OTHER_CONDITION_ID.to_owned(), dec!(10)),
+                (test_condition_id(), dec!(7.75)),
+            ]
+        );
         mock.assert();
 
         Ok(())
@@ -434,6 +1582,81 @@ mod closed_positions {
 
         Ok(())
     }
+
+    fn closed_position_json(timestamp: i64) -> serde_json::Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "avgPrice": 0.45,
+            "totalBought": 100.0,
+            "realizedPnl": 55.0,
+            "curPrice": 1.0,
+            "timestamp": timestamp,
+            "title": "Resolved Market",
+            "slug": "resolved-market",
+            "icon": "https://example.com/icon.png",
+            "eventSlug": "event-slug",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "endDate": "2025-12-31"
+        })
+    }
+
+    #[tokio::test]
+    async fn closed_positions_should_filter_by_time_range() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/closed-positions");
+            then.status(StatusCode::OK).json_body(json!([
+                closed_position_json(1_700_000_200),
+                closed_position_json(1_700_000_100),
+                closed_position_json(1_700_000_000),
+                closed_position_json(1_699_999_999),
+            ]));
+        });
+
+        let request = ClosedPositionsRequest::builder()
+            .user(test_user())
+            .start(1_700_000_000)
+            .end(1_700_000_100)
+            .build();
+
+        let response = client.closed_positions(&request).await?;
+
+        let timestamps: Vec<_> = response.iter().map(|p| p.timestamp).collect();
+        assert_eq!(timestamps, vec![1_700_000_100, 1_700_000_000]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn closed_positions_without_range_should_keep_all() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/closed-positions");
+            then.status(StatusCode::OK).json_body(json!([
+                closed_position_json(1_700_000_000),
+                closed_position_json(-1),
+            ]));
+        });
+
+        let request = ClosedPositionsRequest::builder().user(test_user()).build();
+        let response = client.closed_positions(&request).await?;
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response[1].timestamp, -1);
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod leaderboard {
@@ -441,6 +1664,7 @@ mod leaderboard {
     use polymarket_client_sdk::data::{
         Client,
         types::request::TraderLeaderboardRequest,
+        types::response::TraderLeaderboardEntry,
         types::{LeaderboardCategory, LeaderboardOrderBy, TimePeriod},
     };
     use reqwest::StatusCode;
@@ -496,6 +1720,42 @@ mod leaderboard {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn leaderboard_should_accept_numeric_ranks() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/leaderboard");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "rank": 10,
+                    "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                    "vol": 100.0,
+                    "pnl": 10.0
+                },
+                {
+                    "rank": "9",
+                    "proxyWallet": "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+                    "vol": 200.0,
+                    "pnl": 20.0
+                }
+            ]));
+        });
+
+        let mut response = client
+            .leaderboard(&TraderLeaderboardRequest::builder().build())
+            .await?;
+        response.sort_by_key(TraderLeaderboardEntry::rank_num);
+
+        assert_eq!(response[0].rank, "9");
+        assert_eq!(response[1].rank, "10");
+        assert_eq!(response[1].rank_num(), Some(10));
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn leaderboard_with_filters_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -848,9 +2108,11 @@ mod builder_volume {
 }
 
 mod error_handling {
+    use std::time::Duration;
+
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
-    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::error::{ApiError, Kind, ResponseTooLarge};
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -875,6 +2137,38 @@ mod error_handling {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(err.kind(), Kind::Status);
+        assert_eq!(
+            err.as_api_error(),
+            Some(&ApiError::Validation("Invalid user address".to_owned()))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_should_return_retry_after() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::TOO_MANY_REQUESTS)
+                .header("Retry-After", "30")
+                .body("Too Many Requests");
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = client.positions(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        assert_eq!(
+            err.as_api_error(),
+            Some(&ApiError::RateLimited {
+                retry_after: Some(Duration::from_secs(30))
+            })
+        );
         mock.assert();
 
         Ok(())
@@ -905,6 +2199,112 @@ mod error_handling {
         Ok(())
     }
 
+    #[cfg(feature = "lenient")]
+    #[tokio::test]
+    async fn lenient_client_should_degrade_malformed_optional_fields() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let strict = Client::new(&server.base_url())?;
+        let lenient = Client::new(&server.base_url())?.with_lenient(true);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                    "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                    "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                    "size": 100.5,
+                    "avgPrice": 0.65,
+                    "initialValue": 65.325,
+                    "currentValue": 70.35,
+                    "cashPnl": 5.025,
+                    "percentPnl": 7.69,
+                    "totalBought": 100.5,
+                    "realizedPnl": 0.0,
+                    "percentRealizedPnl": 0.0,
+                    "curPrice": 0.70,
+                    "redeemable": false,
+                    "mergeable": false,
+                    "title": "Will BTC hit $100k?",
+                    "slug": "btc-100k",
+                    "icon": "https://example.com/btc.png",
+                    "eventSlug": "crypto-prices",
+                    "outcome": "Yes",
+                    "outcomeIndex": 0,
+                    "oppositeOutcome": "No",
+                    "oppositeAsset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                    "endDate": "2025-12-31",
+                    "negativeRisk": false,
+                    "eventId": ["malformed"]
+                }
+            ]));
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = strict.positions(&request).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Internal);
+
+        let positions = lenient.positions(&request).await?;
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].title, "Will BTC hit $100k?");
+        assert_eq!(positions[0].event_id, None);
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "lenient")]
+    #[tokio::test]
+    async fn lenient_client_should_not_drop_malformed_items() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let lenient = Client::new(&server.base_url())?.with_lenient(true);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "proxyWallet": null,
+                    "title": "Malformed position"
+                }
+            ]));
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = lenient.positions(&request).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Internal);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_response_bytes_should_reject_larger_bodies() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let body = format!("{}[]", " ".repeat(98));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::OK).body(&body);
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let limited = Client::new(&server.base_url())?.with_max_response_bytes(64);
+        let err = limited.positions(&request).await.unwrap_err();
+        assert_eq!(err.kind(), Kind::ResponseTooLarge);
+        let too_large = err.downcast_ref::<ResponseTooLarge>().unwrap();
+        assert_eq!(too_large.path, "/positions");
+        assert_eq!(too_large.limit, 64);
+
+        let exact = Client::new(&server.base_url())?.with_max_response_bytes(body.len());
+        assert!(exact.positions(&request).await?.is_empty());
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn null_response_should_return_error() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -950,7 +2350,66 @@ mod client {
     }
 }
 
+mod scoped_client {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::Client;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::test_user;
+
+    #[tokio::test]
+    async fn scoped_client_should_target_its_user() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.for_user(test_user());
+
+        let value_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/value")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678");
+            then.status(StatusCode::OK).json_body(json!([
+                { "user": "0x1234567890abcdef1234567890abcdef12345678", "value": 12.5 }
+            ]));
+        });
+        let traded_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/traded")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678");
+            then.status(StatusCode::OK).json_body(json!({
+                "user": "0x1234567890abcdef1234567890abcdef12345678",
+                "traded": 3
+            }));
+        });
+        let trades_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("limit", "5");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        assert_eq!(client.user(), test_user());
+        assert_eq!(client.value(|_| {}).await?[0].value, dec!(12.5));
+        assert_eq!(client.traded().await?.traded, 3);
+        let trades = client
+            .trades(|request| {
+                request.user = None;
+                request.limit = Some(5);
+            })
+            .await?;
+        assert!(trades.is_empty());
+        value_mock.assert();
+        traded_mock.assert();
+        trades_mock.assert();
+
+        Ok(())
+    }
+}
+
 mod types {
+    use std::collections::HashMap;
+
     use polymarket_client_sdk::ToQueryParams as _;
     use polymarket_client_sdk::data::{
         types::request::{
@@ -958,10 +2417,12 @@ mod types {
             PositionsRequest, TradedRequest, TraderLeaderboardRequest, TradesRequest,
         },
         types::{
-            ActivityType, BoundedIntError, LeaderboardCategory, LeaderboardOrderBy, MarketFilter,
-            PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
+            ActivityType, BoundedIntError, Hash64, LeaderboardCategory, LeaderboardOrderBy,
+            MarketFilter, PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
+            validate_hash64s,
         },
     };
+    use polymarket_client_sdk::types::Address;
     use rust_decimal_macros::dec;
 
     use super::address;
@@ -1075,6 +2536,35 @@ mod types {
         assert!(!qs.contains("eventId="));
     }
 
+    #[test]
+    fn try_markets_filter_should_validate_ids() {
+        let hash1 = "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
+        let hash2 = "0xAA22472E552920B8438158EA7238BFADFA4F736AA4CEE91A6B86C39EAD110917";
+
+        let filter = MarketFilter::try_markets([hash1, hash2]).unwrap();
+        assert!(matches!(filter, MarketFilter::Markets(ids) if ids == [hash1, hash2]));
+
+        let err = MarketFilter::try_markets([hash1, "0x1234", "not-a-hash"]).unwrap_err();
+        assert_eq!(err.value, "0x1234");
+        assert!(err.to_string().contains("0x1234"));
+
+        MarketFilter::try_markets([hash1.trim_start_matches("0x")]).unwrap_err();
+        MarketFilter::try_markets([hash1.replace('d', "g")]).unwrap_err();
+    }
+
+    #[test]
+    fn validate_hash64s_for_holders_request() {
+        let ids =
+            vec!["0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".to_owned()];
+
+        let req = HoldersRequest::builder()
+            .markets(validate_hash64s(&ids).unwrap())
+            .build();
+        assert_eq!(req.markets, ids);
+
+        validate_hash64s(["0xdd22"]).unwrap_err();
+    }
+
     #[test]
     fn event_id_filter_query_string() {
         let req = PositionsRequest::builder()
@@ -1212,6 +2702,35 @@ mod types {
     fn sort_direction_display() {
         assert_eq!(SortDirection::Asc.to_string(), "ASC");
     }
+
+    #[test]
+    fn identifiers_should_serialize_as_json_map_keys() -> anyhow::Result<()> {
+        let prices: HashMap<Hash64, f64> = HashMap::from([(super::test_condition_id(), 0.5)]);
+
+        let json = serde_json::to_value(&prices)?;
+        assert_eq!(
+            json,
+            serde_json::json!({ super::TEST_CONDITION_ID_STR: 0.5 })
+        );
+        assert_eq!(
+            serde_json::from_value::<HashMap<Hash64, f64>>(json)?,
+            prices
+        );
+
+        let balances: HashMap<Address, f64> = HashMap::from([(super::test_user(), 1.5)]);
+
+        let json = serde_json::to_string(&balances)?;
+        assert_eq!(
+            json.to_lowercase(),
+            r#"{"0x1234567890abcdef1234567890abcdef12345678":1.5}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<HashMap<Address, f64>>(&json)?,
+            balances
+        );
+
+        Ok(())
+    }
 }
 
 mod error_display {
@@ -1363,6 +2882,19 @@ mod request_query_string_extended {
         assert!(qs.contains("sortDirection="));
     }
 
+    #[test]
+    fn closed_positions_request_time_range_not_serialized() {
+        let req = ClosedPositionsRequest::builder()
+            .user(test_addr())
+            .start(1_700_000_000)
+            .end(1_700_000_100)
+            .build();
+
+        let qs = req.query_params(None);
+        assert!(!qs.contains("start="));
+        assert!(!qs.contains("end="));
+    }
+
     #[test]
     fn builder_leaderboard_request_full() {
         let req = BuilderLeaderboardRequest::builder()
@@ -356,12 +356,17 @@ mod tags {
 }
 
 mod events {
+    use futures::StreamExt as _;
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::ApiError;
     use polymarket_client_sdk::gamma::{
         Client,
+        types::GameStatus,
         types::request::{EventByIdRequest, EventBySlugRequest, EventsRequest},
+        types::response::Event,
     };
     use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
     use serde_json::json;
 
     #[tokio::test]
@@ -394,6 +399,100 @@ mod events {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn events_by_ids_should_chunk_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let join = |ids: std::ops::RangeInclusive<i32>| {
+            ids.map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+        };
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("id", join(1..=50))
+                .query_param("limit", "50");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "50" }]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("id", join(51..=60))
+                .query_param("limit", "10");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "60" }]));
+        });
+
+        let response = client.events_by_ids((1..=60).collect()).await?;
+
+        let ids: Vec<_> = response.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "50", "60"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_by_tag_slugs_should_merge_and_dedupe() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let nba = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_slug", "nba")
+                .query_param("closed", "false")
+                .query_param_missing("tag_id");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+        let nfl = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_slug", "nfl")
+                .query_param("closed", "false");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "2" }, { "id": "3" }]));
+        });
+
+        let request = EventsRequest::builder().closed(false).tag_id("9").build();
+        let response = client
+            .events_by_tag_slugs(&request, vec!["nba".to_owned(), "nfl".to_owned()])
+            .await?;
+
+        let ids: Vec<_> = response.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        nba.assert();
+        nfl.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_by_tag_ids_should_request_each_tag() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param_exists("tag_id");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }]));
+        });
+
+        let response = client
+            .events_by_tag_ids(&EventsRequest::default(), vec![100, 200])
+            .await?;
+
+        assert_eq!(response.len(), 1);
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn event_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -432,79 +531,898 @@ mod events {
             }));
         });
 
-        let request = EventBySlugRequest::builder().slug("my-event").build();
-        let response = client.event_by_slug(&request).await?;
+        let request = EventBySlugRequest::builder().slug("my-event").build();
+        let response = client.event_by_slug(&request).await?;
+
+        assert_eq!(response.id, "789");
+        assert_eq!(response.slug, Some("my-event".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_by_id_range_should_skip_missing_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let found = [1, 3].map(|id| {
+            server.mock(|when, then| {
+                when.method(GET).path(format!("/events/{id}"));
+                then.status(StatusCode::OK)
+                    .json_body(json!({ "id": id.to_string() }));
+            })
+        });
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/events/2");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "not found" }));
+        });
+        let failing = server.mock(|when, then| {
+            when.method(GET).path("/events/4");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let mut stream = client.events_by_id_range(1..=4);
+        let mut results = Vec::new();
+        while let Some(result) = stream.next().await {
+            results.push(result);
+        }
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id, "1");
+        assert_eq!(results[1].as_ref().unwrap().id, "3");
+        results[2].as_ref().unwrap_err();
+        assert_eq!(stream.skipped(), 1);
+        for mock in found {
+            mock.assert();
+        }
+        missing.assert();
+        failing.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_market_prices_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let clob = polymarket_client_sdk::clob::Client::new(
+            &server.base_url(),
+            polymarket_client_sdk::clob::Config::default(),
+        )?;
+
+        let event_mock = server.mock(|when, then| {
+            when.method(GET).path("/events/456");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "456",
+                "title": "Candidate Field",
+                "markets": [
+                    {
+                        "id": "1",
+                        "question": "Will Alice win?",
+                        "enableOrderBook": true,
+                        "clobTokenIds": "[\"111\", \"112\"]"
+                    },
+                    {
+                        "id": "2",
+                        "question": "Will Bob win?",
+                        "enableOrderBook": true,
+                        "clobTokenIds": "[\"221\", \"222\"]"
+                    },
+                    {
+                        "id": "3",
+                        "question": "Will Carol win?",
+                        "enableOrderBook": false
+                    },
+                    {
+                        "id": "4",
+                        "question": "Will Dave win?",
+                        "enableOrderBook": true,
+                        "clobTokenIds": "[\"441\", \"442\"]"
+                    }
+                ]
+            }));
+        });
+        let alice_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", "111");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.55" }));
+        });
+        let bob_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", "221");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        });
+
+        let dave_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", "441");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "internal error" }));
+        });
+
+        let response = client.event_market_prices(&clob, "456").await?;
+
+        assert_eq!(response.len(), 4);
+        assert_eq!(response[0].0.id, "1");
+        assert_eq!(response[0].1.as_ref().ok(), Some(&Some(dec!(0.55))));
+        assert_eq!(response[1].0.id, "2");
+        assert_eq!(response[1].1.as_ref().ok(), Some(&None));
+        assert_eq!(response[2].0.id, "3");
+        assert_eq!(response[2].1.as_ref().ok(), Some(&None));
+        assert_eq!(response[3].0.id, "4");
+        assert!(matches!(
+            response[3].1.as_ref().unwrap_err().as_api_error(),
+            Some(ApiError::Server(_))
+        ));
+        event_mock.assert();
+        alice_mock.assert();
+        bob_mock.assert();
+        dave_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risk_board_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let clob = polymarket_client_sdk::clob::Client::new(
+            &server.base_url(),
+            polymarket_client_sdk::clob::Config::default(),
+        )?;
+
+        let event_mock = server.mock(|when, then| {
+            when.method(GET).path("/events/456");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "456",
+                "title": "Candidate Field",
+                "negRisk": true,
+                "negRiskMarketID": "0xneg",
+                "markets": [
+                    {
+                        "id": "1",
+                        "question": "Will Alice win?",
+                        "enableOrderBook": true,
+                        "negRiskMarketID": "0xneg",
+                        "clobTokenIds": "[\"111\", \"112\"]"
+                    },
+                    {
+                        "id": "2",
+                        "question": "Will Bob win?",
+                        "enableOrderBook": true,
+                        "negRiskMarketID": "0xneg",
+                        "clobTokenIds": "[\"221\", \"222\"]"
+                    },
+                    {
+                        "id": "3",
+                        "question": "Will Carol win?",
+                        "enableOrderBook": false,
+                        "negRiskMarketID": "0xneg",
+                        "clobTokenIds": "[\"331\", \"332\"]"
+                    },
+                    {
+                        "id": "4",
+                        "question": "Unrelated",
+                        "enableOrderBook": true,
+                        "negRiskMarketID": "0xother",
+                        "clobTokenIds": "[\"441\", \"442\"]"
+                    }
+                ]
+            }));
+        });
+        let midpoints_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/midpoints")
+                .json_body(json!([{ "token_id": "111" }, { "token_id": "221" }]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "111": "0.55", "221": "0.40" }));
+        });
+
+        let board = client.neg_risk_board(&clob, "456").await?;
+
+        assert_eq!(board.event_id, "456");
+        assert_eq!(board.neg_risk_market_id, "0xneg");
+        assert_eq!(board.outcomes.len(), 3);
+        assert_eq!(board.outcomes[0].market.id, "1");
+        assert_eq!(board.outcomes[0].yes_token_id.as_deref(), Some("111"));
+        assert_eq!(board.outcomes[0].no_token_id.as_deref(), Some("112"));
+        assert_eq!(board.outcomes[0].price, Some(dec!(0.55)));
+        assert_eq!(board.outcomes[1].price, Some(dec!(0.40)));
+        assert_eq!(board.outcomes[2].market.id, "3");
+        assert_eq!(board.outcomes[2].price, None);
+        assert_eq!(board.price_sum(), dec!(0.95));
+        assert!(!board.is_fully_priced());
+        event_mock.assert();
+        midpoints_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risk_board_should_fail_for_regular_event() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let clob = polymarket_client_sdk::clob::Client::new(
+            &server.base_url(),
+            polymarket_client_sdk::clob::Config::default(),
+        )?;
+
+        let event_mock = server.mock(|when, then| {
+            when.method(GET).path("/events/789");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "789",
+                "negRisk": false,
+                "markets": []
+            }));
+        });
+
+        let err = client.neg_risk_board(&clob, "789").await.unwrap_err();
+
+        let msg = &err
+            .downcast_ref::<polymarket_client_sdk::error::Validation>()
+            .unwrap()
+            .reason;
+        assert_eq!(msg, "Event 789 is not a negative risk event");
+        event_mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_should_expose_game_state() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "gameId": 42,
+            "gameStatus": "InProgress",
+            "live": true,
+            "ended": false,
+            "score": "3-1",
+            "period": "Q2",
+            "elapsed": "07:12"
+        }))?;
+
+        let state = event.game_state().unwrap();
+
+        assert_eq!(state.game_id, Some(42));
+        assert_eq!(state.status, Some(GameStatus::InProgress));
+        assert_eq!(state.score.as_deref(), Some("3-1"));
+        assert_eq!(state.period.as_deref(), Some("Q2"));
+        assert_eq!(state.elapsed.as_deref(), Some("07:12"));
+        assert!(state.is_in_play());
+
+        let not_a_game: Event = serde_json::from_value(json!({ "id": "2" }))?;
+        assert_eq!(not_a_game.game_state(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn neg_risk_redeem_fee_bips_should_require_neg_risk() -> anyhow::Result<()> {
+        let event = |neg_risk: bool| -> serde_json::Result<Event> {
+            serde_json::from_value(json!({
+                "id": "1",
+                "negRisk": neg_risk,
+                "negRiskFeeBips": 200
+            }))
+        };
+
+        assert_eq!(event(true)?.neg_risk_redeem_fee_bips(), Some(200));
+        assert_eq!(event(false)?.neg_risk_redeem_fee_bips(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn game_state_should_gate_on_status_before_live_flag() -> anyhow::Result<()> {
+        let game = |status: &str, live: bool, ended: bool| -> anyhow::Result<bool> {
+            let event: Event = serde_json::from_value(json!({
+                "id": "1",
+                "gameStatus": status,
+                "live": live,
+                "ended": ended
+            }))?;
+            Ok(event.game_state().unwrap().is_in_play())
+        };
+
+        assert!(game("halftime", false, false)?);
+        assert!(!game("Final", true, false)?);
+        assert!(!game("scheduled", true, false)?);
+        assert!(game("overtime", true, false)?);
+        assert!(!game("in_progress", true, true)?);
+        assert_eq!(GameStatus::from("in_progress"), GameStatus::InProgress);
+        assert_eq!(
+            GameStatus::from("overtime"),
+            GameStatus::Other("overtime".to_owned())
+        );
+
+        Ok(())
+    }
+}
+
+mod markets {
+    use std::time::Duration;
+
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::clob::types::TickSize;
+    use polymarket_client_sdk::error::{ApiError, Validation};
+    use polymarket_client_sdk::gamma::{
+        Client,
+        types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
+        types::response::Market,
+        types::{PriceSource, ResolutionStatus, SportsLine, SportsMarketType},
+    };
+    use polymarket_client_sdk::types::address;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    const LIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[tokio::test]
+    async fn markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets").query_param("limit", "10");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "question": "Test Market?",
+                    "slug": "test-market"
+                }
+            ]));
+        });
+
+        let request = MarketsRequest::builder().limit(10).build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].id, "1");
+        assert_eq!(response[0].question, Some("Test Market?".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_by_ids_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("id", "7,8")
+                .query_param("limit", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "7" }, { "id": "8" }]));
+        });
+
+        let response = client.markets_by_ids(vec![7, 8]).await?;
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response[1].id, "8");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_id_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/42");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "42",
+                "question": "Specific Market?",
+                "slug": "specific-market"
+            }));
+        });
+
+        let request = MarketByIdRequest::builder().id("42").build();
+        let response = client.market_by_id(&request).await?;
+
+        assert_eq!(response.id, "42");
+        assert_eq!(response.question, Some("Specific Market?".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_expose_decimal_amounts() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "liquidity": "12345.678901",
+                    "liquidityNum": 12345.68,
+                    "volume": "1.5e3",
+                    "fee": "20000000000000000"
+                },
+                { "id": "2", "liquidityNum": 42.5, "volume": "n/a" }
+            ]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+
+        assert_eq!(response[0].liquidity_decimal(), Some(dec!(12345.678901)));
+        assert_eq!(response[0].volume_decimal(), Some(dec!(1500)));
+        assert_eq!(
+            response[0].fee_decimal(),
+            Some(dec!(20_000_000_000_000_000))
+        );
+        assert_eq!(response[1].liquidity_decimal(), Some(dec!(42.5)));
+        assert_eq!(response[1].volume_decimal(), None);
+        assert_eq!(response[1].fee_decimal(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_score_liquidity() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "liquidityNum": 1000, "spread": 0.02, "rewardsMaxSpread": 4 },
+                { "id": "2", "liquidityNum": 1000, "spread": 0.2, "rewardsMaxSpread": 4 },
+                { "id": "3", "liquidityNum": 1000, "spread": 0.02 },
+                { "id": "4", "liquidityNum": 1000, "spread": 0.02, "rewardsMaxSpread": 0 }
+            ]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+
+        assert_eq!(response[0].liquidity_score(), Some(dec!(750)));
+        assert_eq!(response[1].liquidity_score(), Some(dec!(0)));
+        assert_eq!(response[2].liquidity_score(), None);
+        assert_eq!(response[3].liquidity_score(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_parse_encoded_arrays() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "shortOutcomes": "[\"Y\", \"N\"]",
+                    "outcomePrices": "[\"0.25\", \"0.75\"]",
+                    "clobTokenIds": "[\"123\", \"456\"]"
+                },
+                { "id": "2", "outcomes": "[]", "outcomePrices": "[\"0.25\", \"abc\"]" }
+            ]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+
+        assert_eq!(response[0].outcome_names(), ["Yes", "No"]);
+        assert_eq!(response[0].short_outcome_names(), ["Y", "N"]);
+        assert_eq!(
+            response[0].outcome_prices_decimal(),
+            [dec!(0.25), dec!(0.75)]
+        );
+        assert_eq!(response[0].token_ids(), ["123", "456"]);
+        assert!(response[1].outcome_names().is_empty());
+        assert!(response[1].outcome_prices_decimal().is_empty());
+        assert!(response[1].token_ids().is_empty());
+
+        Ok(())
+    }
+
+    fn clob(server: &MockServer) -> anyhow::Result<polymarket_client_sdk::clob::Client> {
+        Ok(polymarket_client_sdk::clob::Client::new(
+            &server.base_url(),
+            polymarket_client_sdk::clob::Config::default(),
+        )?)
+    }
+
+    #[tokio::test]
+    async fn effective_last_price_should_prefer_clob() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let clob_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/last-trade-price")
+                .query_param("token_id", "111");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "price": "0.57", "side": "BUY" }));
+        });
+        let gamma_mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let price = client
+            .effective_last_price(&clob(&server)?, "111", LIVE_TIMEOUT)
+            .await?;
+
+        assert_eq!(price, (dec!(0.57), PriceSource::Clob));
+        clob_mock.assert();
+        gamma_mock.assert_hits(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn effective_last_price_should_fall_back_to_gamma() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/last-trade-price");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No trades" }));
+        });
+        let gamma_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("clob_token_ids", "112");
+            then.status(StatusCode::OK).json_body(json!([{
+                "id": "1",
+                "clobTokenIds": "[\"111\", \"112\"]",
+                "lastTradePrice": 0.4
+            }]));
+        });
+
+        let price = client
+            .effective_last_price(&clob(&server)?, "112", LIVE_TIMEOUT)
+            .await?;
+
+        assert_eq!(price, (dec!(0.6), PriceSource::Gamma));
+        gamma_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn effective_last_price_without_market_should_be_not_found() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/last-trade-price");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No trades" }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let err = client
+            .effective_last_price(&clob(&server)?, "112", LIVE_TIMEOUT)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.as_api_error(), Some(&ApiError::NotFound));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn effective_last_price_should_reject_multi_outcome_markets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/last-trade-price");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No trades" }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([{
+                "id": "1",
+                "clobTokenIds": "[\"111\", \"112\", \"113\"]",
+                "lastTradePrice": 0.4
+            }]));
+        });
+
+        let err = client
+            .effective_last_price(&clob(&server)?, "112", LIVE_TIMEOUT)
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Market 1 has 3 outcomes, so its last trade price cannot price token 112"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "new-markets")]
+    #[tokio::test]
+    async fn new_markets_stream_should_yield_only_new_markets() -> anyhow::Result<()> {
+        use futures::StreamExt as _;
+        use tokio::time::timeout;
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let poll = Duration::from_millis(20);
+
+        let existing = json!([
+            { "id": "2", "createdAt": "2025-01-02T00:00:00Z" },
+            { "id": "1", "createdAt": "2025-01-01T00:00:00Z" }
+        ]);
+        let mut mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("order", "createdAt")
+                .query_param("ascending", "false")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(existing.clone());
+        });
+
+        let request = MarketsRequest::default();
+        let stream = client.new_markets_stream(&request, poll);
+        let mut stream = Box::pin(stream);
+
+        // Existing markets only set the watermark
+        assert!(timeout(poll * 5, stream.next()).await.is_err());
+
+        mock.delete();
+        mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "unavailable" }));
+        });
+        timeout(LIVE_TIMEOUT, stream.next())
+            .await?
+            .unwrap()
+            .unwrap_err();
+
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "4", "createdAt": "2025-01-03T00:00:00Z" },
+                { "id": "3", "createdAt": "2025-01-02T00:00:00Z" },
+                { "id": "5" },
+                { "id": "2", "createdAt": "2025-01-02T00:00:00Z" },
+                { "id": "1", "createdAt": "2025-01-01T00:00:00Z" }
+            ]));
+        });
+
+        let third = timeout(LIVE_TIMEOUT, stream.next()).await?.unwrap()?;
+        let fourth = timeout(LIVE_TIMEOUT, stream.next()).await?.unwrap()?;
+        assert_eq!((third.id.as_str(), fourth.id.as_str()), ("3", "4"));
+        assert!(timeout(poll * 5, stream.next()).await.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_should_expose_resolution_info() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "umaResolutionStatus": "disputed",
+            "umaResolutionStatuses": "[\"proposed\", \"disputed\"]",
+            "umaEndDate": "2025-03-01T12:00:00Z",
+            "resolvedBy": "0x6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74",
+            "umaBond": "500",
+            "umaReward": "5"
+        }))?;
+
+        let info = market.resolution_info();
+
+        assert_eq!(info.status, Some(ResolutionStatus::Disputed));
+        assert_eq!(
+            info.history,
+            [ResolutionStatus::Proposed, ResolutionStatus::Disputed]
+        );
+        assert_eq!(info.end_date, Some("2025-03-01T12:00:00Z".parse()?));
+        assert_eq!(
+            info.resolved_by,
+            Some(address!("0x6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74"))
+        );
+        assert_eq!(info.bond, Some(dec!(500)));
+        assert_eq!(info.reward, Some(dec!(5)));
+        assert!(info.is_disputed());
+        assert!(!info.is_proposed());
+
+        let proposed: Market = serde_json::from_value(json!({
+            "id": "2",
+            "umaResolutionStatuses": "[\"proposed\"]",
+            "umaEndDateIso": "2025-03-01",
+            "umaBond": "not a number"
+        }))?;
+        let info = proposed.resolution_info();
+
+        assert!(info.is_proposed());
+        assert_eq!(info.end_date, Some("2025-03-01T00:00:00Z".parse()?));
+        assert_eq!(info.bond, None);
+        assert_eq!(
+            ResolutionStatus::from("Challenged"),
+            ResolutionStatus::Other("Challenged".to_owned())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_keep_quotes_exact() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([{
+                "id": "1",
+                "bestBid": 0.07,
+                "bestAsk": "0.071",
+                "spread": 0.001,
+                "lastTradePrice": 0.3
+            }]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+
+        assert_eq!(response[0].best_bid, Some(dec!(0.07)));
+        assert_eq!(response[0].best_ask, Some(dec!(0.071)));
+        assert_eq!(response[0].spread, Some(dec!(0.001)));
+        assert_eq!(response[0].last_trade_price, Some(dec!(0.3)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_type_sports_lines() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "sportsMarketType": "spreads", "line": -3.5 },
+                { "id": "2", "sportsMarketType": "totals", "line": 47.5 },
+                { "id": "3", "sportsMarketType": "moneyline" },
+                { "id": "4", "sportsMarketType": "first_half_totals", "line": 21.5 }
+            ]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+
+        assert_eq!(
+            response[0].sports_market_type_typed(),
+            Some(SportsMarketType::Spread)
+        );
+        assert_eq!(
+            response[0].sports_line(),
+            Some(SportsLine::Spread(dec!(-3.5)))
+        );
+        assert_eq!(
+            response[1].sports_line(),
+            Some(SportsLine::Total(dec!(47.5)))
+        );
+        assert_eq!(
+            response[2].sports_market_type_typed(),
+            Some(SportsMarketType::Moneyline)
+        );
+        assert_eq!(response[2].sports_line(), None);
+        assert_eq!(
+            response[3].sports_market_type_typed(),
+            Some(SportsMarketType::Other("first_half_totals".to_owned()))
+        );
+        assert_eq!(response[3].sports_line(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_slug_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/slug/my-market");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "99",
+                "question": "My Market?",
+                "slug": "my-market"
+            }));
+        });
+
+        let request = MarketBySlugRequest::builder().slug("my-market").build();
+        let response = client.market_by_slug(&request).await?;
 
-        assert_eq!(response.id, "789");
-        assert_eq!(response.slug, Some("my-event".to_owned()));
+        assert_eq!(response.id, "99");
+        assert_eq!(response.slug, Some("my-market".to_owned()));
         mock.assert();
 
         Ok(())
     }
-}
-
-mod markets {
-    use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{
-        Client,
-        types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
-    };
-    use reqwest::StatusCode;
-    use serde_json::json;
 
     #[tokio::test]
-    async fn markets_should_succeed() -> anyhow::Result<()> {
+    async fn resolve_slug_should_succeed_and_cache() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
         let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets").query_param("limit", "10");
-            then.status(StatusCode::OK).json_body(json!([
-                {
-                    "id": "1",
-                    "question": "Test Market?",
-                    "slug": "test-market"
-                }
-            ]));
+            when.method(GET).path("/markets/slug/my-market");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "99",
+                "slug": "my-market",
+                "conditionId": "0xabc",
+                "outcomes": "[\"Yes\", \"No\"]",
+                "clobTokenIds": "[\"111\", \"112\"]",
+                "orderPriceMinTickSize": 0.01,
+                "negRisk": true
+            }));
         });
 
-        let request = MarketsRequest::builder().limit(10).build();
-        let response = client.markets(&request).await?;
+        let resolved = client.resolve_slug("my-market").await?;
+        let cached = client.resolve_slug("my-market").await?;
 
-        assert_eq!(response.len(), 1);
-        assert_eq!(response[0].id, "1");
-        assert_eq!(response[0].question, Some("Test Market?".to_owned()));
-        mock.assert();
+        assert_eq!(resolved, cached);
+        assert_eq!(resolved.condition_id, "0xabc");
+        assert_eq!(resolved.token_ids, vec!["111".to_owned(), "112".to_owned()]);
+        assert_eq!(resolved.tick_size, TickSize::Hundredth);
+        assert!(resolved.neg_risk);
+        assert_eq!(resolved.token_id("yes"), Some("111"));
+        assert_eq!(resolved.token_id("No"), Some("112"));
+        assert_eq!(resolved.token_id("Maybe"), None);
+        mock.assert_hits(1);
+
+        client.invalidate_internal_caches();
+        client.resolve_slug("my-market").await?;
+        mock.assert_hits(2);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn market_by_id_should_succeed() -> anyhow::Result<()> {
+    async fn resolve_slug_should_refetch_after_ttl() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = Client::new(&server.base_url())?;
+        let client = Client::new(&server.base_url())?.with_resolved_slug_ttl(Duration::ZERO);
 
         let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets/42");
+            when.method(GET).path("/markets/slug/my-market");
             then.status(StatusCode::OK).json_body(json!({
-                "id": "42",
-                "question": "Specific Market?",
-                "slug": "specific-market"
+                "id": "99",
+                "conditionId": "0xabc",
+                "clobTokenIds": "[\"111\", \"112\"]",
+                "orderPriceMinTickSize": 0.001
             }));
         });
 
-        let request = MarketByIdRequest::builder().id("42").build();
-        let response = client.market_by_id(&request).await?;
+        client.resolve_slug("my-market").await?;
+        let resolved = client.resolve_slug("my-market").await?;
 
-        assert_eq!(response.id, "42");
-        assert_eq!(response.question, Some("Specific Market?".to_owned()));
-        mock.assert();
+        assert_eq!(resolved.tick_size, TickSize::Thousandth);
+        mock.assert_hits(2);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn market_by_slug_should_succeed() -> anyhow::Result<()> {
+    async fn resolve_slug_should_fail_without_token_ids() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
@@ -512,16 +1430,18 @@ mod markets {
             when.method(GET).path("/markets/slug/my-market");
             then.status(StatusCode::OK).json_body(json!({
                 "id": "99",
-                "question": "My Market?",
-                "slug": "my-market"
+                "conditionId": "0xabc",
+                "orderPriceMinTickSize": 0.01
             }));
         });
 
-        let request = MarketBySlugRequest::builder().slug("my-market").build();
-        let response = client.market_by_slug(&request).await?;
+        let err = client.resolve_slug("my-market").await.unwrap_err();
 
-        assert_eq!(response.id, "99");
-        assert_eq!(response.slug, Some("my-market".to_owned()));
+        let msg = &err
+            .downcast_ref::<polymarket_client_sdk::error::Validation>()
+            .unwrap()
+            .reason;
+        assert_eq!(msg, "Market my-market has no CLOB token IDs");
         mock.assert();
 
         Ok(())
@@ -606,6 +1526,7 @@ mod markets {
 }
 
 mod search {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{Client, types::request::SearchRequest};
     use reqwest::StatusCode;
@@ -641,6 +1562,68 @@ mod search {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn search_stream_should_page_until_no_more() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "bitcoin")
+                .query_param("page", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [{ "id": "1" }],
+                "profiles": [],
+                "pagination": { "hasMore": true, "totalResults": 2 }
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "bitcoin")
+                .query_param("page", "2");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [],
+                "profiles": [{ "id": "7", "name": "satoshi" }],
+                "pagination": { "hasMore": false, "totalResults": 2 }
+            }));
+        });
+
+        let request = SearchRequest::builder().q("bitcoin").build();
+        let pages: Vec<_> = client.search_stream(&request).try_collect().await?;
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].events.as_ref().map(Vec::len), Some(1));
+        assert_eq!(pages[1].profiles.as_ref().map(Vec::len), Some(1));
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_stream_should_stop_on_empty_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/public-search");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [],
+                "pagination": { "hasMore": true }
+            }));
+        });
+
+        let request = SearchRequest::builder().q("bitcoin").page(3).build();
+        let pages: Vec<_> = client.search_stream(&request).try_collect().await?;
+
+        assert_eq!(pages.len(), 1);
+        mock.assert_hits(1);
+
+        Ok(())
+    }
 }
 
 mod health {
@@ -671,7 +1654,9 @@ mod series {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{
         Client,
+        types::Recurrence,
         types::request::{SeriesByIdRequest, SeriesListRequest},
+        types::response::Series,
     };
     use reqwest::StatusCode;
     use serde_json::json;
@@ -728,20 +1713,72 @@ mod series {
         assert_eq!(response.id, "42");
         assert_eq!(response.title, Some("NFL Season 2024".to_owned()));
         assert_eq!(response.recurrence, Some("weekly".to_owned()));
+        assert_eq!(response.recurrence_typed(), Some(Recurrence::Weekly));
         mock.assert();
 
         Ok(())
     }
+
+    #[test]
+    fn series_should_traverse_events_and_markets() -> anyhow::Result<()> {
+        let series: Series = serde_json::from_value(json!({
+            "id": "1",
+            "events": [
+                {
+                    "id": "10",
+                    "active": true,
+                    "closed": false,
+                    "markets": [{ "id": "100" }, { "id": "101" }]
+                },
+                {
+                    "id": "11",
+                    "active": true,
+                    "closed": true,
+                    "markets": null
+                },
+                {
+                    "id": "12",
+                    "markets": [{ "id": "120" }]
+                }
+            ]
+        }))?;
+
+        let market_ids: Vec<_> = series
+            .all_markets()
+            .iter()
+            .map(|market| market.id.as_str())
+            .collect();
+        assert_eq!(market_ids, ["100", "101", "120"]);
+
+        let active: Vec<_> = series
+            .active_events()
+            .iter()
+            .map(|event| event.id.as_str())
+            .collect();
+        assert_eq!(active, ["10"]);
+
+        let events: Vec<_> = series.event_iter().collect();
+        assert_eq!(events[1].market_iter().count(), 0);
+
+        let empty: Series = serde_json::from_value(json!({ "id": "2" }))?;
+        assert!(empty.all_markets().is_empty());
+        assert!(empty.active_events().is_empty());
+
+        Ok(())
+    }
 }
 
 mod comments {
+    use alloy::primitives::U256;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::types::ParentEntityType;
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest},
+        types::response::Comment,
     };
     use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
     use serde_json::json;
 
     #[tokio::test]
@@ -777,6 +1814,33 @@ mod comments {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn comments_for_entity_should_set_matching_type() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        for (entity_type, request) in [
+            ("Event", CommentsRequest::for_event("1")),
+            ("Series", CommentsRequest::for_series("2")),
+            ("market", CommentsRequest::for_market("3")),
+        ] {
+            let mut mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/comments")
+                    .query_param("parent_entity_type", entity_type)
+                    .query_param("parent_entity_id", &request.parent_entity_id);
+                then.status(StatusCode::OK).json_body(json!([]));
+            });
+
+            client.comments(&request).await?;
+
+            mock.assert();
+            mock.delete();
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments_with_filters_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -832,6 +1896,30 @@ mod comments {
         Ok(())
     }
 
+    #[test]
+    fn comment_position_should_parse_size_and_token_id() -> anyhow::Result<()> {
+        let comment: Comment = serde_json::from_value(json!({
+            "id": "1",
+            "profile": {
+                "positions": [
+                    { "tokenId": "12345", "positionSize": "1500.25" },
+                    { "tokenId": "not a token", "positionSize": "lots" },
+                    {}
+                ]
+            }
+        }))?;
+        let positions = comment.profile.unwrap().positions.unwrap();
+
+        assert_eq!(positions[0].token_id_u256(), Some(U256::from(12345)));
+        assert_eq!(positions[0].position_size_decimal(), Some(dec!(1500.25)));
+        assert_eq!(positions[1].token_id_u256(), None);
+        assert_eq!(positions[1].position_size_decimal(), None);
+        assert_eq!(positions[2].token_id_u256(), None);
+        assert_eq!(positions[2].position_size_decimal(), None);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments_by_user_address_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -870,7 +1958,11 @@ mod comments {
 
 mod profiles {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{Client, types::request::PublicProfileRequest};
+    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::gamma::{
+        Client, types::request::PublicProfileRequest, types::response::PublicProfileError,
+    };
+    use polymarket_client_sdk::types::address;
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -896,7 +1988,10 @@ mod profiles {
         let request = PublicProfileRequest::builder()
             .address("0x56687bf447db6ffa42ffe2204a05edaa20f55839")
             .build();
-        let response = client.public_profile(&request).await?;
+        let response = client
+            .public_profile(&request)
+            .await?
+            .expect("profile should exist");
 
         assert_eq!(response.name, Some("Polymarket Trader".to_owned()));
         assert_eq!(response.pseudonym, Some("PolyTrader".to_owned()));
@@ -905,6 +2000,107 @@ mod profiles {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn public_profile_should_be_none_when_not_found() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/public-profile");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "type": "not found error", "error": "profile not found" }));
+        });
+
+        let request = PublicProfileRequest::builder()
+            .address("0x0000000000000000000000000000000000000001")
+            .build();
+        let response = client.public_profile(&request).await?;
+
+        assert_eq!(response, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn public_profile_should_fail_on_error_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/public-profile");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "type": "validation error", "error": "invalid address" }));
+        });
+
+        let request = PublicProfileRequest::builder().address("0x1234").build();
+        let err = client.public_profile(&request).await.unwrap_err();
+
+        let profile_error = err.downcast_ref::<PublicProfileError>().unwrap();
+        assert_eq!(profile_error.error_type, "validation error");
+        assert_eq!(profile_error.error, "invalid address");
+        assert_eq!(err.kind(), Kind::Status);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn public_profiles_should_skip_missing_profiles() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let found = address!("0x56687bf447db6ffa42ffe2204a05edaa20f55839");
+        let missing = address!("0x0000000000000000000000000000000000000001");
+
+        let found_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-profile")
+                .query_param("address", "0x56687bf447db6ffa42ffe2204a05edaa20f55839");
+            then.status(StatusCode::OK).json_body(json!({
+                "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                "name": "Polymarket Trader"
+            }));
+        });
+        let missing_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-profile")
+                .query_param("address", "0x0000000000000000000000000000000000000001");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "type": "not found error", "error": "profile not found" }));
+        });
+
+        let response = client.public_profiles(vec![found, missing]).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[&found].name, Some("Polymarket Trader".to_owned()));
+        assert!(!response.contains_key(&missing));
+        found_mock.assert();
+        missing_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn public_profiles_should_fail_on_server_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/public-profile");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let result = client
+            .public_profiles(vec![address!("0x56687bf447db6ffa42ffe2204a05edaa20f55839")])
+            .await;
+
+        result.unwrap_err();
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod event_tags {
@@ -948,6 +2144,39 @@ mod event_tags {
     }
 }
 
+mod market_description {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::gamma::{Client, types::request::MarketByIdRequest};
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn market_description_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/456");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "456",
+                "question": "Will it rain tomorrow?",
+                "description": "Resolves Yes if it rains in NYC on the given date."
+            }));
+        });
+
+        let request = MarketByIdRequest::builder().id("456").build();
+        let description = client.market_description(&request).await?;
+
+        assert_eq!(
+            description.as_deref(),
+            Some("Resolves Yes if it rains in NYC on the given date.")
+        );
+        mock.assert();
+
+        Ok(())
+    }
+}
+
 mod market_tags {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{Client, types::request::MarketTagsRequest};
@@ -988,6 +2217,7 @@ mod market_tags {
 
 mod query_string {
     use chrono::{TimeZone as _, Utc};
+    use polymarket_client_sdk::error::Validation;
     use polymarket_client_sdk::gamma::types::request::{
         CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
         EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest,
@@ -995,7 +2225,7 @@ mod query_string {
         RelatedTagsByIdRequest, RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest,
         SeriesListRequest, TagByIdRequest, TagBySlugRequest, TagsRequest, TeamsRequest,
     };
-    use polymarket_client_sdk::gamma::types::{ParentEntityType, RelatedTagsStatus};
+    use polymarket_client_sdk::gamma::types::{ParentEntityType, Recurrence, RelatedTagsStatus};
     use rust_decimal_macros::dec;
     use serde::Serialize;
 
@@ -1123,6 +2353,65 @@ mod query_string {
         assert!(qs.contains("status=all"));
     }
 
+    #[test]
+    fn events_request_should_round_trip_query_string() {
+        let request = EventsRequest::builder()
+            .limit(50)
+            .id(vec!["1".to_owned(), "2".to_owned()])
+            .tag_id("42")
+            .recurrence(Recurrence::Weekly)
+            .closed(false)
+            .liquidity_min(dec!(1000.5))
+            .start_date_min(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .build();
+
+        let qs = query_string(&request);
+        let parsed = EventsRequest::from_query_string(&qs).unwrap();
+
+        assert_eq!(parsed.limit, Some(50));
+        assert_eq!(parsed.id, vec!["1".to_owned(), "2".to_owned()]);
+        assert_eq!(parsed.recurrence, Some(Recurrence::Weekly));
+        assert_eq!(parsed.liquidity_min, Some(dec!(1000.5)));
+        assert!(parsed.slug.is_empty());
+        assert_eq!(query_string(&parsed), qs);
+    }
+
+    #[test]
+    fn presets_should_set_only_their_flags() {
+        let events = EventsRequest::live().limit(5).build();
+        assert_eq!(
+            query_string(&events),
+            "?limit=5&active=true&archived=false&closed=false"
+        );
+
+        let markets = MarketsRequest::tradeable().limit(5).build();
+        assert_eq!(query_string(&markets), "?limit=5&closed=false");
+    }
+
+    #[test]
+    fn markets_request_from_url() {
+        let request = MarketsRequest::from_query_string(
+            "https://gamma-api.polymarket.com/markets?limit=10&closed=false&slug=a%2Cb&clob_token_ids=1&clob_token_ids=2",
+        )
+        .unwrap();
+
+        assert_eq!(request.limit, Some(10));
+        assert_eq!(request.closed, Some(false));
+        assert_eq!(request.slug, vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(request.clob_token_ids, vec!["1".to_owned(), "2".to_owned()]);
+        assert_eq!(
+            request.clob_token_ids_query(),
+            "clob_token_ids=1&clob_token_ids=2"
+        );
+    }
+
+    #[test]
+    fn markets_request_from_invalid_query_string() {
+        let err = MarketsRequest::from_query_string("limit=ten").unwrap_err();
+
+        assert!(err.to_string().contains("Unable to parse query string"));
+    }
+
     #[test]
     fn events_request_all_params() {
         let start_date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
@@ -1145,7 +2434,9 @@ mod query_string {
             .cyom(false)
             .include_chat(true)
             .include_template(true)
-            .recurrence("weekly".to_owned())
+            .recurrence(Recurrence::Weekly)
+            .series_id(10345)
+            .series_slug("nfl")
             .closed(false)
             .liquidity_min(dec!(1000))
             .liquidity_max(dec!(100_000))
@@ -1175,6 +2466,8 @@ mod query_string {
         assert!(qs.contains("include_chat=true"));
         assert!(qs.contains("include_template=true"));
         assert!(qs.contains("recurrence=weekly"));
+        assert!(qs.contains("series_id=10345"));
+        assert!(qs.contains("series_slug=nfl"));
         assert!(qs.contains("closed=false"));
         assert!(qs.contains("liquidity_min=1000"));
         assert!(qs.contains("liquidity_max=100000"));
@@ -1366,7 +2659,7 @@ mod query_string {
             .categories_labels(vec!["Sports".to_owned(), "Politics".to_owned()])
             .closed(false)
             .include_chat(true)
-            .recurrence("daily".to_owned())
+            .recurrence(Recurrence::Daily)
             .build();
 
         let qs = query_string(&request);
@@ -1507,7 +2800,7 @@ mod query_string {
             .ascending(false)
             .search_tags(true)
             .search_profiles(true)
-            .recurrence("weekly".to_owned())
+            .recurrence(Recurrence::Weekly)
             .exclude_tag_id(vec!["1".to_owned(), "2".to_owned()])
             .optimized(true)
             .build();
@@ -1529,6 +2822,31 @@ mod query_string {
         assert!(qs.contains("optimized=true"));
     }
 
+    #[test]
+    fn recurrence_should_round_trip_unknown_values() {
+        let request = SeriesListRequest::builder()
+            .recurrence(Recurrence::Other("hourly".to_owned()))
+            .build();
+
+        assert!(query_string(&request).contains("recurrence=hourly"));
+        assert_eq!(
+            Recurrence::try_from("monthly").unwrap(),
+            Recurrence::Monthly
+        );
+        assert_eq!(Recurrence::Monthly.to_string(), "monthly");
+    }
+
+    #[test]
+    fn recurrence_should_reject_unknown_strings() {
+        let err = Recurrence::try_from("daly").unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unknown recurrence: daly. Expected one of: daily, weekly, monthly"
+        );
+    }
+
     #[test]
     fn search_request_empty_arrays_not_included() {
         let request = SearchRequest::builder()
@@ -10,9 +10,11 @@ use std::str::FromStr as _;
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use httpmock::MockServer;
-use polymarket_client_sdk::clob::types::response::OrderSummary;
+use polymarket_client_sdk::clob::types::response::{
+    FeeSchedule, GeoblockResponse, MarketParams, MarketStatus, OrderSummary,
+};
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side, SignatureType, TickSize};
-use polymarket_client_sdk::types::{Address, Decimal, address};
+use polymarket_client_sdk::types::{Address, Decimal, RoundingStrategy, address};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 
@@ -385,6 +387,52 @@ mod lifecycle {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn order_signature_type_should_override_client() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Tenth);
+
+        let funder = address!("0xaDEFf2158d668f64308C62ef227C5CcaCAAf976D");
+        let signable_order = client
+            .limit_order()
+            .token_id("1")
+            .size(Decimal::ONE_HUNDRED)
+            .price(dec!(0.1))
+            .side(Side::Buy)
+            .signature_type(SignatureType::GnosisSafe)
+            .funder(funder)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.maker, funder);
+        assert_eq!(
+            signable_order.order.signatureType,
+            SignatureType::GnosisSafe as u8
+        );
+        assert_ne!(signable_order.order.maker, signable_order.order.signer);
+
+        let err = client
+            .limit_order()
+            .token_id("1")
+            .size(Decimal::ONE_HUNDRED)
+            .price(dec!(0.1))
+            .side(Side::Buy)
+            .signature_type(SignatureType::Proxy)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Must have a funder address with a Proxy signature type"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn signer_with_no_chain_id_should_fail() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -455,6 +503,36 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_fail_on_taker_matching_maker() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Tenth);
+
+        let err = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .taker(client.address())
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            &format!(
+                "Cannot have the maker {} as the taker of its own order",
+                client.address()
+            )
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_missing_fields() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -561,6 +639,281 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_use_market_params_without_lookups() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(true)
+            .fee_bps(10)
+            .build();
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(5))
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.feeRateBps, U256::from(10));
+        assert_eq!(signable_order.order.makerAmount, U256::from(1_700_000_u64));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_params_should_not_seed_client_caches() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, TOKEN_1, TickSize::Thousandth);
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(true)
+            .fee_bps(10)
+            .build();
+
+        client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(5))
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await?;
+
+        assert!(!client.neg_risk(TOKEN_1).await?.neg_risk);
+        assert_eq!(
+            client.tick_size(TOKEN_1).await?.minimum_tick_size,
+            TickSize::Thousandth
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_prefer_fee_schedule_taker_fee() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .fee_bps(10)
+            .build();
+        let fee_schedule = FeeSchedule::builder().maker_bps(0).taker_bps(1000).build();
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(5))
+            .side(Side::Buy)
+            .market_params(params)
+            .fee_schedule(fee_schedule)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.feeRateBps, U256::from(1000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_below_market_params_min_order_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .fee_bps(0)
+            .build();
+
+        let err = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(4.99))
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to build Order: Size 4.99 is below the minimum order size 5"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn round_size_should_round_to_lot_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Hundredth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(12.8275))
+            .side(Side::Buy)
+            .round_size(RoundingStrategy::ToZero)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.takerAmount, U256::from(12_820_000));
+        assert_eq!(signable_order.order.makerAmount, U256::from(6_410_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn round_size_should_check_rounded_size_against_min_order_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .fee_bps(0)
+            .build();
+        let order = |strategy: RoundingStrategy| {
+            client
+                .limit_order()
+                .token_id(TOKEN_1)
+                .price(dec!(0.34))
+                .size(dec!(4.996))
+                .side(Side::Buy)
+                .market_params(params)
+                .round_size(strategy)
+        };
+
+        let problems = order(RoundingStrategy::ToZero).validate().unwrap_err();
+        let err = order(RoundingStrategy::ToZero).build().await.unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            problems[0].reason,
+            "Unable to build Order: Size 4.99 is below the minimum order size 5"
+        );
+        assert_eq!(
+            msg,
+            "Unable to build Order: Size 4.99 is below the minimum order size 5"
+        );
+        order(RoundingStrategy::AwayFromZero).validate().unwrap();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_should_report_every_problem() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .fee_bps(0)
+            .build();
+
+        let problems = client
+            .limit_order()
+            .price(dec!(0.345))
+            .size(dec!(4.99))
+            .side(Side::Buy)
+            .order_type(OrderType::GTD)
+            .expiration(DateTime::<Utc>::from_str("2024-01-20T00:00:00Z").unwrap())
+            .market_params(params)
+            .validate()
+            .unwrap_err();
+        let reasons: Vec<_> = problems.iter().map(|p| p.reason.as_str()).collect();
+
+        assert_eq!(
+            reasons,
+            [
+                "Unable to build Order due to missing token ID",
+                "Unable to build Order: Price 0.345 has 3 decimal places. Minimum tick size 0.01 has 2 decimal places. Price decimal places <= minimum tick size decimal places",
+                "Unable to build Order: Size 4.99 is below the minimum order size 5",
+                "Unable to build Order: Expiration 2024-01-20 00:00:00 UTC has already passed",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_refuse_unplaceable_markets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, TOKEN_1, TickSize::Hundredth);
+
+        let order = |status: MarketStatus| {
+            client
+                .limit_order()
+                .token_id(TOKEN_1)
+                .price(dec!(0.5))
+                .size(dec!(21.04))
+                .side(Side::Buy)
+                .market_status(status)
+        };
+
+        let err = order(MarketStatus::Archived).build().await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to build Order: market archived"
+        );
+
+        let geoblock = GeoblockResponse::builder()
+            .blocked(true)
+            .ip("192.0.2.1".to_owned())
+            .country("US".to_owned())
+            .region("NY".to_owned())
+            .build();
+        let status = MarketStatus::Open.with_geoblock(&geoblock);
+        assert_eq!(status, MarketStatus::Geoblocked);
+        let problems = order(status).validate().unwrap_err();
+        assert_eq!(problems[0].reason, "Unable to build Order: geoblocked");
+
+        order(MarketStatus::Open).build().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_should_accept_valid_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let builder = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy);
+
+        builder.validate().unwrap();
+
+        Ok(())
+    }
+
     mod buy {
         use super::*;
 
@@ -1223,6 +1576,99 @@ mod market {
         });
     }
 
+    #[tokio::test]
+    async fn validate_should_report_every_problem() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let problems = client
+            .market_order()
+            .token_id(TOKEN_1)
+            .side(Side::Sell)
+            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .order_type(OrderType::GTC)
+            .min_fill_size(dec!(0))
+            .validate()
+            .unwrap_err();
+        let reasons: Vec<_> = problems.iter().map(|p| p.reason.as_str()).collect();
+
+        assert_eq!(
+            reasons,
+            [
+                "Sell Orders must specify their `amount`s in shares",
+                "Cannot set an order type other than FAK/FOK for a market order",
+                "`min_fill_size` can only be set for FAK orders",
+                "`min_fill_size` 0 must be positive",
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn level(price: Decimal, size: Decimal) -> OrderSummary {
+        OrderSummary::builder().price(price).size(size).build()
+    }
+
+    #[tokio::test]
+    async fn max_slippage_should_bound_price_around_midpoint() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        // Midpoint 0.45, so a 0.1 cap allows buying up to 0.5 and selling down to 0.4
+        ensure_requirements_for_market_price(
+            &server,
+            TOKEN_1,
+            &[level(dec!(0.3), dec!(100)), level(dec!(0.4), dec!(30))],
+            &[level(dec!(0.6), dec!(50)), level(dec!(0.5), dec!(100))],
+        );
+
+        let order = |side: Side, amount: Amount| {
+            client
+                .market_order()
+                .token_id(TOKEN_1)
+                .side(side)
+                .amount(amount)
+                .max_slippage(dec!(0.1))
+        };
+
+        let buy = order(Side::Buy, Amount::usdc(dec!(40))?).build().await?;
+        assert_eq!(buy.order.makerAmount, U256::from(40_000_000));
+        assert_eq!(buy.order.takerAmount, U256::from(80_000_000));
+
+        let sell = order(Side::Sell, Amount::shares(dec!(20))?).build().await?;
+        assert_eq!(sell.order.makerAmount, U256::from(20_000_000));
+        assert_eq!(sell.order.takerAmount, U256::from(8_000_000));
+
+        let err = order(Side::Buy, Amount::usdc(dec!(80))?)
+            .build()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to fill order for 1 at 80 within the max slippage price 0.5"
+        );
+
+        let err = order(Side::Sell, Amount::shares(dec!(50))?)
+            .build()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "Unable to fill order for 1 at 50 within the max slippage price 0.4"
+        );
+
+        let problems = order(Side::Buy, Amount::usdc(dec!(40))?)
+            .price(dec!(0.5))
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            problems[0].reason,
+            "Cannot set both `price` and `max_slippage`"
+        );
+
+        Ok(())
+    }
+
     mod buy {
         use super::*;
 
@@ -1701,6 +2147,108 @@ mod market {
                 Ok(())
             }
 
+            #[tokio::test]
+            async fn should_fail_below_min_fill_size() -> anyhow::Result<()> {
+                let server = MockServer::start();
+                let client = create_authenticated(&server).await?;
+
+                ensure_requirements_for_market_price(
+                    &server,
+                    TOKEN_1,
+                    &[],
+                    &[
+                        OrderSummary::builder()
+                            .price(dec!(0.6))
+                            .size(Decimal::ONE_HUNDRED)
+                            .build(),
+                        OrderSummary::builder()
+                            .price(dec!(0.5))
+                            .size(dec!(50))
+                            .build(),
+                    ],
+                );
+
+                let err = client
+                    .market_order()
+                    .token_id(TOKEN_1)
+                    .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+                    .side(Side::Buy)
+                    .price(dec!(0.5))
+                    .min_fill_size(dec!(80))
+                    .build()
+                    .await
+                    .unwrap_err();
+                let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+                assert_eq!(
+                    msg,
+                    "Only 50 of the minimum 80 shares for 1 can fill at 0.5"
+                );
+
+                Ok(())
+            }
+
+            #[tokio::test]
+            async fn should_succeed_with_min_fill_size() -> anyhow::Result<()> {
+                let server = MockServer::start();
+                let client = create_authenticated(&server).await?;
+
+                ensure_requirements_for_market_price(
+                    &server,
+                    TOKEN_1,
+                    &[],
+                    &[
+                        OrderSummary::builder()
+                            .price(dec!(0.6))
+                            .size(Decimal::ONE_HUNDRED)
+                            .build(),
+                        OrderSummary::builder()
+                            .price(dec!(0.5))
+                            .size(dec!(50))
+                            .build(),
+                    ],
+                );
+
+                let signable_order = client
+                    .market_order()
+                    .token_id(TOKEN_1)
+                    .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+                    .side(Side::Buy)
+                    .price(dec!(0.5))
+                    .min_fill_size(dec!(50))
+                    .build()
+                    .await?;
+
+                assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000)); // 100 `TOKEN_1` tokens
+
+                Ok(())
+            }
+
+            #[tokio::test]
+            async fn should_fail_min_fill_size_for_fok() -> anyhow::Result<()> {
+                let server = MockServer::start();
+                let client = create_authenticated(&server).await?;
+
+                ensure_requirements_for_market_price(&server, TOKEN_1, &[], &[]);
+
+                let err = client
+                    .market_order()
+                    .token_id(TOKEN_1)
+                    .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+                    .side(Side::Buy)
+                    .price(dec!(0.5))
+                    .order_type(OrderType::FOK)
+                    .min_fill_size(dec!(50))
+                    .build()
+                    .await
+                    .unwrap_err();
+                let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+                assert_eq!(msg, "`min_fill_size` can only be set for FAK orders");
+
+                Ok(())
+            }
+
             #[tokio::test]
             async fn should_succeed_5() -> anyhow::Result<()> {
                 let server = MockServer::start();
@@ -1747,6 +2295,36 @@ mod market {
             }
         }
 
+        #[tokio::test]
+        async fn should_truncate_usdc_amount_to_lot_size() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements(&server, TOKEN_1, TickSize::Tenth);
+            ensure_requirements_for_market_price(
+                &server,
+                TOKEN_1,
+                &[],
+                &[OrderSummary::builder()
+                    .price(dec!(0.5))
+                    .size(Decimal::ONE_HUNDRED)
+                    .build()],
+            );
+
+            let signable_order = client
+                .market_order()
+                .token_id(TOKEN_1)
+                .amount(Amount::usdc(dec!(10.123456))?)
+                .side(Side::Buy)
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(10_120_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(20_240_000));
+
+            Ok(())
+        }
+
         #[tokio::test]
         async fn should_succeed_0_1() -> anyhow::Result<()> {
             let server = MockServer::start();
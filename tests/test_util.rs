@@ -0,0 +1,215 @@
+#![cfg(feature = "test-util")]
+
+mod clob {
+    use polymarket_client_sdk::clob::fake::FakeClobApi;
+    use polymarket_client_sdk::clob::types::Side;
+    use polymarket_client_sdk::clob::types::request::{MidpointRequest, PriceRequest};
+    use polymarket_client_sdk::clob::types::response::{MidpointResponse, PriceResponse};
+    use polymarket_client_sdk::clob::{Client, ClobApi};
+    use polymarket_client_sdk::error::{ApiError, Status, Validation};
+    use polymarket_client_sdk::types::Decimal;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+
+    const TOKEN_ID: &str = "1234";
+
+    /// Code under test depends only on the trait.
+    async fn spread_from_mid<A: ClobApi>(api: &A, token_id: &str) -> anyhow::Result<Decimal> {
+        let mid = api
+            .midpoint(&MidpointRequest::builder().token_id(token_id).build())
+            .await?
+            .mid;
+        let ask = api
+            .price(
+                &PriceRequest::builder()
+                    .token_id(token_id)
+                    .side(Side::Sell)
+                    .build(),
+            )
+            .await?
+            .price;
+
+        Ok(ask - mid)
+    }
+
+    #[tokio::test]
+    async fn fake_should_return_programmed_responses() -> anyhow::Result<()> {
+        let api = FakeClobApi::new()
+            .with_midpoint(TOKEN_ID, MidpointResponse::builder().mid(dec!(0.5)).build())
+            .with_price(
+                TOKEN_ID,
+                Side::Sell,
+                PriceResponse::builder().price(dec!(0.52)).build(),
+            );
+
+        assert_eq!(spread_from_mid(&api, TOKEN_ID).await?, dec!(0.02));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fake_should_fail_for_unprogrammed_token() {
+        let api = FakeClobApi::new()
+            .with_midpoint(TOKEN_ID, MidpointResponse::builder().mid(dec!(0.5)).build());
+
+        let err = api
+            .midpoint(&MidpointRequest::builder().token_id("5678").build())
+            .await
+            .unwrap_err();
+
+        let validation = err.downcast_ref::<Validation>().unwrap();
+        assert_eq!(validation.reason, "No canned response for `midpoint`");
+    }
+
+    #[tokio::test]
+    async fn fake_should_fail_with_programmed_status() {
+        let api = FakeClobApi::new()
+            .with_midpoint(TOKEN_ID, MidpointResponse::builder().mid(dec!(0.5)).build())
+            .with_failure("midpoint", StatusCode::TOO_MANY_REQUESTS);
+
+        let err = api
+            .midpoint(&MidpointRequest::builder().token_id(TOKEN_ID).build())
+            .await
+            .unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            err.as_api_error(),
+            Some(&ApiError::RateLimited { retry_after: None })
+        );
+    }
+
+    #[test]
+    fn real_client_should_implement_trait() {
+        fn assert_clob_api<A: ClobApi>(_: &A) {}
+
+        assert_clob_api(&Client::default());
+    }
+}
+
+#[cfg(feature = "data")]
+mod data {
+    use polymarket_client_sdk::data::fake::FakeDataApi;
+    use polymarket_client_sdk::data::types::request::{PositionsRequest, TradesRequest};
+    use polymarket_client_sdk::data::types::response::Position;
+    use polymarket_client_sdk::data::{Client, DataApi};
+    use polymarket_client_sdk::error::Validation;
+    use polymarket_client_sdk::types::address;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn position() -> serde_json::Result<Position> {
+        serde_json::from_value(json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "size": 100.5,
+            "avgPrice": 0.65,
+            "initialValue": 65.325,
+            "currentValue": 70.35,
+            "cashPnl": 5.025,
+            "percentPnl": 7.69,
+            "totalBought": 100.5,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 0.70,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Will BTC hit $100k?",
+            "slug": "btc-100k",
+            "icon": "",
+            "eventSlug": "crypto-prices",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "",
+            "endDate": "2025-12-31",
+            "negativeRisk": false
+        }))
+    }
+
+    #[tokio::test]
+    async fn fake_should_return_programmed_responses() -> anyhow::Result<()> {
+        let api = FakeDataApi::new().with_positions(vec![position()?]);
+        let request = PositionsRequest::builder()
+            .user(address!("1234567890abcdef1234567890abcdef12345678"))
+            .build();
+
+        let positions = api.positions(&request).await?;
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].size, dec!(100.5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fake_should_fail_for_unprogrammed_method() {
+        let api = FakeDataApi::new();
+
+        let err = api.trades(&TradesRequest::default()).await.unwrap_err();
+
+        let validation = err.downcast_ref::<Validation>().unwrap();
+        assert_eq!(validation.reason, "No canned response for `trades`");
+    }
+
+    #[test]
+    fn real_client_should_implement_trait() {
+        fn assert_data_api<A: DataApi>(_: &A) {}
+
+        assert_data_api(&Client::default());
+    }
+}
+
+#[cfg(feature = "gamma")]
+mod gamma {
+    use polymarket_client_sdk::error::Validation;
+    use polymarket_client_sdk::gamma::fake::FakeGammaApi;
+    use polymarket_client_sdk::gamma::types::request::{EventBySlugRequest, EventsRequest};
+    use polymarket_client_sdk::gamma::types::response::Event;
+    use polymarket_client_sdk::gamma::{Client, GammaApi};
+    use serde_json::json;
+
+    fn event(id: &str, slug: &str) -> serde_json::Result<Event> {
+        serde_json::from_value(json!({ "id": id, "slug": slug }))
+    }
+
+    #[tokio::test]
+    async fn fake_should_look_up_programmed_events() -> anyhow::Result<()> {
+        let api =
+            FakeGammaApi::new().with_events(vec![event("1", "first")?, event("2", "second")?]);
+
+        let events = api.events(&EventsRequest::default()).await?;
+        let event = api
+            .event_by_slug(&EventBySlugRequest::builder().slug("second").build())
+            .await?;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(event.id, "2");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fake_should_fail_for_unknown_slug() -> anyhow::Result<()> {
+        let api = FakeGammaApi::new().with_events(vec![event("1", "first")?]);
+
+        let err = api
+            .event_by_slug(&EventBySlugRequest::builder().slug("missing").build())
+            .await
+            .unwrap_err();
+
+        let validation = err.downcast_ref::<Validation>().unwrap();
+        assert_eq!(validation.reason, "No canned event matches `event_by_slug`");
+
+        Ok(())
+    }
+
+    #[test]
+    fn real_client_should_implement_trait() {
+        fn assert_gamma_api<A: GammaApi>(_: &A) {}
+
+        assert_gamma_api(&Client::default());
+    }
+}
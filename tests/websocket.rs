@@ -61,7 +61,12 @@ impl MockWsServer {
                             // Handle incoming messages from client
                             msg = read.next() => {
                                 match msg {
-                                    Some(Ok(Message::Text(text))) if text != "PING" => {
+                                    Some(Ok(Message::Text(text))) if text == "PING" => {
+                                        if write.send(Message::Text("PONG".into())).await.is_err() {
+                                            break;
+                                        }
+                                    }
+                                    Some(Ok(Message::Text(text))) => {
                                         drop(sub_tx.send(text.to_string()));
                                     }
                                     Some(Ok(_)) => {}
@@ -238,6 +243,12 @@ mod payloads {
 }
 
 mod market_channel {
+    use std::str::FromStr as _;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use alloy::primitives::U256;
+    use polymarket_client_sdk::clob::ws::WsError;
+    use polymarket_client_sdk::metrics::MetricsObserver;
     use rust_decimal_macros::dec;
 
     use super::*;
@@ -278,6 +289,182 @@ mod market_channel {
         assert_eq!(book.hash, Some("0x1234567890abcdef".to_owned()));
     }
 
+    #[tokio::test]
+    async fn subscribe_orderbook_raw_yields_original_frame() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_orderbook_raw(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        // Keep the original formatting to verify the frame is passed through verbatim
+        let frame = serde_json::to_string_pretty(&payloads::book()).unwrap();
+        server.send(&frame);
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let (book, raw) = result.unwrap().unwrap().unwrap();
+
+        assert_eq!(book.asset_id, payloads::ASSET_ID);
+        assert_eq!(book.bids[0].price, dec!(0.48));
+        assert_eq!(raw, frame);
+    }
+
+    #[tokio::test]
+    async fn subscribe_orderbook_tokens_sends_decimal_ids() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let token_id = U256::from_str(payloads::ASSET_ID).unwrap();
+        let stream = client.subscribe_orderbook_tokens(&[token_id]).unwrap();
+        let mut stream = Box::pin(stream);
+
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains(payloads::ASSET_ID));
+
+        server.send(&payloads::book().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let book = result.unwrap().unwrap().unwrap();
+        assert_eq!(book.asset_id, payloads::ASSET_ID);
+    }
+
+    #[tokio::test]
+    async fn subscribe_orderbook_tokens_rejects_zero() {
+        let server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let Err(err) = client.subscribe_orderbook_tokens(&[U256::ZERO]) else {
+            panic!("zero token ID should be rejected");
+        };
+        let ws_error = err.downcast_ref::<WsError>().unwrap();
+        assert!(matches!(ws_error, WsError::InvalidArgument { .. }));
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_replay_replays_to_late_subscribers() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let first = client
+            .subscribe_with_replay(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut first = Box::pin(first);
+        server.recv_subscription().await.unwrap();
+
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::price_change_batch(payloads::ASSET_ID).to_string());
+        for _ in 0..2 {
+            timeout(Duration::from_secs(2), first.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+        }
+
+        // The late subscriber shares the upstream subscription and starts from the same state
+        let late = client
+            .subscribe_with_replay(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut late = Box::pin(late);
+
+        let book = timeout(Duration::from_secs(2), late.next()).await.unwrap();
+        assert!(
+            matches!(book, Some(Ok(WsMessage::Book(ref book))) if book.asset_id == payloads::ASSET_ID)
+        );
+        let change = timeout(Duration::from_secs(2), late.next()).await.unwrap();
+        assert!(matches!(change, Some(Ok(WsMessage::PriceChange(_)))));
+        assert_eq!(server.recv_subscription().await, None);
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_replay_resubscribes_assets_subscribed_without_replay() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let _plain = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        server.recv_subscription().await.unwrap();
+
+        // Nothing was kept for the asset, so a fresh snapshot is requested
+        let _replaying = client
+            .subscribe_with_replay(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let request = server.recv_subscription().await.unwrap();
+        assert!(request.contains(payloads::ASSET_ID));
+
+        let _again = client
+            .subscribe_with_replay(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        assert_eq!(server.recv_subscription().await, None);
+    }
+
+    #[tokio::test]
+    async fn subscribe_orderbook_rejects_malformed_asset_ids() {
+        let server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        for asset_id in [
+            payloads::MARKET,
+            " 65818619657568813474341868652308942079804919287380422192892211131408793125422",
+            "0",
+            "",
+        ] {
+            let Err(err) = client
+                .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned(), asset_id.to_owned()])
+            else {
+                panic!("{asset_id:?} should be rejected");
+            };
+            let ws_error = err.downcast_ref::<WsError>().unwrap();
+            assert!(matches!(ws_error, WsError::InvalidArgument { .. }));
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribe_decode_errors_reports_malformed_messages() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut errors = Box::pin(client.subscribe_decode_errors());
+        server.recv_subscription().await.unwrap();
+
+        let malformed = json!({
+            "event_type": "book",
+            "asset_id": payloads::ASSET_ID,
+            "bids": 42
+        });
+        server.send(&malformed.to_string());
+
+        let error = timeout(Duration::from_secs(2), errors.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let ws_error = error.downcast_ref::<WsError>().unwrap();
+        assert!(matches!(ws_error, WsError::Decode { raw, .. } if raw.contains("\"bids\":42")));
+    }
+
     #[tokio::test]
     async fn subscribe_prices_receives_price_changes() {
         let mut server = MockWsServer::start().await;
@@ -306,6 +493,53 @@ mod market_channel {
         assert_eq!(price.price_changes[0].best_ask, Some(dec!(1)));
     }
 
+    #[tokio::test]
+    async fn subscribe_price_changes_skips_other_messages() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_price_changes(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::last_trade_price(payloads::ASSET_ID).to_string());
+        server.send(&payloads::price_change_batch(payloads::ASSET_ID).to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let price = result.unwrap().unwrap().unwrap();
+        assert_eq!(price.price_changes[0].asset_id, payloads::ASSET_ID);
+    }
+
+    #[tokio::test]
+    async fn subscribe_last_trade_price_receives_last_trade_prices() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_last_trade_price(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains(payloads::ASSET_ID));
+
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::last_trade_price(OTHER_ASSET_ID).to_string());
+        server.send(&payloads::last_trade_price(payloads::ASSET_ID).to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let last_trade_price = result.unwrap().unwrap().unwrap();
+        assert_eq!(last_trade_price.asset_id, payloads::ASSET_ID);
+    }
+
     #[tokio::test]
     async fn filters_messages_by_asset_id() {
         let mut server = MockWsServer::start().await;
@@ -399,6 +633,48 @@ mod market_channel {
         let midpoint = result.unwrap().unwrap().unwrap();
         assert_eq!(midpoint.midpoint, dec!(0.50));
     }
+
+    #[derive(Debug, Default)]
+    struct HeartbeatCounter(AtomicUsize);
+
+    impl MetricsObserver for HeartbeatCounter {
+        fn on_heartbeat(&self, _endpoint: &str, _round_trip: Duration) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn latency_should_report_answered_heartbeats() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let counter = Arc::new(HeartbeatCounter::default());
+        let mut config = Config::default();
+        config.heartbeat_interval = Duration::from_millis(20);
+        config.metrics = Some(Arc::clone(&counter) as Arc<dyn MetricsObserver>);
+        let client = Client::new(&endpoint, config).unwrap();
+
+        assert_eq!(client.latency(), None);
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        let latency = timeout(Duration::from_secs(2), async {
+            loop {
+                if let Some(latency) = client.latency() {
+                    break latency;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(latency < Duration::from_secs(2));
+        assert!(counter.0.load(Ordering::SeqCst) >= 1);
+    }
 }
 
 mod user_channel {
@@ -847,6 +1123,47 @@ mod reconnection {
             "Re-subscription should contain all tracked assets, got: {resub_str}"
         );
     }
+
+    #[tokio::test]
+    async fn reconnect_attempts_should_reset_once_connection_is_stable() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let mut config = config();
+        config.reconnect.stability_window = Duration::from_millis(300);
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+        assert_eq!(client.reconnect_attempts(), 0);
+
+        // A connection dropped before the stability window counts as a failed attempt
+        server.disconnect_all();
+        timeout(Duration::from_secs(2), async {
+            while client.reconnect_attempts() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        server.allow_reconnect();
+        let resub = server.recv_subscription().await;
+        assert!(resub.is_some(), "Should receive re-subscription");
+        assert!(client.reconnect_attempts() >= 1);
+
+        // Once the new connection stays up for the window, the count resets
+        timeout(Duration::from_secs(2), async {
+            while client.reconnect_attempts() != 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert!(client.connection_state().is_connected());
+    }
 }
 
 mod unsubscribe {
@@ -1189,3 +1506,345 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod connection_config {
+    use polymarket_client_sdk::clob::ws::ProxyConfig;
+    use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::tungstenite::http::HeaderValue;
+
+    use super::*;
+
+    /// Start a minimal HTTP `CONNECT` proxy that forwards every tunnel to `target`, reporting
+    /// each `CONNECT` request it receives.
+    async fn start_proxy(target: SocketAddr) -> (SocketAddr, mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut inbound, _)) = listener.accept().await {
+                let request_tx = request_tx.clone();
+
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0_u8; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        let read = inbound.read(&mut buffer).await.unwrap();
+                        request.extend_from_slice(&buffer[..read]);
+                    }
+                    drop(request_tx.send(String::from_utf8(request).unwrap()));
+
+                    let mut outbound = TcpStream::connect(target).await.unwrap();
+                    inbound
+                        .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+                        .await
+                        .unwrap();
+                    drop(tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await);
+                });
+            }
+        });
+
+        (addr, request_rx)
+    }
+
+    #[tokio::test]
+    async fn connects_through_proxy() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+        let (proxy_addr, mut proxy_requests) = start_proxy(server.addr).await;
+
+        let mut config = Config::default();
+        config.proxy = Some(ProxyConfig::new(&format!("http://user:pass@{proxy_addr}")).unwrap());
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let connect = timeout(Duration::from_secs(2), proxy_requests.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(connect.starts_with(&format!("CONNECT {} HTTP/1.1", server.addr)));
+        assert!(connect.contains("Proxy-Authorization: Basic dXNlcjpwYXNz"));
+
+        let _: Option<String> = server.recv_subscription().await;
+        server.send(&payloads::book().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let book = result.unwrap().unwrap().unwrap();
+        assert_eq!(book.asset_id, payloads::ASSET_ID);
+    }
+
+    #[tokio::test]
+    async fn sends_custom_headers_on_upgrade() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (header_tx, mut header_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ws_stream = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &Request, response: Response| {
+                    let cookie = request
+                        .headers()
+                        .get("cookie")
+                        .map(|value| value.to_str().unwrap().to_owned());
+                    drop(header_tx.send(cookie));
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let mut config = Config::default();
+        config
+            .headers
+            .insert("cookie", HeaderValue::from_static("session=abc123"));
+        let _client = Client::new(&format!("ws://{addr}/ws/market"), config).unwrap();
+
+        let cookie = timeout(Duration::from_secs(2), header_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cookie.as_deref(), Some("session=abc123"));
+    }
+}
+
+mod health {
+    use httpmock::MockServer;
+    use polymarket_client_sdk::clob;
+    use polymarket_client_sdk::health::{ServiceStatus, Services};
+    use reqwest::StatusCode;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn health_all_reports_reachable_services_up() {
+        let ws_server = MockWsServer::start().await;
+        let ws = Client::new(&ws_server.ws_url(""), Config::default()).unwrap();
+        let http_server = MockServer::start();
+        http_server.mock(|when, then| {
+            when.path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+        let clob = clob::Client::new(&http_server.base_url(), clob::Config::default()).unwrap();
+
+        let report = Services::new().clob(&clob).ws(&ws).health_all().await;
+
+        assert!(report.is_healthy(), "{report:?}");
+        assert!(matches!(report.clob, Some(ServiceStatus::Up { .. })));
+        assert!(matches!(report.ws, Some(ServiceStatus::Up { .. })));
+        assert_eq!(report.gamma, None);
+        assert_eq!(report.data_api, None);
+    }
+
+    #[tokio::test]
+    async fn health_all_reports_failing_service_down() {
+        let http_server = MockServer::start();
+        http_server.mock(|when, then| {
+            when.path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+        let clob = clob::Client::new(&http_server.base_url(), clob::Config::default()).unwrap();
+
+        let report = Services::new().clob(&clob).health_all().await;
+
+        assert!(!report.is_healthy(), "{report:?}");
+        let Some(ServiceStatus::Down { reason, .. }) = report.clob else {
+            panic!("Expected CLOB to be down: {report:?}");
+        };
+        assert!(reason.contains("503"), "{reason}");
+    }
+
+    #[tokio::test]
+    async fn health_all_times_out_unresponsive_service() {
+        // Accepts TCP connections but never completes the WebSocket handshake
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let _accepted = tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                held.push(stream);
+            }
+        });
+        let ws = Client::new(&format!("ws://{addr}"), Config::default()).unwrap();
+
+        let report = Services::new()
+            .ws(&ws)
+            .timeout(Duration::from_millis(200))
+            .health_all()
+            .await;
+
+        let Some(ServiceStatus::Down { reason, latency }) = report.ws else {
+            panic!("Expected WebSocket gateway to be down: {report:?}");
+        };
+        assert!(reason.starts_with("No response within"), "{reason}");
+        assert!(latency >= Duration::from_millis(200), "{latency:?}");
+    }
+}
+
+mod merge {
+    use futures::{TryStreamExt as _, stream};
+    use polymarket_client_sdk::clob::ws::{MultiStream, TaggedResult};
+    use polymarket_client_sdk::error::Error;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum Feed {
+        Books,
+        Trades,
+    }
+
+    #[tokio::test]
+    async fn multi_stream_should_tag_messages_by_channel() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), Config::default()).unwrap();
+        let assets = vec![payloads::ASSET_ID.to_owned()];
+
+        let mut merged = MultiStream::new()
+            .add(
+                Feed::Books,
+                client
+                    .subscribe_orderbook(assets.clone())
+                    .unwrap()
+                    .map_ok(WsMessage::Book),
+            )
+            .add(
+                Feed::Trades,
+                client
+                    .subscribe_last_trade_price(assets)
+                    .unwrap()
+                    .map_ok(WsMessage::LastTradePrice),
+            );
+        assert_eq!(merged.len(), 2);
+
+        let _: Option<String> = server.recv_subscription().await;
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::last_trade_price(payloads::ASSET_ID).to_string());
+
+        let mut channels = Vec::new();
+        for _ in 0..2 {
+            let tagged = timeout(Duration::from_secs(2), merged.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            match (tagged.channel, tagged.message) {
+                (Feed::Books, WsMessage::Book(book)) => {
+                    assert_eq!(book.asset_id, payloads::ASSET_ID);
+                }
+                (Feed::Trades, WsMessage::LastTradePrice(trade)) => {
+                    assert_eq!(trade.asset_id, payloads::ASSET_ID);
+                }
+                (channel, message) => panic!("unexpected {message:?} on {channel:?}"),
+            }
+            channels.push(tagged.channel);
+        }
+        channels.sort();
+
+        assert_eq!(channels, [Feed::Books, Feed::Trades]);
+    }
+
+    #[tokio::test]
+    async fn multi_stream_should_outlive_failing_and_finished_streams() {
+        let failing = stream::iter([Err(Error::validation("dropped")), Ok(1)]);
+        let finished = stream::iter([Ok::<_, Error>(2)]);
+
+        let merged: Vec<TaggedResult<&str, i32>> = MultiStream::new()
+            .add("failing", failing)
+            .add("finished", finished)
+            .collect()
+            .await;
+        let failed: Vec<_> = merged
+            .iter()
+            .filter_map(|message| message.as_ref().err())
+            .map(|(channel, _)| *channel)
+            .collect();
+
+        let tagged: Vec<_> = merged
+            .iter()
+            .filter_map(|message| message.as_ref().ok())
+            .map(|tagged| (tagged.channel, tagged.message))
+            .collect();
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(tagged.len(), 2);
+        assert!(tagged.contains(&("failing", 1)));
+        assert!(tagged.contains(&("finished", 2)));
+        assert_eq!(failed, ["failing"]);
+    }
+}
+
+mod store {
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    use polymarket_client_sdk::clob::ws::OrderBookStore;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn message(payload: serde_json::Value) -> WsMessage {
+        serde_json::from_value(payload).unwrap()
+    }
+
+    #[test]
+    fn store_should_apply_snapshots_and_price_changes() {
+        let mut store = OrderBookStore::new();
+        let before = Instant::now();
+
+        store.apply(&message(payloads::price_change_batch(payloads::ASSET_ID)));
+        assert_eq!(store.last_update(payloads::ASSET_ID), None);
+
+        store.apply(&message(payloads::book()));
+        store.apply(&message(payloads::price_change_batch(payloads::ASSET_ID)));
+
+        assert_eq!(
+            store.best_bid(payloads::ASSET_ID),
+            Some((dec!(0.5), dec!(200)))
+        );
+        assert_eq!(
+            store.best_ask(payloads::ASSET_ID),
+            Some((dec!(0.52), dec!(25)))
+        );
+        assert_eq!(store.bids(payloads::ASSET_ID).unwrap().len(), 3);
+        assert!(store.last_update(payloads::ASSET_ID).unwrap() >= before);
+    }
+
+    #[test]
+    fn store_should_report_stale_books_once() {
+        let stale = Arc::new(Mutex::new(Vec::new()));
+        let reported = Arc::clone(&stale);
+        let mut store = OrderBookStore::new()
+            .with_staleness_callback(Duration::ZERO, move |asset_id, _| {
+                reported.lock().unwrap().push(asset_id.to_owned())
+            });
+
+        assert!(store.is_stale(payloads::ASSET_ID, Duration::from_secs(60)));
+
+        store.apply(&message(payloads::book()));
+
+        assert!(!store.is_stale(payloads::ASSET_ID, Duration::from_secs(60)));
+        assert!(store.is_stale(payloads::ASSET_ID, Duration::ZERO));
+        assert_eq!(store.check_staleness(), 1);
+        assert_eq!(store.check_staleness(), 0, "already reported");
+
+        store.apply(&message(payloads::price_change_batch(payloads::ASSET_ID)));
+
+        assert_eq!(store.check_staleness(), 1, "stale again after an update");
+        assert_eq!(
+            *stale.lock().unwrap(),
+            [payloads::ASSET_ID, payloads::ASSET_ID]
+        );
+    }
+}